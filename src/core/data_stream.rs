@@ -1,10 +1,16 @@
 //! # Data stream module
 //!
-//! This module contains the [`DataStream`] struct.
+//! This module contains the [`DataStream`] struct and the
+//! [`DataStreamOverflowPolicy`] enum.
 
 use futures::Stream;
 use spin::RwLock;
 
+#[cfg(all(feature = "subscribe", feature = "std"))]
+use crate::core::PubNubError;
+#[cfg(all(feature = "subscribe", feature = "std"))]
+use core::time::Duration;
+
 use crate::lib::{
     alloc::{collections::VecDeque, sync::Arc},
     core::{
@@ -14,6 +20,32 @@ use crate::lib::{
     },
 };
 
+/// Data stream queue overflow policy.
+///
+/// Policy defines how [`DataStream`] handles new data pushed into full queue.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DataStreamOverflowPolicy {
+    /// Drop the oldest queued entry to make room for new data.
+    #[default]
+    DropOldest,
+
+    /// Drop new data and keep already queued entries.
+    DropNewest,
+
+    /// Wait until stream listener frees room in the queue.
+    ///
+    /// Thread which delivers data (subscription loop) is blocked, so stream
+    /// listener should run on another thread, or subscription loop will
+    /// stall.
+    Block,
+
+    /// Reject new data with [`PubNubError::DataStreamOverflow`] error and
+    /// keep already queued entries.
+    ///
+    /// [`PubNubError::DataStreamOverflow`]: crate::core::PubNubError::DataStreamOverflow
+    Error,
+}
+
 /// A generic data stream.
 ///
 /// [`DataStream`] provides functionality which allows to `poll` any new data
@@ -58,6 +90,17 @@ pub struct DataStreamRef<D> {
     /// Queue with data for stream listener.
     queue: RwLock<VecDeque<D>>,
 
+    /// Maximum number of entries in queue.
+    capacity: usize,
+
+    /// Policy which is used when queue is full.
+    overflow_policy: DataStreamOverflowPolicy,
+
+    /// Number of entries dropped since queue overflowed.
+    ///
+    /// Counter reset when stream listener drains the queue.
+    dropped: RwLock<usize>,
+
     /// Data stream waker.
     ///
     /// Handler used each time when new data available for a stream listener.
@@ -106,7 +149,32 @@ impl<D> DataStream<D> {
     /// let data_stream = DataStream::<usize>::with_queue_size(10);
     /// ```
     pub fn with_queue_size(size: usize) -> DataStream<D> {
-        Self::with_queue_data(VecDeque::new(), size)
+        Self::with_overflow_policy(size, DataStreamOverflowPolicy::default())
+    }
+
+    /// Creates a new `DataStream` with a specified queue size and overflow
+    /// policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The maximum number of elements that can be stored in the
+    ///   queue.
+    /// * `policy` - The policy which is used when data pushed into full queue.
+    ///
+    /// # Returns
+    ///
+    /// A new `DataStream` with the specified queue size and overflow policy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::core::{DataStream, DataStreamOverflowPolicy};
+    ///
+    /// let data_stream =
+    ///     DataStream::<usize>::with_overflow_policy(10, DataStreamOverflowPolicy::DropNewest);
+    /// ```
+    pub fn with_overflow_policy(size: usize, policy: DataStreamOverflowPolicy) -> DataStream<D> {
+        Self::with_configuration(VecDeque::new(), size, policy)
     }
 
     /// Creates a new `DataStream` with a given queue `data` and `size`.
@@ -131,15 +199,37 @@ impl<D> DataStream<D> {
     /// let stream: DataStream<i32> = DataStream::with_queue_data(data, 5);
     /// ```
     pub fn with_queue_data(data: VecDeque<D>, size: usize) -> DataStream<D> {
+        Self::with_configuration(data, size, DataStreamOverflowPolicy::default())
+    }
+
+    /// Maximum number of entries which can be stored in the queue.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Policy which is used when data pushed into full queue.
+    pub fn overflow_policy(&self) -> DataStreamOverflowPolicy {
+        self.overflow_policy
+    }
+
+    #[cfg_attr(not(all(feature = "subscribe", feature = "std")), allow(dead_code))]
+    pub(crate) fn with_configuration(
+        data: VecDeque<D>,
+        size: usize,
+        policy: DataStreamOverflowPolicy,
+    ) -> DataStream<D> {
         let mut queue_data = VecDeque::with_capacity(size);
 
         if !data.is_empty() {
-            queue_data.extend(data.into_iter().take(queue_data.capacity()));
+            queue_data.extend(data.into_iter().take(size));
         }
 
         Self {
             inner: Arc::new(DataStreamRef {
                 queue: RwLock::new(queue_data),
+                capacity: size,
+                overflow_policy: policy,
+                dropped: RwLock::new(0),
                 waker: RwLock::new(None),
                 is_valid: RwLock::new(true),
             }),
@@ -147,21 +237,57 @@ impl<D> DataStream<D> {
     }
 
     #[cfg(all(feature = "subscribe", feature = "std"))]
-    pub(crate) fn push_data(&self, data: D) {
+    pub(crate) fn push_data(&self, data: D) -> Result<(), PubNubError> {
+        if self.overflow_policy == DataStreamOverflowPolicy::Block {
+            while self.capacity > 0
+                && *self.is_valid.read()
+                && self.queue.read().len() >= self.capacity
+            {
+                self.wake_stream();
+                std::thread::sleep(Duration::from_millis(1));
+            }
+        }
+
         if !*self.is_valid.read() {
-            return;
+            return Ok(());
         }
 
         let mut queue_data_slot = self.queue.write();
 
-        // Dropping the earliest entry to prevent the queue from growing too large.
-        if queue_data_slot.len() == queue_data_slot.capacity() {
-            queue_data_slot.pop_front();
+        // Dropping entry to prevent the queue from growing too large.
+        if queue_data_slot.len() >= self.capacity {
+            let mut dropped_slot = self.dropped.write();
+            if *dropped_slot == 0 {
+                log::warn!(
+                    "Data stream queue is full ({} entries, {:?} policy). Entries will be \
+                    dropped until stream listener drains the queue.",
+                    self.capacity,
+                    self.overflow_policy
+                );
+            }
+            *dropped_slot += 1;
+
+            match self.overflow_policy {
+                DataStreamOverflowPolicy::DropOldest => {
+                    queue_data_slot.pop_front();
+                }
+                DataStreamOverflowPolicy::DropNewest | DataStreamOverflowPolicy::Block => {
+                    return Ok(())
+                }
+                DataStreamOverflowPolicy::Error => {
+                    return Err(PubNubError::DataStreamOverflow {
+                        capacity: self.capacity,
+                    })
+                }
+            }
         }
 
-        queue_data_slot.push_back(data);
+        if self.capacity > 0 {
+            queue_data_slot.push_back(data);
+        }
 
         self.wake_stream();
+        Ok(())
     }
 
     #[cfg(all(feature = "subscribe", feature = "std"))]
@@ -190,10 +316,98 @@ impl<D> Stream for DataStream<D> {
         let mut waker_slot = self.waker.write();
         *waker_slot = Some(ctx.waker().clone());
 
-        if let Some(data) = self.queue.write().pop_front() {
+        let mut queue_data_slot = self.queue.write();
+        let data = queue_data_slot.pop_front();
+
+        if queue_data_slot.is_empty() {
+            let mut dropped_slot = self.dropped.write();
+            if *dropped_slot > 0 {
+                log::warn!(
+                    "Data stream listener drained the queue. {} entries have been dropped.",
+                    *dropped_slot
+                );
+                *dropped_slot = 0;
+            }
+        }
+
+        if let Some(data) = data {
             Poll::Ready(Some(data))
         } else {
             Poll::Pending
         }
     }
 }
+
+#[cfg(all(test, feature = "subscribe", feature = "std"))]
+mod should {
+    use super::*;
+    use futures::{FutureExt, StreamExt};
+
+    #[tokio::test]
+    async fn drop_oldest_entry_when_queue_is_full() {
+        let mut stream = DataStream::with_queue_size(2);
+
+        (0..3).for_each(|value| stream.push_data(value).unwrap());
+
+        assert_eq!(stream.next().await, Some(1));
+        assert_eq!(stream.next().await, Some(2));
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn drop_newest_entry_when_queue_is_full() {
+        let mut stream = DataStream::with_overflow_policy(2, DataStreamOverflowPolicy::DropNewest);
+
+        (0..3).for_each(|value| stream.push_data(value).unwrap());
+
+        assert_eq!(stream.next().await, Some(0));
+        assert_eq!(stream.next().await, Some(1));
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn block_until_queue_has_room() {
+        let mut stream = DataStream::with_overflow_policy(1, DataStreamOverflowPolicy::Block);
+        stream.push_data(0).unwrap();
+
+        let producer = {
+            let stream = stream.clone();
+            std::thread::spawn(move || stream.push_data(1))
+        };
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(!producer.is_finished());
+
+        assert_eq!(stream.next().await, Some(0));
+        assert!(producer.join().unwrap().is_ok());
+        assert_eq!(stream.next().await, Some(1));
+        assert_eq!(*stream.dropped.read(), 0);
+    }
+
+    #[tokio::test]
+    async fn return_error_when_queue_is_full() {
+        let mut stream = DataStream::with_overflow_policy(2, DataStreamOverflowPolicy::Error);
+
+        (0..2).for_each(|value| stream.push_data(value).unwrap());
+
+        assert_eq!(
+            stream.push_data(2),
+            Err(PubNubError::DataStreamOverflow { capacity: 2 })
+        );
+        assert_eq!(stream.next().await, Some(0));
+        assert_eq!(stream.next().await, Some(1));
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn count_dropped_entries_until_queue_drained() {
+        let mut stream = DataStream::with_queue_size(2);
+
+        (0..4).for_each(|value| stream.push_data(value).unwrap());
+        assert_eq!(*stream.dropped.read(), 2);
+
+        assert_eq!(stream.next().await, Some(2));
+        assert_eq!(*stream.dropped.read(), 2);
+        assert_eq!(stream.next().await, Some(3));
+        assert_eq!(*stream.dropped.read(), 0);
+    }
+}
@@ -19,6 +19,7 @@ use crate::core::PubNubError;
 /// Features and their results:
 /// - `publish` - [`PublishResponseBody`]
 /// - `access` - [`GrantTokenResponseBody`] and [`RevokeTokenResponseBody`]
+/// - always available - [`TimeResponseBody`]
 ///
 /// More information about the response of the PubNub API can be found in the
 /// [PubNub API Reference](https://www.pubnub.com/docs).
@@ -42,6 +43,7 @@ use crate::core::PubNubError;
 /// [`PublishResponseBody`]: ../../dx/publish/result/enum.PublishResponseBody.html
 /// [`GrantTokenResponseBody`]: ../../dx/access/result/enum.GrantTokenResponseBody.html
 /// [`RevokeTokenResponseBody`]: ../../dx/access/result/enum.RevokeTokenResponseBody.html
+/// [`TimeResponseBody`]: ../../dx/time/result/struct.TimeResponseBody.html
 pub trait Deserializer: Send + Sync {
     /// Deserialize a `&Vec<u8>` into a `Result<T, PubNubError>`.
     ///
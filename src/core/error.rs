@@ -40,9 +40,22 @@ pub enum PubNubError {
         details: String,
 
         /// Failed request HTTP status code.
+        ///
+        /// Status code is available when the request has been processed by
+        /// the service.
+        status: Option<u16>,
+
+        /// Raw service response.
         response: Option<Box<TransportResponse>>,
     },
 
+    /// this error is returned when request hasn't been completed in time
+    #[snafu(display("Request timeout: {endpoint}"))]
+    Timeout {
+        /// Endpoint which didn't respond in time.
+        endpoint: String,
+    },
+
     /// this error is returned when request has been cancelled.
     #[snafu(display("Request cancelled: {details}"))]
     RequestCancel {
@@ -125,6 +138,14 @@ pub enum PubNubError {
     #[snafu(display("Event engine effect has been canceled"))]
     EffectCanceled,
 
+    /// this error is returned when data can't be pushed into full data stream
+    /// queue.
+    #[snafu(display("Data stream queue is full ({capacity} entries)"))]
+    DataStreamOverflow {
+        /// Maximum number of entries in data stream queue.
+        capacity: usize,
+    },
+
     /// this error is returned when the subscription initialization fails
     #[snafu(display("Subscription initialization error: {details}"))]
     SubscribeInitialization {
@@ -180,6 +201,28 @@ impl PubNubError {
         }
     }
 
+    /// Check whether request which failed with this error can be retried.
+    ///
+    /// Transport issues (including timeouts) and service errors with `429` or
+    /// `5xx` status codes are considered as temporary and can be retried.
+    ///
+    /// > Note: Status code of the attached service response takes precedence
+    /// > over error's `status`.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            PubNubError::Transport { .. } | PubNubError::Timeout { .. } => true,
+            PubNubError::API {
+                status, response, ..
+            } => matches!(
+                response
+                    .as_ref()
+                    .map_or(*status, |response| response.status),
+                429 | 500..=599
+            ),
+            _ => false,
+        }
+    }
+
     /// Retrieve attached service response.
     #[cfg(all(
         feature = "std",
@@ -194,6 +237,21 @@ impl PubNubError {
         }
     }
 
+    /// Retrieve failed request HTTP status code.
+    #[cfg(all(
+        feature = "std",
+        any(feature = "publish", feature = "access", feature = "subscribe")
+    ))]
+    pub(crate) fn status(&self) -> Option<u16> {
+        match self {
+            PubNubError::Transport {
+                status: Some(status),
+                ..
+            } => Some(*status),
+            _ => self.transport_response().map(|response| response.status),
+        }
+    }
+
     /// Attach service response.
     ///
     /// For better understanding some errors may provide additional information
@@ -218,9 +276,198 @@ impl PubNubError {
             },
             PubNubError::Transport { details, .. } => PubNubError::Transport {
                 details: details.clone(),
+                status: Some(service_response.status),
                 response: Some(Box::new(service_response)),
             },
             _ => self,
         }
     }
 }
+
+#[cfg(feature = "hyper")]
+impl From<hyper::Error> for PubNubError {
+    fn from(error: hyper::Error) -> Self {
+        use crate::lib::alloc::{format, string::ToString};
+
+        if error.is_timeout() {
+            PubNubError::Timeout {
+                endpoint: Default::default(),
+            }
+        } else if error.is_connect() {
+            PubNubError::Transport {
+                details: format!("Unable to connect: {error}"),
+                status: None,
+                response: None,
+            }
+        } else {
+            PubNubError::Transport {
+                details: error.to_string(),
+                status: None,
+                response: None,
+            }
+        }
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for PubNubError {
+    fn from(error: reqwest::Error) -> Self {
+        use crate::lib::alloc::{format, string::ToString};
+
+        if error.is_timeout() {
+            PubNubError::Timeout {
+                endpoint: error.url().map(|url| url.path().into()).unwrap_or_default(),
+            }
+        } else if error.is_decode() {
+            PubNubError::Deserialization {
+                details: format!("Unable to decode response: {error}"),
+            }
+        } else if error.is_connect() {
+            PubNubError::Transport {
+                details: format!("Unable to connect: {error}"),
+                status: None,
+                response: None,
+            }
+        } else if error.is_body() {
+            PubNubError::Transport {
+                details: format!("Unable to process request or response body: {error}"),
+                status: None,
+                response: None,
+            }
+        } else {
+            PubNubError::Transport {
+                details: error.to_string(),
+                status: None,
+                response: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+
+    #[test]
+    fn display_timeout_error() {
+        let error = PubNubError::Timeout {
+            endpoint: "/v2/subscribe/demo/ch1/0".into(),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "Request timeout: /v2/subscribe/demo/ch1/0"
+        );
+    }
+
+    #[test]
+    fn classify_retriable_errors() {
+        assert!(PubNubError::Transport {
+            details: "test".into(),
+            status: None,
+            response: None,
+        }
+        .is_retriable());
+        assert!(PubNubError::Timeout {
+            endpoint: "/time/0".into(),
+        }
+        .is_retriable());
+        assert!(api_error(429).is_retriable());
+        assert!(api_error(503).is_retriable());
+    }
+
+    #[test]
+    fn classify_not_retriable_errors() {
+        assert!(!api_error(400).is_retriable());
+        assert!(!api_error(403).is_retriable());
+        assert!(!PubNubError::EffectCanceled.is_retriable());
+        assert!(!PubNubError::Serialization {
+            details: "test".into(),
+        }
+        .is_retriable());
+        assert!(!PubNubError::Deserialization {
+            details: "test".into(),
+        }
+        .is_retriable());
+        assert!(!PubNubError::Decryption {
+            details: "test".into(),
+        }
+        .is_retriable());
+        assert!(!PubNubError::CryptoInitialization {
+            details: "test".into(),
+        }
+        .is_retriable());
+    }
+
+    fn api_error(status: u16) -> PubNubError {
+        PubNubError::API {
+            status,
+            message: "test".into(),
+            service: None,
+            affected_channels: None,
+            affected_channel_groups: None,
+            response: None,
+        }
+    }
+
+    #[cfg(any(feature = "publish", feature = "access", feature = "subscribe"))]
+    #[test]
+    fn thread_status_from_attached_response() {
+        let error = PubNubError::Transport {
+            details: "Service unavailable".into(),
+            status: None,
+            response: None,
+        }
+        .attach_response(TransportResponse {
+            status: 503,
+            ..Default::default()
+        });
+
+        assert!(matches!(
+            error,
+            PubNubError::Transport {
+                status: Some(503),
+                ..
+            }
+        ));
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn classify_reqwest_connect_error() {
+        let error = reqwest::Client::default()
+            .get("http://127.0.0.1:1/time/0")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            PubNubError::from(error),
+            PubNubError::Transport { response: None, .. }
+        ));
+    }
+
+    #[cfg(feature = "reqwest")]
+    #[tokio::test]
+    async fn classify_reqwest_decode_error() {
+        use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{\"malformed\""))
+            .mount(&server)
+            .await;
+
+        let error = reqwest::get(format!("{}/time/0", server.uri()))
+            .await
+            .unwrap()
+            .json::<serde_json::Value>()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            PubNubError::from(error),
+            PubNubError::Deserialization { .. }
+        ));
+    }
+}
@@ -28,6 +28,7 @@ impl CancellationTask {
                 .await
                 .map_err(|err| PubNubError::Transport {
                     details: format!("Cancellation pipe failed: {err}"),
+                    status: None,
                     response: None,
                 })?
                 .eq(&self.id)
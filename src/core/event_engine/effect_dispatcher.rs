@@ -147,7 +147,7 @@ where
     }
 
     /// Remove managed effect.
-    fn remove_managed_effect(&self, effect_id: String) {
+    pub(super) fn remove_managed_effect(&self, effect_id: String) {
         let mut managed = self.managed.write();
         if let Some(position) = managed.iter().position(|ef| ef.id() == effect_id) {
             managed.remove(position);
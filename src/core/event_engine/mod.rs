@@ -4,6 +4,8 @@ use async_channel::Sender;
 use log::error;
 use spin::rwlock::RwLock;
 
+#[cfg(test)]
+use crate::lib::alloc::{vec, vec::Vec};
 use crate::{core::runtime::Runtime, lib::alloc::sync::Arc};
 
 #[doc(inline)]
@@ -129,6 +131,48 @@ where
         }
     }
 
+    /// Process external event and wait for effects completion.
+    ///
+    /// Unlike [`EventEngine::process`], effects requested by transition are
+    /// executed in place (in order of invocation) and events produced by them
+    /// returned to the caller instead of being processed by the event engine.
+    #[cfg(test)]
+    pub(crate) async fn process_async(&self, event: &EI::Event) -> Vec<EI::Event> {
+        if !*self.active.read() {
+            return vec![];
+        }
+
+        let transition = {
+            let state = self.current_state.read();
+            state.transition(event)
+        };
+        let Some(transition) = transition else {
+            return vec![];
+        };
+
+        if let Some(state) = transition.state {
+            let mut writable_state = self.current_state.write();
+            *writable_state = state;
+        }
+
+        let mut events = vec![];
+        for invocation in transition.invocations {
+            let Some(effect) = self.effect_dispatcher.dispatch(&invocation) else {
+                continue;
+            };
+
+            if !effect.is_cancelled() {
+                events.extend(effect.run().await);
+            }
+
+            if invocation.is_managed() {
+                self.effect_dispatcher.remove_managed_effect(effect.id());
+            }
+        }
+
+        events
+    }
+
     /// Process transition.
     ///
     /// This method is responsible for transition maintenance:
@@ -388,6 +432,74 @@ mod should {
         }
     }
 
+    /// Runtime which doesn't depend on `tokio` and runs each spawned task on
+    /// separate thread.
+    #[derive(Clone, Default)]
+    struct ThreadRuntime {
+        spawned: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark()
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+        let mut context = std::task::Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+
+        loop {
+            match future.as_mut().poll(&mut context) {
+                std::task::Poll::Ready(output) => return output,
+                std::task::Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl Runtime for ThreadRuntime {
+        fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static)
+        where
+            R: Send + 'static,
+        {
+            self.spawned
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::thread::spawn(move || block_on(future));
+        }
+
+        async fn sleep(self, delay: u64) {
+            std::thread::sleep(std::time::Duration::from_secs(delay))
+        }
+
+        async fn sleep_microseconds(self, delay: u64) {
+            std::thread::sleep(std::time::Duration::from_micros(delay))
+        }
+    }
+
+    #[test]
+    fn transit_with_custom_runtime() {
+        let runtime = ThreadRuntime::default();
+        let engine = EventEngine::new(TestEffectHandler {}, TestState::NotStarted, runtime.clone());
+
+        engine.process(&TestEvent::One);
+        assert!(matches!(engine.current_state(), TestState::Started));
+
+        // Event engine loop and effects for `NotStarted` exit and `Started`
+        // enter invocations.
+        let spawned = || runtime.spawned.load(std::sync::atomic::Ordering::SeqCst);
+        for _ in 0..100 {
+            if spawned() >= 3 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(spawned(), 3);
+    }
+
     #[tokio::test]
     async fn set_initial_state() {
         let engine = EventEngine::new(TestEffectHandler {}, TestState::NotStarted, TestRuntime {});
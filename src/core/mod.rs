@@ -76,7 +76,7 @@ pub use runtime::Runtime;
 pub mod runtime;
 
 #[doc(inline)]
-pub use data_stream::DataStream;
+pub use data_stream::{DataStream, DataStreamOverflowPolicy};
 pub mod data_stream;
 
 pub(crate) mod utils;
@@ -9,8 +9,13 @@
 //! [`pubnub`]: ../index.html
 
 use getrandom::getrandom;
+use spin::Mutex;
+use std::time::{Duration, Instant};
 
-use crate::{core::PubNubError, lib::alloc::vec::Vec};
+use crate::{
+    core::PubNubError,
+    lib::alloc::{sync::Arc, vec::Vec},
+};
 
 /// List of known endpoint groups (by context)
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -55,6 +60,12 @@ pub enum RequestRetryConfiguration {
     /// Requests shouldn't be tried again.
     None,
 
+    /// Retry the request immediately (without delay).
+    Fixed {
+        /// Number of times a request can be retried.
+        max_retry: u8,
+    },
+
     /// Retry the request after the same amount of time.
     Linear {
         /// The delay between failed retry attempts in seconds.
@@ -67,6 +78,12 @@ pub enum RequestRetryConfiguration {
         ///
         /// Endpoint groups for which automatic retry shouldn't be used.
         excluded_endpoints: Option<Vec<Endpoint>>,
+
+        /// Optional maximum total retry duration in seconds.
+        ///
+        /// Retry attempts will stop as soon as accumulated delay exceeds this
+        /// value.
+        max_elapsed: Option<u32>,
     },
 
     /// Retry the request using exponential amount of time.
@@ -84,6 +101,19 @@ pub enum RequestRetryConfiguration {
         ///
         /// Endpoint groups for which automatic retry shouldn't be used.
         excluded_endpoints: Option<Vec<Endpoint>>,
+
+        /// Optional maximum total retry duration in seconds.
+        ///
+        /// Retry attempts will stop as soon as accumulated delay exceeds this
+        /// value.
+        max_elapsed: Option<u32>,
+
+        /// Optional delay jitter in percent (`0..=100`).
+        ///
+        /// Computed delay will be randomized within `[delay * (1 - jitter),
+        /// delay * (1 + jitter)]` range to spread retry attempts of multiple
+        /// clients in time.
+        jitter: Option<u8>,
     },
 }
 
@@ -123,6 +153,7 @@ impl RequestRetryConfiguration {
             delay: 2,
             max_retry: 10,
             excluded_endpoints: None,
+            max_elapsed: None,
         }
     }
 
@@ -159,9 +190,57 @@ impl RequestRetryConfiguration {
             max_delay: 150,
             max_retry: 6,
             excluded_endpoints: None,
+            max_elapsed: None,
+            jitter: None,
         }
     }
 
+    /// Exclude endpoint groups from automatic retry.
+    ///
+    /// Requests to the `endpoints` won't be retried on failure.
+    ///
+    /// > Note: Only [`RequestRetryConfiguration::Linear`] and
+    /// > [`RequestRetryConfiguration::Exponential`] policies support endpoint
+    /// > exclusion.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pubnub::{core::retry_policy::Endpoint, RequestRetryConfiguration};
+    ///
+    /// let retry_configuration =
+    ///     RequestRetryConfiguration::default_linear().excluding(vec![Endpoint::MessageSend]);
+    /// ```
+    pub fn excluding(mut self, endpoints: Vec<Endpoint>) -> Self {
+        if let Self::Linear {
+            excluded_endpoints, ..
+        }
+        | Self::Exponential {
+            excluded_endpoints, ..
+        } = &mut self
+        {
+            *excluded_endpoints = Some(endpoints);
+        }
+
+        self
+    }
+
+    /// Check whether failed request should be retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the failed request.
+    /// * `attempt` - The attempt count of the request.
+    /// * `status` - HTTP status code of the failed request.
+    ///
+    /// # Returns
+    ///
+    /// `true` if request to not excluded endpoint is allowed to be retried one
+    /// more time.
+    pub fn should_retry(&self, path: &str, attempt: u8, status: u16) -> bool {
+        self.retriable_status(Some(path), &attempt, status)
+    }
+
     /// Check whether next retry `attempt` is allowed.
     ///
     /// # Arguments
@@ -183,17 +262,33 @@ impl RequestRetryConfiguration {
     where
         S: Into<String>,
     {
-        if self.is_excluded_endpoint(path)
-            || self.reached_max_retry(attempt)
-            || matches!(self, RequestRetryConfiguration::None)
-        {
+        let Some(error) = error.filter(|e| e.is_retriable()) else {
             return false;
+        };
+
+        // Timeout and connection errors don't have status, but still retriable.
+        match error.status() {
+            Some(status) => self.retriable_status(path, attempt, status),
+            None => self.retry_allowed(path, attempt),
         }
+    }
 
-        error
-            .and_then(|e| e.transport_response())
-            .map(|response| matches!(response.status, 429 | 500..=599))
-            .unwrap_or(false)
+    /// Check whether request with `status` is allowed to be retried.
+    fn retriable_status<S>(&self, path: Option<S>, attempt: &u8, status: u16) -> bool
+    where
+        S: Into<String>,
+    {
+        self.retry_allowed(path, attempt) && matches!(status, 429 | 500..=599)
+    }
+
+    /// Check whether policy allows one more retry for request to `path`.
+    fn retry_allowed<S>(&self, path: Option<S>, attempt: &u8) -> bool
+    where
+        S: Into<String>,
+    {
+        !(self.is_excluded_endpoint(path)
+            || self.reached_max_retry(attempt)
+            || matches!(self, RequestRetryConfiguration::None))
     }
 
     /// Calculate the delay before retrying a request.
@@ -201,7 +296,8 @@ impl RequestRetryConfiguration {
     /// If the request can be retried based on the given attempt count and
     /// error, the delay is calculated based on the error response status code.
     /// - If the status code is 429 (Too Many Requests), the delay is determined
-    ///   by the `retry-after` header in the response, if present.
+    ///   by the `retry-after` header in the response, if present (`Fixed`
+    ///   policy retries immediately).
     /// - If the status code is in the range 500-599 (Server Error), the delay
     ///   is calculated based on the configured retry strategy.
     ///
@@ -221,34 +317,219 @@ impl RequestRetryConfiguration {
         path: Option<String>,
         attempt: &u8,
         error: Option<&PubNubError>,
+    ) -> Option<u64> {
+        self.retry_delay_in_microseconds(path, attempt, 0, error)
+    }
+
+    /// Calculate the delay before retrying a request with respect to the
+    /// maximum total retry duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Optional path of the failed request.
+    /// * `attempt` - The attempt count of the request.
+    /// * `elapsed` - Accumulated delay (in seconds) of the previous retry
+    ///   attempts.
+    /// * `error` - An optional `PubNubError` representing the error response.
+    ///   If `None`, the request cannot be retried.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pubnub::{core::PubNubError, RequestRetryConfiguration};
+    ///
+    /// let retry_configuration = RequestRetryConfiguration::Linear {
+    ///     delay: 10,
+    ///     max_retry: 5,
+    ///     excluded_endpoints: None,
+    ///     max_elapsed: Some(25),
+    /// };
+    /// let error = PubNubError::Transport {
+    ///     details: "Connection refused".into(),
+    ///     status: None,
+    ///     response: None,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     retry_configuration.retry_delay_with_elapsed(None, &1, 0, Some(&error)),
+    ///     Some(10)
+    /// );
+    /// assert_eq!(
+    ///     retry_configuration.retry_delay_with_elapsed(None, &3, 20, Some(&error)),
+    ///     None
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// An optional `u32` representing the delay in seconds before retrying the
+    /// request. `None` if the request should not be retried or next delay will
+    /// exceed `max_elapsed`.
+    pub fn retry_delay_with_elapsed(
+        &self,
+        path: Option<String>,
+        attempt: &u8,
+        elapsed: u32,
+        error: Option<&PubNubError>,
+    ) -> Option<u32> {
+        const MICROS_IN_SECOND: u64 = 1_000_000;
+
+        self.retry_delay_in_microseconds(
+            path,
+            attempt,
+            u64::from(elapsed) * MICROS_IN_SECOND,
+            error,
+        )
+        .map(|delay| (delay / MICROS_IN_SECOND).min(u64::from(u32::MAX)) as u32)
+    }
+
+    /// Calculate the delay (in microseconds) before retrying a request with
+    /// respect to the maximum total retry duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Optional path of the failed request.
+    /// * `attempt` - The attempt count of the request.
+    /// * `elapsed` - Accumulated delay (in microseconds) of the previous retry
+    ///   attempts.
+    /// * `error` - An optional `PubNubError` representing the error response.
+    ///   If `None`, the request cannot be retried.
+    ///
+    /// # Returns
+    ///
+    /// An optional `u64` representing the delay in microseconds before retrying
+    /// the request. `None` if the request should not be retried or next delay
+    /// will exceed `max_elapsed`.
+    pub(crate) fn retry_delay_in_microseconds(
+        &self,
+        path: Option<String>,
+        attempt: &u8,
+        elapsed: u64,
+        error: Option<&PubNubError>,
     ) -> Option<u64> {
         if !self.retriable(path, attempt, error) {
             return None;
         }
 
-        error
-            .and_then(|err| err.transport_response())
-            .map(|response| match response.status {
-                // Respect service requested delay.
-                429 if response.headers.contains_key("retry-after") => {
+        let response = error.and_then(|err| err.transport_response());
+
+        match (error.and_then(|err| err.status()), response) {
+            // Fixed policy retries immediately, even when service asks to wait.
+            (Some(429), _) if matches!(self, Self::Fixed { .. }) => self.strategy_delay(attempt),
+            // Respect service requested delay.
+            (Some(429), Some(response)) if response.headers.contains_key("retry-after") => {
+                Self::delay_in_microseconds(
                     (!matches!(self, Self::None))
                         .then(|| response.headers.get("retry-after"))
                         .flatten()
-                        .and_then(|value| value.parse::<u64>().ok())
+                        .and_then(|value| Self::parse_retry_after(value)),
+                )
+            }
+            (Some(500..=599) | None, _) => self.strategy_delay(attempt),
+            _ => None,
+        }
+        .filter(|delay| !self.exceeds_max_elapsed(elapsed.saturating_add(*delay)))
+    }
+
+    /// Calculate delay (in microseconds) according to the retry strategy.
+    fn strategy_delay(&self, attempt: &u8) -> Option<u64> {
+        match self {
+            Self::None => None,
+            Self::Fixed { .. } => Some(0),
+            Self::Linear { delay, .. } => Self::delay_in_microseconds(Some(*delay)),
+            Self::Exponential {
+                min_delay,
+                max_delay,
+                jitter,
+                ..
+            } => {
+                let delay = Self::delay_in_microseconds(Some(
+                    (*min_delay * 2_u64.pow((*attempt - 1) as u32)).min(*max_delay),
+                ));
+
+                match (delay, jitter) {
+                    (Some(delay), Some(jitter)) => Some(Self::jittered_delay(
+                        delay,
+                        *jitter,
+                        Self::random_value().unwrap_or(u64::MAX / 2),
+                    )),
+                    _ => delay,
                 }
-                500..=599 => match self {
-                    Self::None => None,
-                    Self::Linear { delay, .. } => Some(*delay),
-                    Self::Exponential {
-                        min_delay,
-                        max_delay,
-                        ..
-                    } => Some((*min_delay * 2_u64.pow((*attempt - 1) as u32)).min(*max_delay)),
-                },
-                _ => None,
-            })
-            .map(Self::delay_in_microseconds)
-            .unwrap_or(None)
+            }
+        }
+    }
+
+    /// Parse `retry-after` header value.
+    ///
+    /// Header value can be represented by the number of seconds or by the
+    /// HTTP-date (RFC 7231) after which request can be retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `retry-after` header value.
+    ///
+    /// # Returns
+    ///
+    /// Delay in seconds or `None` if value can't be parsed.
+    fn parse_retry_after(value: &str) -> Option<u64> {
+        if let Ok(delay) = value.trim().parse::<u64>() {
+            return Some(delay);
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let date = Self::parse_http_date(value)?;
+            let now = time::OffsetDateTime::now_utc().unix_timestamp();
+            Some(date.saturating_sub(now).max(0) as u64)
+        }
+
+        #[cfg(not(feature = "std"))]
+        None
+    }
+
+    /// Parse HTTP-date in the preferred IMF-fixdate format.
+    ///
+    /// Expected value format: `Sun, 06 Nov 1994 08:49:37 GMT`.
+    ///
+    /// # Returns
+    ///
+    /// Unix timestamp (in seconds) or `None` if value can't be parsed.
+    #[cfg(feature = "std")]
+    fn parse_http_date(value: &str) -> Option<i64> {
+        let mut components = value.split_whitespace();
+        let _weekday = components.next().filter(|day| day.ends_with(','))?;
+        let day = components.next()?.parse::<u8>().ok()?;
+        let month = match components.next()? {
+            "Jan" => time::Month::January,
+            "Feb" => time::Month::February,
+            "Mar" => time::Month::March,
+            "Apr" => time::Month::April,
+            "May" => time::Month::May,
+            "Jun" => time::Month::June,
+            "Jul" => time::Month::July,
+            "Aug" => time::Month::August,
+            "Sep" => time::Month::September,
+            "Oct" => time::Month::October,
+            "Nov" => time::Month::November,
+            "Dec" => time::Month::December,
+            _ => return None,
+        };
+        let year = components.next()?.parse::<i32>().ok()?;
+        let mut clock = components.next()?.split(':').map(|c| c.parse::<u8>().ok());
+        let (hour, minute, second) = (clock.next()??, clock.next()??, clock.next()??);
+
+        if components.next() != Some("GMT") || components.next().is_some() {
+            return None;
+        }
+
+        let date = time::Date::from_calendar_date(year, month, day).ok()?;
+        let time = time::Time::from_hms(hour, minute, second).ok()?;
+
+        Some(
+            time::PrimitiveDateTime::new(date, time)
+                .assume_utc()
+                .unix_timestamp(),
+        )
     }
 
     /// Check whether failed endpoint has been excluded or not.
@@ -286,9 +567,28 @@ impl RequestRetryConfiguration {
     /// Check whether reached maximum retry count or not.
     fn reached_max_retry(&self, attempt: &u8) -> bool {
         match self {
-            Self::Linear { max_retry, .. } | Self::Exponential { max_retry, .. } => {
-                attempt.gt(max_retry)
+            Self::Fixed { max_retry }
+            | Self::Linear { max_retry, .. }
+            | Self::Exponential { max_retry, .. } => attempt.gt(max_retry),
+            _ => false,
+        }
+    }
+
+    /// Check whether accumulated delay exceeds maximum total retry duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `elapsed` - Accumulated delay in microseconds.
+    fn exceeds_max_elapsed(&self, elapsed: u64) -> bool {
+        match self {
+            Self::Linear {
+                max_elapsed: Some(max_elapsed),
+                ..
             }
+            | Self::Exponential {
+                max_elapsed: Some(max_elapsed),
+                ..
+            } => elapsed > u64::from(*max_elapsed) * 1_000_000,
             _ => false,
         }
     }
@@ -303,20 +603,58 @@ impl RequestRetryConfiguration {
     ///
     /// * `Some(delay_in_microseconds)` - The delay in microseconds.
     /// * `None` - If `delay_in_seconds` is `None`.
+    ///
+    /// > Note: Random jitter is not added to the zero delay, so the request
+    /// > will be retried immediately.
     fn delay_in_microseconds(delay_in_seconds: Option<u64>) -> Option<u64> {
         let Some(delay_in_seconds) = delay_in_seconds else {
             return None;
         };
 
+        if delay_in_seconds == 0 {
+            return Some(0);
+        }
+
         const MICROS_IN_SECOND: u64 = 1_000_000;
         let delay = delay_in_seconds * MICROS_IN_SECOND;
-        let mut random_bytes = [0u8; 8];
 
-        if getrandom(&mut random_bytes).is_err() {
+        let Some(random) = Self::random_value() else {
             return Some(delay);
-        }
+        };
+
+        Some(delay + random % MICROS_IN_SECOND)
+    }
+
+    /// Apply random `jitter` to the computed delay.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The delay in microseconds.
+    /// * `jitter` - Jitter in percent which will be clamped to the `0..=100`
+    ///   range.
+    /// * `random` - Random value which is used to compute multiplier.
+    ///
+    /// # Returns
+    ///
+    /// Delay in microseconds within `[delay * (1 - jitter), delay * (1 +
+    /// jitter)]` range.
+    fn jittered_delay(delay: u64, jitter: u8, random: u64) -> u64 {
+        let jitter = jitter.min(100) as f64 / 100.0;
+        let multiplier = 1.0 - jitter + 2.0 * jitter * (random as f64 / u64::MAX as f64);
 
-        Some(delay + u64::from_be_bytes(random_bytes) % MICROS_IN_SECOND)
+        (delay as f64 * multiplier) as u64
+    }
+
+    /// Random value source.
+    ///
+    /// # Returns
+    ///
+    /// Random `u64` value or `None` if it wasn't possible to get random bytes.
+    fn random_value() -> Option<u64> {
+        let mut random_bytes = [0u8; 8];
+        getrandom(&mut random_bytes).ok()?;
+
+        Some(u64::from_be_bytes(random_bytes))
     }
 }
 
@@ -349,6 +687,133 @@ impl From<String> for Endpoint {
     }
 }
 
+/// Client-wide retry budget.
+///
+/// Token bucket which limits total number of request retries made by the
+/// client (regardless of the endpoint and retry policy). Each retry attempt
+/// consumes one token and bucket refills with `refill_rate` tokens per second
+/// up to its `capacity`. When budget exhausted, failed requests won't be
+/// retried until the bucket refills.
+///
+/// Budget clones share the same bucket.
+///
+/// # Example
+///
+/// ```
+/// use pubnub::{
+///     core::retry_policy::RetryBudget, Keyset, PubNubClientBuilder, RequestRetryConfiguration,
+/// };
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = PubNubClientBuilder::with_reqwest_transport()
+///     .with_keyset(Keyset {
+///         publish_key: Some("pub-c-abc123"),
+///         subscribe_key: "sub-c-abc123",
+///         secret_key: None,
+///     })
+///     .with_user_id("my-user-id")
+///     .with_retry_configuration(RequestRetryConfiguration::default_linear())
+///     .with_retry_budget(RetryBudget::new(20, 2))
+///     .build()?;
+/// #    Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    /// Maximum number of retry tokens in the bucket.
+    capacity: u32,
+
+    /// Number of retry tokens added to the bucket each second.
+    refill_rate: u32,
+
+    /// Current bucket state.
+    state: Arc<Mutex<RetryBudgetState>>,
+}
+
+/// Retry budget bucket state.
+#[derive(Debug)]
+struct RetryBudgetState {
+    /// Number of available retry tokens.
+    tokens: u32,
+
+    /// Time of the last bucket refill.
+    refilled_at: Instant,
+}
+
+impl RetryBudget {
+    /// Creates a new retry budget with full bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - Maximum number of retries which can be made in a row.
+    /// * `refill_rate` - Number of retries restored each second.
+    pub fn new(capacity: u32, refill_rate: u32) -> Self {
+        Self {
+            capacity,
+            refill_rate,
+            state: Arc::new(Mutex::new(RetryBudgetState {
+                tokens: capacity,
+                refilled_at: Instant::now(),
+            })),
+        }
+    }
+
+    /// Try to consume retry token.
+    ///
+    /// # Returns
+    ///
+    /// `true` if there was token for retry attempt.
+    pub fn try_acquire(&self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    /// Try to consume retry token at specified point in time.
+    pub(crate) fn try_acquire_at(&self, now: Instant) -> bool {
+        let mut state = self.state.lock();
+        self.refill(&mut state, now);
+
+        if state.tokens == 0 {
+            return false;
+        }
+
+        state.tokens -= 1;
+        true
+    }
+
+    /// Add tokens accumulated since last refill.
+    fn refill(&self, state: &mut RetryBudgetState, now: Instant) {
+        if self.refill_rate == 0 || state.tokens >= self.capacity {
+            state.refilled_at = now;
+            return;
+        }
+
+        let elapsed = now.saturating_duration_since(state.refilled_at);
+        let tokens = elapsed.as_millis() * self.refill_rate as u128 / 1000;
+        if tokens == 0 {
+            return;
+        }
+
+        let tokens = tokens.min((self.capacity - state.tokens) as u128) as u32;
+        state.tokens += tokens;
+        state.refilled_at = if state.tokens == self.capacity {
+            now
+        } else {
+            // Keep time which is not enough for the next token.
+            state.refilled_at
+                + Duration::from_millis(tokens as u64 * 1000 / self.refill_rate as u64)
+        };
+    }
+}
+
+// Budgets are equal when configured with the same capacity and refill rate.
+impl PartialEq for RetryBudget {
+    fn eq(&self, other: &Self) -> bool {
+        self.capacity == other.capacity && self.refill_rate == other.refill_rate
+    }
+}
+
+impl Eq for RetryBudget {}
+
 #[cfg(test)]
 mod should {
     use super::*;
@@ -365,13 +830,31 @@ mod should {
     }
 
     fn too_many_requests_error_response() -> TransportResponse {
+        too_many_requests_error_response_with_retry_after("150")
+    }
+
+    fn too_many_requests_error_response_with_retry_after(value: &str) -> TransportResponse {
         TransportResponse {
             status: 429,
-            headers: HashMap::from([(String::from("retry-after"), String::from("150"))]),
+            headers: HashMap::from([(String::from("retry-after"), String::from(value))]),
             ..Default::default()
         }
     }
 
+    /// Format date in `retry-after` HTTP-date format.
+    fn http_date(date: time::OffsetDateTime) -> String {
+        format!(
+            "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+            &date.weekday().to_string()[..3],
+            date.day(),
+            &date.month().to_string()[..3],
+            date.year(),
+            date.hour(),
+            date.minute(),
+            date.second()
+        )
+    }
+
     fn server_error_response() -> TransportResponse {
         TransportResponse {
             status: 500,
@@ -404,6 +887,129 @@ mod should {
         assert!(matches!(policy, RequestRetryConfiguration::None));
     }
 
+    #[test]
+    fn parse_retry_after_seconds() {
+        assert_eq!(
+            RequestRetryConfiguration::parse_retry_after("150"),
+            Some(150)
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_http_date() {
+        let date = time::OffsetDateTime::now_utc() + time::Duration::seconds(150);
+        let delay = RequestRetryConfiguration::parse_retry_after(&http_date(date)).unwrap();
+
+        assert!((149..=150).contains(&delay));
+    }
+
+    #[test]
+    fn parse_retry_after_http_date_in_past() {
+        assert_eq!(
+            RequestRetryConfiguration::parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn not_parse_malformed_retry_after() {
+        assert_eq!(RequestRetryConfiguration::parse_retry_after("soon"), None);
+        assert_eq!(
+            RequestRetryConfiguration::parse_retry_after("Sun, 06 Nov 1994 08:49:37 PST"),
+            None
+        );
+    }
+
+    #[test]
+    fn not_retry_excluded_endpoint() {
+        let policy =
+            RequestRetryConfiguration::default_linear().excluding(vec![Endpoint::MessageSend]);
+
+        assert!(policy.should_retry("/v2/subscribe/demo/ch1/0", 1, 500));
+        assert!(!policy.should_retry("/publish/demo/demo/0/ch1/0/%22hello%22", 1, 500));
+        assert!(!policy.should_retry("/v2/subscribe/demo/ch1/0", 1, 400));
+    }
+
+    #[test]
+    fn retry_transport_error_with_status() {
+        let policy = RequestRetryConfiguration::Fixed { max_retry: 2 };
+
+        assert!(policy.retriable(
+            None::<String>,
+            &1,
+            Some(&PubNubError::Transport {
+                details: "test".into(),
+                status: Some(503),
+                response: None,
+            })
+        ));
+        assert!(policy.retriable(
+            None::<String>,
+            &1,
+            Some(&PubNubError::Transport {
+                details: "test".into(),
+                status: None,
+                response: None,
+            })
+        ));
+    }
+
+    #[test]
+    fn retry_errors_without_status() {
+        let policy = RequestRetryConfiguration::Linear {
+            delay: 2,
+            max_retry: 2,
+            excluded_endpoints: None,
+            max_elapsed: None,
+        };
+        let error = PubNubError::Timeout {
+            endpoint: "/time/0".into(),
+        };
+
+        assert!(policy.retriable(None::<String>, &2, Some(&error)));
+        assert!(!policy.retriable(None::<String>, &3, Some(&error)));
+        assert!(policy
+            .retry_delay(None, &1, Some(&error))
+            .is_some_and(|delay| (2_000_000..3_000_000).contains(&delay)));
+        assert!(!RequestRetryConfiguration::None.retriable(None::<String>, &1, Some(&error)));
+    }
+
+    #[test]
+    fn not_retry_client_api_errors() {
+        let policy = RequestRetryConfiguration::Fixed { max_retry: 2 };
+
+        [400, 403, 404].into_iter().for_each(|status| {
+            assert!(!policy.retriable(
+                Some("/v2/subscribe/demo/ch1/0"),
+                &1,
+                Some(&PubNubError::general_api_error("test", Some(status), None))
+            ));
+        });
+        assert!(policy.retriable(
+            Some("/v2/subscribe/demo/ch1/0"),
+            &1,
+            Some(&PubNubError::general_api_error(
+                "test",
+                Some(429),
+                Some(Box::new(too_many_requests_error_response()))
+            ))
+        ));
+    }
+
+    #[test]
+    fn not_retry_transport_error_after_max_retry() {
+        let policy = RequestRetryConfiguration::Fixed { max_retry: 2 };
+        let error = PubNubError::Transport {
+            details: "test".into(),
+            status: Some(502),
+            response: None,
+        };
+
+        assert!(policy.retriable(None::<String>, &2, Some(&error)));
+        assert!(!policy.retriable(None::<String>, &3, Some(&error)));
+        assert!(!policy.retriable(None::<String>, &1, None));
+    }
+
     mod none_policy {
         use super::*;
 
@@ -456,6 +1062,123 @@ mod should {
         }
     }
 
+    mod fixed_policy {
+        use super::*;
+
+        #[test]
+        fn return_none_delay_for_client_error_response() {
+            let policy = RequestRetryConfiguration::Fixed { max_retry: 5 };
+
+            assert_eq!(
+                policy.retry_delay(
+                    None,
+                    &1,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(client_error_response()))
+                    ))
+                ),
+                None
+            );
+        }
+
+        #[test]
+        fn return_zero_delay_for_server_error_response() {
+            let policy = RequestRetryConfiguration::Fixed { max_retry: 5 };
+
+            assert_eq!(
+                policy.retry_delay(
+                    None,
+                    &1,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(server_error_response()))
+                    ))
+                ),
+                Some(0)
+            );
+
+            assert_eq!(
+                policy.retry_delay(
+                    None,
+                    &2,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(server_error_response()))
+                    ))
+                ),
+                Some(0)
+            );
+        }
+
+        #[test]
+        fn return_none_delay_when_reach_max_retry_for_server_error_response() {
+            let policy = RequestRetryConfiguration::Fixed { max_retry: 3 };
+
+            assert_eq!(
+                policy.retry_delay(
+                    None,
+                    &3,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(server_error_response()))
+                    ))
+                ),
+                Some(0)
+            );
+
+            assert_eq!(
+                policy.retry_delay(
+                    None,
+                    &4,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(server_error_response()))
+                    ))
+                ),
+                None
+            );
+        }
+
+        #[test]
+        fn return_zero_delay_for_too_many_requests_error_response() {
+            let policy = RequestRetryConfiguration::Fixed { max_retry: 2 };
+
+            // `Retry-After` header from 'too_many_requests_error_response' is
+            // ignored.
+            assert_eq!(
+                policy.retry_delay(
+                    None,
+                    &2,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(too_many_requests_error_response()))
+                    ))
+                ),
+                Some(0)
+            );
+
+            assert_eq!(
+                policy.retry_delay(
+                    None,
+                    &3,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(too_many_requests_error_response()))
+                    ))
+                ),
+                None
+            );
+        }
+    }
+
     mod linear_policy {
         use super::*;
 
@@ -465,6 +1188,7 @@ mod should {
                 delay: 10,
                 max_retry: 5,
                 excluded_endpoints: None,
+                max_elapsed: None,
             };
 
             assert_eq!(
@@ -488,6 +1212,7 @@ mod should {
                 delay: expected_delay,
                 max_retry: 5,
                 excluded_endpoints: None,
+                max_elapsed: None,
             };
 
             assert!(is_equal_with_accuracy(
@@ -524,6 +1249,7 @@ mod should {
                 delay: expected_delay,
                 max_retry: 3,
                 excluded_endpoints: None,
+                max_elapsed: None,
             };
 
             assert!(is_equal_with_accuracy(
@@ -559,9 +1285,10 @@ mod should {
                 delay: 10,
                 max_retry: 2,
                 excluded_endpoints: None,
+                max_elapsed: None,
             };
 
-            // 150 is from 'server_error_response' `Retry-After` header.
+            // 150 is from 'too_many_requests_error_response' `Retry-After` header.
             assert!(is_equal_with_accuracy(
                 policy.retry_delay(
                     None,
@@ -575,6 +1302,88 @@ mod should {
                 Some(150)
             ));
         }
+
+        #[test]
+        fn return_service_delay_for_too_many_requests_error_response_with_http_date() {
+            let policy = RequestRetryConfiguration::Linear {
+                delay: 10,
+                max_retry: 2,
+                excluded_endpoints: None,
+                max_elapsed: None,
+            };
+            let date = time::OffsetDateTime::now_utc() + time::Duration::seconds(150);
+
+            let delay = policy
+                .retry_delay(
+                    None,
+                    &2,
+                    Some(&PubNubError::general_api_error(
+                        "test",
+                        None,
+                        Some(Box::new(too_many_requests_error_response_with_retry_after(
+                            &http_date(date),
+                        ))),
+                    )),
+                )
+                .unwrap();
+
+            assert!((149_000_000..151_000_000).contains(&delay));
+        }
+        #[test]
+        fn return_none_delay_when_exceed_max_elapsed_for_server_error_response() {
+            let expected_delay: u64 = 10;
+            let policy = RequestRetryConfiguration::Linear {
+                delay: expected_delay,
+                max_retry: 5,
+                excluded_endpoints: None,
+                max_elapsed: Some(25),
+            };
+            let error = PubNubError::general_api_error(
+                "test",
+                None,
+                Some(Box::new(server_error_response())),
+            );
+
+            assert_eq!(
+                policy.retry_delay_with_elapsed(None, &1, 0, Some(&error)),
+                Some(expected_delay as u32)
+            );
+
+            assert_eq!(
+                policy.retry_delay_with_elapsed(None, &2, 11, Some(&error)),
+                Some(expected_delay as u32)
+            );
+
+            assert_eq!(
+                policy.retry_delay_with_elapsed(None, &3, 22, Some(&error)),
+                None
+            );
+        }
+
+        #[test]
+        fn return_none_delay_when_exceed_max_elapsed_in_microseconds() {
+            let policy = RequestRetryConfiguration::Linear {
+                delay: 10,
+                max_retry: 5,
+                excluded_endpoints: None,
+                max_elapsed: Some(25),
+            };
+            let error = PubNubError::general_api_error(
+                "test",
+                None,
+                Some(Box::new(server_error_response())),
+            );
+
+            assert!(is_equal_with_accuracy(
+                policy.retry_delay_in_microseconds(None, &2, 11_000_000, Some(&error)),
+                Some(10)
+            ));
+
+            assert_eq!(
+                policy.retry_delay_in_microseconds(None, &3, 22_000_000, Some(&error)),
+                None
+            );
+        }
     }
 
     mod exponential_policy {
@@ -588,6 +1397,8 @@ mod should {
                 max_delay: 100,
                 max_retry: 2,
                 excluded_endpoints: None,
+                max_elapsed: None,
+                jitter: None,
             };
 
             assert_eq!(
@@ -612,6 +1423,8 @@ mod should {
                 max_delay: 100,
                 max_retry: 3,
                 excluded_endpoints: None,
+                max_elapsed: None,
+                jitter: None,
             };
 
             assert!(is_equal_with_accuracy(
@@ -649,6 +1462,8 @@ mod should {
                 max_delay: 100,
                 max_retry: 3,
                 excluded_endpoints: None,
+                max_elapsed: None,
+                jitter: None,
             };
 
             assert!(is_equal_with_accuracy(
@@ -687,6 +1502,8 @@ mod should {
                 max_delay,
                 max_retry: 5,
                 excluded_endpoints: None,
+                max_elapsed: None,
+                jitter: None,
             };
 
             assert!(is_equal_with_accuracy(
@@ -723,9 +1540,11 @@ mod should {
                 max_delay: 100,
                 max_retry: 2,
                 excluded_endpoints: None,
+                max_elapsed: None,
+                jitter: None,
             };
 
-            // 150 is from 'server_error_response' `Retry-After` header.
+            // 150 is from 'too_many_requests_error_response' `Retry-After` header.
             assert!(is_equal_with_accuracy(
                 policy.retry_delay(
                     None,
@@ -739,5 +1558,112 @@ mod should {
                 Some(150)
             ));
         }
+
+        #[test]
+        fn return_jittered_delay_for_server_error_response() {
+            let expected_delay = 8;
+            let jitter = 50;
+            let policy = RequestRetryConfiguration::Exponential {
+                min_delay: expected_delay,
+                max_delay: 100,
+                max_retry: 3,
+                excluded_endpoints: None,
+                max_elapsed: None,
+                jitter: Some(jitter),
+            };
+
+            (0..100).for_each(|_| {
+                let delay = policy
+                    .retry_delay(
+                        None,
+                        &1,
+                        Some(&PubNubError::general_api_error(
+                            "test",
+                            None,
+                            Some(Box::new(server_error_response())),
+                        )),
+                    )
+                    .unwrap();
+                let lower = (expected_delay as f64 * 1_000_000.0 * 0.5) as u64;
+                let upper = ((expected_delay * 1_000_000 + 999_999) as f64 * 1.5) as u64;
+
+                assert!((lower..=upper).contains(&delay));
+            });
+        }
+
+        #[test]
+        fn keep_jittered_delay_within_bounds() {
+            let delay: u64 = 8_000_000;
+            let jitter = 30;
+            let lower = (delay as f64 * 0.7) as u64;
+            let upper = (delay as f64 * 1.3) as u64;
+
+            // Simple linear congruential generator with a fixed seed.
+            let mut seed: u64 = 42;
+            (0..1000).for_each(|_| {
+                seed = seed
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let jittered = RequestRetryConfiguration::jittered_delay(delay, jitter, seed);
+
+                assert!((lower..=upper).contains(&jittered));
+            });
+
+            assert_eq!(
+                RequestRetryConfiguration::jittered_delay(delay, jitter, 0),
+                lower
+            );
+            assert_eq!(
+                RequestRetryConfiguration::jittered_delay(delay, 0, u64::MAX),
+                delay
+            );
+            assert_eq!(RequestRetryConfiguration::jittered_delay(delay, 200, 0), 0);
+        }
+    }
+
+    mod retry_budget {
+        use super::*;
+
+        #[test]
+        fn fail_when_budget_exhausted() {
+            let budget = RetryBudget::new(2, 1);
+            let now = Instant::now();
+
+            assert!(budget.try_acquire_at(now));
+            assert!(budget.try_acquire_at(now));
+            assert!(!budget.try_acquire_at(now));
+        }
+
+        #[test]
+        fn refill_budget_over_time() {
+            let budget = RetryBudget::new(2, 2);
+            let now = Instant::now();
+            (0..2).for_each(|_| assert!(budget.try_acquire_at(now)));
+            assert!(!budget.try_acquire_at(now + Duration::from_millis(400)));
+
+            assert!(budget.try_acquire_at(now + Duration::from_millis(500)));
+            assert!(!budget.try_acquire_at(now + Duration::from_millis(600)));
+            assert!(budget.try_acquire_at(now + Duration::from_millis(1000)));
+        }
+
+        #[test]
+        fn not_refill_budget_above_capacity() {
+            let budget = RetryBudget::new(1, 10);
+            let now = Instant::now();
+            assert!(budget.try_acquire_at(now));
+
+            let later = now + Duration::from_secs(60);
+            assert!(budget.try_acquire_at(later));
+            assert!(!budget.try_acquire_at(later));
+        }
+
+        #[test]
+        fn share_budget_between_clones() {
+            let budget = RetryBudget::new(1, 0);
+            let clone = budget.clone();
+
+            assert!(clone.try_acquire());
+            assert!(!budget.try_acquire());
+        }
     }
 }
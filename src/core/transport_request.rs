@@ -8,10 +8,11 @@
 //! [`pubnub`]: ../index.html
 
 use crate::{
-    core::PubNubError,
+    core::{utils::encoding::url_encode, PubNubError},
     lib::{
         alloc::{
             boxed::Box,
+            format,
             sync::Arc,
             {string::String, vec::Vec},
         },
@@ -21,7 +22,9 @@ use crate::{
 };
 
 #[cfg(feature = "std")]
-use crate::core::{runtime::RuntimeSupport, RequestRetryConfiguration, Runtime};
+use crate::core::{
+    retry_policy::RetryBudget, runtime::RuntimeSupport, RequestRetryConfiguration, Runtime,
+};
 
 type DeserializerClosure<B> = Box<dyn FnOnce(&[u8]) -> Result<B, PubNubError>>;
 
@@ -42,6 +45,12 @@ pub enum TransportMethod {
 
     /// The DELETE method.
     Delete,
+
+    /// The PATCH method.
+    Patch,
+
+    /// The HEAD method.
+    Head,
 }
 
 impl Display for TransportMethod {
@@ -53,6 +62,8 @@ impl Display for TransportMethod {
                 TransportMethod::Get => "GET",
                 TransportMethod::Post => "POST",
                 TransportMethod::Delete => "DELETE",
+                TransportMethod::Patch => "PATCH",
+                TransportMethod::Head => "HEAD",
             }
         )
     }
@@ -90,6 +101,59 @@ pub struct TransportRequest {
 }
 
 impl TransportRequest {
+    /// Full request URL.
+    ///
+    /// Compose URL from provided `base` origin, request `path` and query
+    /// parameters. Query parameters sorted by name (for deterministic result)
+    /// and their values are URL-encoded.
+    ///
+    /// `base` is ignored if `path` is an absolute URL (for example, when
+    /// request should be sent to the external file storage).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::core::TransportRequest;
+    ///
+    /// let request = TransportRequest {
+    ///     path: "/time/0".into(),
+    ///     query_parameters: [("uuid".into(), "user id".into())].into(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     request.url("https://ps.pndsn.com"),
+    ///     "https://ps.pndsn.com/time/0?uuid=user%20id"
+    /// );
+    /// ```
+    pub fn url(&self, base: &str) -> String {
+        let base = if self.is_absolute() { "" } else { base };
+
+        if self.query_parameters.is_empty() {
+            return format!("{base}{}", self.path);
+        }
+
+        let mut query_parameters = self.query_parameters.iter().collect::<Vec<_>>();
+        query_parameters.sort_by_key(|(key, _)| *key);
+
+        let query = query_parameters
+            .iter()
+            .map(|(key, value)| format!("{key}={}", url_encode(value.as_bytes())))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        format!("{base}{}?{query}", self.path)
+    }
+
+    /// Whether request `path` is an absolute URL or not.
+    ///
+    /// Requests with absolute URL target services outside of [`PubNub API`].
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    pub(crate) fn is_absolute(&self) -> bool {
+        self.path.starts_with("https://") || self.path.starts_with("http://")
+    }
+
     /// Send async request and process [`PubNub API`] response.
     ///
     /// [`PubNub API`]: https://www.pubnub.com/docs
@@ -98,8 +162,9 @@ impl TransportRequest {
         &self,
         transport: &T,
         deserializer: Arc<D>,
-        #[cfg(feature = "std")] retry_configuration: RequestRetryConfiguration,
-        #[cfg(feature = "std")] runtime: RuntimeSupport,
+        #[cfg(feature = "std")] retry_configuration: &RequestRetryConfiguration,
+        #[cfg(feature = "std")] retry_budget: Option<&RetryBudget>,
+        #[cfg(feature = "std")] runtime: &RuntimeSupport,
     ) -> Result<R, PubNubError>
     where
         B: for<'de> super::Deserialize<'de>,
@@ -110,7 +175,9 @@ impl TransportRequest {
         #[cfg(feature = "std")]
         {
             let mut last_result;
-            let mut retry_attempt = 0_u8;
+            // Retry policy counts attempts starting from `1`.
+            let mut retry_attempt = 1_u8;
+            let mut retry_elapsed = 0_u64;
 
             loop {
                 let deserializer_clone = deserializer.clone();
@@ -133,12 +200,19 @@ impl TransportRequest {
                     break;
                 }
 
-                if let Some(delay) = retry_configuration.retry_delay(
+                if let Some(delay) = retry_configuration.retry_delay_in_microseconds(
                     Some(self.path.clone()),
                     &retry_attempt,
+                    retry_elapsed,
                     Some(error),
                 ) {
+                    // Client-wide budget prevents retry storms during outage.
+                    if retry_budget.is_some_and(|budget| !budget.try_acquire()) {
+                        return Err(Self::retry_budget_exhausted(error));
+                    }
+
                     retry_attempt += 1;
+                    retry_elapsed += delay;
                     runtime.clone().sleep_microseconds(delay).await;
                 } else {
                     break;
@@ -168,6 +242,7 @@ impl TransportRequest {
         transport: &T,
         deserializer: Arc<D>,
         #[cfg(feature = "std")] retry_configuration: &RequestRetryConfiguration,
+        #[cfg(feature = "std")] retry_budget: Option<&RetryBudget>,
         #[cfg(feature = "std")] runtime: &RuntimeSupport,
     ) -> Result<R, PubNubError>
     where
@@ -179,7 +254,9 @@ impl TransportRequest {
         #[cfg(feature = "std")]
         {
             let mut last_result;
-            let mut retry_attempt = 0_u8;
+            // Retry policy counts attempts starting from `1`.
+            let mut retry_attempt = 1_u8;
+            let mut retry_elapsed = 0_u64;
 
             loop {
                 let deserializer_clone = deserializer.clone();
@@ -202,12 +279,19 @@ impl TransportRequest {
                     break;
                 }
 
-                if let Some(delay) = retry_configuration.retry_delay(
+                if let Some(delay) = retry_configuration.retry_delay_in_microseconds(
                     Some(self.path.clone()),
                     &retry_attempt,
+                    retry_elapsed,
                     Some(error),
                 ) {
+                    // Client-wide budget prevents retry storms during outage.
+                    if retry_budget.is_some_and(|budget| !budget.try_acquire()) {
+                        return Err(Self::retry_budget_exhausted(error));
+                    }
+
                     retry_attempt += 1;
+                    retry_elapsed += delay;
                     runtime.clone().sleep_microseconds(delay).await;
                 } else {
                     break;
@@ -274,6 +358,17 @@ impl TransportRequest {
         )
     }
 
+    /// Create error for request which can't be retried because client-wide
+    /// retry budget has been exhausted.
+    #[cfg(feature = "std")]
+    fn retry_budget_exhausted(error: &PubNubError) -> PubNubError {
+        PubNubError::Transport {
+            details: format!("Retry budget exhausted: {error}"),
+            status: error.status(),
+            response: error.transport_response(),
+        }
+    }
+
     /// Deserialize [`PubNub API`] response.
     ///
     /// [`PubNub API`]: https://www.pubnub.com/docs
@@ -292,7 +387,7 @@ impl TransportRequest {
                 if deserialize_result.is_err() && response.status >= 500 {
                     Err(PubNubError::general_api_error(
                         "Unexpected service response",
-                        None,
+                        Some(response.status),
                         Some(Box::new(response.clone())),
                     ))
                 } else {
@@ -302,7 +397,7 @@ impl TransportRequest {
             .map_or(
                 Err(PubNubError::general_api_error(
                     "No body in the response!",
-                    None,
+                    Some(response.status),
                     Some(Box::new(response.clone())),
                 )),
                 |response_body| {
@@ -315,3 +410,63 @@ impl TransportRequest {
             )
     }
 }
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::lib::alloc::string::ToString;
+
+    fn request(query_parameters: &[(&str, &str)]) -> TransportRequest {
+        TransportRequest {
+            path: "/publish/pub_key/sub_key/0/channel/0".into(),
+            query_parameters: query_parameters
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn compose_url_without_query() {
+        assert_eq!(
+            request(&[]).url("https://ps.pndsn.com"),
+            "https://ps.pndsn.com/publish/pub_key/sub_key/0/channel/0"
+        );
+    }
+
+    #[test]
+    fn compose_url_with_single_query_parameter() {
+        assert_eq!(
+            request(&[("uuid", "user")]).url("https://ps.pndsn.com"),
+            "https://ps.pndsn.com/publish/pub_key/sub_key/0/channel/0?uuid=user"
+        );
+    }
+
+    #[test]
+    fn compose_url_with_sorted_and_encoded_query_parameters() {
+        assert_eq!(
+            request(&[
+                ("uuid", "user"),
+                ("meta", "{\"k\":\"v w\"}"),
+                ("auth", "a&b")
+            ])
+            .url("https://ps.pndsn.com"),
+            "https://ps.pndsn.com/publish/pub_key/sub_key/0/channel/0\
+            ?auth=a%26b&meta=%7B%22k%22%3A%22v%20w%22%7D&uuid=user"
+        );
+    }
+
+    #[test]
+    fn compose_url_with_absolute_path() {
+        let request = TransportRequest {
+            path: "https://files.example.com/bucket".into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            request.url("https://ps.pndsn.com"),
+            "https://files.example.com/bucket"
+        );
+    }
+}
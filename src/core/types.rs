@@ -9,7 +9,11 @@ use crate::lib::alloc::string::String;
 /// Some endpoints only require [`HashMap`], which should not have nested
 /// collections. This requirement is implemented through this type.
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
 pub enum ScalarValue {
     /// `String` value stored for specific key in [`HashMap`].
     String(String),
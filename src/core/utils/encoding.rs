@@ -24,7 +24,10 @@ const USERINFO: &AsciiSet = &PATH
     .add(b'|');
 
 /// `+` sign needed by PubNub API
-const PUBNUB_SET: &AsciiSet = &USERINFO.add(b'+').add(b'%').add(b'!').add(b'$');
+///
+/// `&` should be encoded to not break query string (for example, when used in
+/// filter expression).
+const PUBNUB_SET: &AsciiSet = &USERINFO.add(b'+').add(b'%').add(b'!').add(b'$').add(b'&');
 
 /// Additional non-channel path component extension.
 const PUBNUB_NON_CHANNEL_PATH: &AsciiSet = &PUBNUB_SET.add(b',');
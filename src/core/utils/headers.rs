@@ -5,3 +5,8 @@
 
 pub(crate) const CONTENT_TYPE: &str = "Content-Type";
 pub(crate) const APPLICATION_JSON: &str = "application/json";
+
+#[cfg(all(feature = "compression", feature = "publish"))]
+pub(crate) const CONTENT_ENCODING: &str = "Content-Encoding";
+#[cfg(all(feature = "compression", feature = "publish"))]
+pub(crate) const GZIP: &str = "gzip";
@@ -240,8 +240,8 @@ mod it_should {
 
     /// Service response payload.
     fn transport_response(status: u16, token: Option<String>) -> TransportResponse {
-        let error = "\"error\":{{\"message\":\"Overall error\",\"source\":\"test\",\
-        \"details\":[{{\"message\":\"Error\",\"location\":\"signature\",\"locationType\":\"query\"}}]}}";
+        let error = "\"error\":{\"message\":\"Overall error\",\"source\":\"test\",\
+        \"details\":[{\"message\":\"Error\",\"location\":\"signature\",\"locationType\":\"query\"}]}}";
         let data = format!(
             "\"data\":{{\"message\":\"Success\"{}}}}}",
             token.map_or(String::new(), |t| format!(",\"token\":\"{}\"", t))
@@ -268,7 +268,6 @@ mod it_should {
 
     /// Construct test client with mocked transport.
     fn client(
-        with_subscribe_key: bool,
         with_secret_key: bool,
         with_auth_key: Option<String>,
         with_auth_token: Option<String>,
@@ -279,7 +278,7 @@ mod it_should {
             request_handler: None,
         }))
         .with_keyset(Keyset {
-            subscribe_key: if with_subscribe_key { "demo" } else { "" },
+            subscribe_key: "demo",
             publish_key: Some(""),
             secret_key: with_secret_key.then_some("demo"),
         })
@@ -300,18 +299,25 @@ mod it_should {
 
     #[test]
     fn not_grant_token_when_subscribe_key_missing() {
-        let permissions = permissions();
-        let client = client(false, true, None, None, None);
-        let request = client.grant_token(10).resources(&permissions).build();
+        let client = PubNubClientBuilder::with_transport(MockTransport {
+            response: None,
+            request_handler: None,
+        })
+        .with_keyset(Keyset {
+            subscribe_key: "",
+            publish_key: Some(""),
+            secret_key: Some("demo"),
+        })
+        .with_user_id("user")
+        .build();
 
-        assert!(&client.config.subscribe_key.is_empty());
-        assert!(request.is_err());
+        assert!(matches!(client, Err(PubNubError::NoKey { .. })));
     }
 
     #[test]
     fn not_grant_token_when_secret_key_missing() {
         let permissions = permissions();
-        let client = client(true, false, None, None, None);
+        let client = client(false, None, None, None);
         let request = client.grant_token(10).resources(&permissions).build();
 
         assert!(client
@@ -330,7 +336,7 @@ mod it_should {
             response: Some(transport_response(200, Some("test-token".into()))),
             ..Default::default()
         };
-        let client = client(true, true, None, None, Some(transport));
+        let client = client(true, None, None, Some(transport));
         let result = client
             .grant_token(10)
             .resources(&permissions)
@@ -354,7 +360,7 @@ mod it_should {
             })),
         };
 
-        let _ = client(true, true, None, None, Some(transport))
+        let _ = client(true, None, None, Some(transport))
             .grant_token(10)
             .resources(&permissions)
             .execute()
@@ -377,7 +383,7 @@ mod it_should {
             })),
         };
 
-        let _ = client(true, true, None, None, Some(transport))
+        let _ = client(true, None, None, Some(transport))
             .grant_token(10)
             .resources(&permissions)
             .execute()
@@ -387,7 +393,7 @@ mod it_should {
     #[test]
     fn include_body_for_grant_token() {
         let permissions = permissions();
-        let request = client(true, true, None, None, None)
+        let request = client(true, None, None, None)
             .grant_token(10)
             .resources(&permissions)
             .meta(HashMap::from([
@@ -415,17 +421,55 @@ mod it_should {
     }
 
     #[test]
-    fn not_revoke_token_when_subscribe_key_missing() {
-        let client = client(false, true, None, None, None);
-        let request = client.revoke_token("test/to+en==").build();
+    fn include_patterns_and_groups_in_body_for_grant_token() {
+        let resources: Vec<Box<dyn permissions::Permission>> =
+            vec![permissions::channel_group("group").read().manage()];
+        let patterns: Vec<Box<dyn permissions::Permission>> =
+            vec![permissions::channel("channel-.*").read().join()];
+        let request = client(true, None, None, None)
+            .grant_token(10)
+            .authorized_user_id("authorized-user")
+            .resources(&resources)
+            .patterns(&patterns)
+            .build()
+            .unwrap()
+            .transport_request();
 
-        assert!(&client.config.subscribe_key.is_empty());
-        assert!(request.is_err());
+        let body = String::from_utf8(request.body.unwrap()).unwrap_or("".into());
+        assert!(body.contains("\"ttl\":10"));
+        assert!(body.contains("\"uuid\":\"authorized-user\""));
+        assert!(body.contains("\"resources\":{\"groups\":{\"group\":5}}"));
+        assert!(body.contains("\"patterns\":{\"channels\":{\"channel-.*\":129}}"));
+        assert_eq!(request.path, "/v3/pam/demo/grant");
+    }
+
+    #[tokio::test]
+    async fn return_error_on_grant_token_failure() {
+        let permissions = permissions();
+        let transport = MockTransport {
+            response: Some(transport_response(400, None)),
+            ..Default::default()
+        };
+        let result = client(true, None, None, Some(transport))
+            .grant_token(10)
+            .resources(&permissions)
+            .execute()
+            .await;
+
+        match result {
+            Err(PubNubError::API {
+                status, message, ..
+            }) => {
+                assert_eq!(status, 400);
+                assert!(message.starts_with("Overall error"));
+            }
+            _ => panic!("Grant token should fail with API error"),
+        }
     }
 
     #[test]
     fn not_revoke_token_when_secret_key_missing() {
-        let client = client(true, false, None, None, None);
+        let client = client(false, None, None, None);
         let request = client.revoke_token("test/to+en==").build();
 
         assert!(client
@@ -439,7 +483,7 @@ mod it_should {
 
     #[tokio::test]
     async fn revoke_token() {
-        let client = client(true, true, None, None, None);
+        let client = client(true, None, None, None);
         let result = client.revoke_token("test/to+en==").execute().await;
 
         assert!(result.is_ok());
@@ -447,7 +491,7 @@ mod it_should {
 
     #[tokio::test]
     async fn include_encoded_token_in_path_for_revoke_token() {
-        let request = client(true, true, None, None, None)
+        let request = client(true, None, None, None)
             .revoke_token("test/to+en==")
             .build()
             .unwrap()
@@ -466,7 +510,7 @@ mod it_should {
                 assert_eq!(req.query_parameters.get("auth").unwrap(), "auth-key");
             })),
         };
-        let client = client(true, true, Some("auth-key".into()), None, Some(transport));
+        let client = client(true, Some("auth-key".into()), None, Some(transport));
 
         let _ = client.revoke_token("test/to+en==").execute().await;
     }
@@ -481,7 +525,7 @@ mod it_should {
             })),
         };
 
-        let client = client(true, true, None, Some("auth-token".into()), Some(transport));
+        let client = client(true, None, Some("auth-token".into()), Some(transport));
 
         let _ = client.revoke_token("test/to+en==").execute().await;
     }
@@ -496,7 +540,6 @@ mod it_should {
             })),
         };
         let client = client(
-            true,
             true,
             Some("auth-key".into()),
             Some("auth-token".into()),
@@ -0,0 +1,140 @@
+//! # PubNub get channel metadata module.
+//!
+//! The [`GetChannelMetadataRequestBuilder`] lets you make and execute requests
+//! that will fetch metadata associated with `channel` object.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::encoding::url_encode, Deserializer, PubNubError, Transport, TransportMethod,
+        TransportRequest,
+    },
+    dx::{
+        app_context::{
+            builders,
+            result::{AppContextResponseBody, ChannelMetadata, GetChannelMetadataResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+    },
+};
+
+/// The [`GetChannelMetadataRequestBuilder`] is used to build get `channel`
+/// metadata request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_channel_metadata`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`get_channel_metadata`]: crate::dx::PubNubClient::get_channel_metadata
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::app_context)", validate = "Self::validate"),
+    no_std
+)]
+pub struct GetChannelMetadataRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(custom))]
+    pub(in crate::dx::app_context) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of `channel` object for which metadata should be fetched.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(into))]
+    pub(in crate::dx::app_context) channel: String,
+
+    /// Whether `custom` fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "true")]
+    pub(in crate::dx::app_context) include_custom: bool,
+}
+
+impl<T, D> GetChannelMetadataRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get `channel` metadata request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+    }
+
+    /// Build [`GetChannelMetadataRequest`] from builder.
+    fn request(self) -> Result<GetChannelMetadataRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> GetChannelMetadataRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::app_context) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/channels/{}",
+                &config.subscribe_key,
+                url_encode(self.channel.as_bytes())
+            ),
+            query_parameters: builders::include_query(self.include_custom),
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, D> GetChannelMetadataRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<GetChannelMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AppContextResponseBody<ChannelMetadata>, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetChannelMetadataRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<GetChannelMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<AppContextResponseBody<ChannelMetadata>, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
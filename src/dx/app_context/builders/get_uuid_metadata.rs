@@ -0,0 +1,147 @@
+//! # PubNub get uuid metadata module.
+//!
+//! The [`GetUuidMetadataRequestBuilder`] lets you make and execute requests
+//! that will fetch metadata associated with `uuid` object.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::encoding::url_encode, Deserializer, PubNubError, Transport, TransportMethod,
+        TransportRequest,
+    },
+    dx::{
+        app_context::{
+            builders,
+            result::{AppContextResponseBody, GetUuidMetadataResult, UuidMetadata},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+    },
+};
+
+/// The [`GetUuidMetadataRequestBuilder`] is used to build get `uuid` metadata
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_uuid_metadata`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`get_uuid_metadata`]: crate::dx::PubNubClient::get_uuid_metadata
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::app_context)", validate = "Self::validate"),
+    no_std
+)]
+pub struct GetUuidMetadataRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(custom))]
+    pub(in crate::dx::app_context) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Identifier of `uuid` object for which metadata should be fetched.
+    ///
+    /// Client's `user_id` will be used if not set.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) uuid: Option<String>,
+
+    /// Whether `custom` fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "true")]
+    pub(in crate::dx::app_context) include_custom: bool,
+}
+
+impl<T, D> GetUuidMetadataRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get `uuid` metadata request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_optional_not_empty(&self.uuid, "uuid"))
+    }
+
+    /// Build [`GetUuidMetadataRequest`] from builder.
+    fn request(self) -> Result<GetUuidMetadataRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> GetUuidMetadataRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::app_context) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let uuid = self.uuid.as_ref().unwrap_or(config.user_id.as_ref());
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/uuids/{}",
+                &config.subscribe_key,
+                url_encode(uuid.as_bytes())
+            ),
+            query_parameters: builders::include_query(self.include_custom),
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, D> GetUuidMetadataRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<GetUuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AppContextResponseBody<UuidMetadata>, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetUuidMetadataRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<GetUuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<AppContextResponseBody<UuidMetadata>, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
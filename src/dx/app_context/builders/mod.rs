@@ -0,0 +1,174 @@
+//! # App Context API builders module.
+//!
+//! Module contains set of builders which provide access to [`PubNub`] App
+//! Context API: [`SetUuidMetadataRequestBuilder`],
+//! [`GetUuidMetadataRequestBuilder`], [`RemoveUuidMetadataRequestBuilder`],
+//! [`SetChannelMetadataRequestBuilder`], [`GetChannelMetadataRequestBuilder`],
+//! [`RemoveChannelMetadataRequestBuilder`], [`SetMembershipsRequestBuilder`],
+//! [`RemoveMembershipsRequestBuilder`] and [`GetMembershipsRequestBuilder`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use set_uuid_metadata::{SetUuidMetadataRequest, SetUuidMetadataRequestBuilder};
+pub mod set_uuid_metadata;
+
+#[doc(inline)]
+pub use get_uuid_metadata::{GetUuidMetadataRequest, GetUuidMetadataRequestBuilder};
+pub mod get_uuid_metadata;
+
+#[doc(inline)]
+pub use remove_uuid_metadata::{RemoveUuidMetadataRequest, RemoveUuidMetadataRequestBuilder};
+pub mod remove_uuid_metadata;
+
+#[doc(inline)]
+pub use set_channel_metadata::{SetChannelMetadataRequest, SetChannelMetadataRequestBuilder};
+pub mod set_channel_metadata;
+
+#[doc(inline)]
+pub use get_channel_metadata::{GetChannelMetadataRequest, GetChannelMetadataRequestBuilder};
+pub mod get_channel_metadata;
+
+#[doc(inline)]
+pub use remove_channel_metadata::{
+    RemoveChannelMetadataRequest, RemoveChannelMetadataRequestBuilder,
+};
+pub mod remove_channel_metadata;
+
+#[doc(inline)]
+pub use set_memberships::{ChannelMembership, SetMembershipsRequest, SetMembershipsRequestBuilder};
+pub mod set_memberships;
+
+#[doc(inline)]
+pub use remove_memberships::{RemoveMembershipsRequest, RemoveMembershipsRequestBuilder};
+pub mod remove_memberships;
+
+#[doc(inline)]
+pub use get_memberships::{GetMembershipsRequest, GetMembershipsRequestBuilder};
+pub mod get_memberships;
+
+use crate::{
+    core::ScalarValue,
+    dx::pubnub_client::PubNubClientInstance,
+    lib::{
+        alloc::string::{String, ToString},
+        collections::HashMap,
+    },
+};
+
+/// `Channel` object identifier payload.
+#[derive(serde::Serialize)]
+pub(in crate::dx::app_context::builders) struct ChannelIdPayload<'request> {
+    /// Unique `channel` object identifier.
+    pub id: &'request str,
+}
+
+/// `Uuid` membership in `channel` object payload.
+#[derive(serde::Serialize)]
+pub(in crate::dx::app_context::builders) struct MembershipPayload<'request> {
+    /// `Channel` object for which membership should be changed.
+    pub channel: ChannelIdPayload<'request>,
+
+    /// Additional information associated with `membership` object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<&'request HashMap<String, ScalarValue>>,
+
+    /// `Membership` object current status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'request String>,
+}
+
+/// Validate [`PubNubClient`] configuration.
+///
+/// Check whether if the [`PubNubConfig`] contains all the required fields set
+/// for App Context endpoint usage or not.
+pub(in crate::dx::app_context::builders) fn validate_configuration<T, D>(
+    client: &Option<PubNubClientInstance<T, D>>,
+) -> Result<(), String> {
+    let client = client
+        .as_ref()
+        .unwrap_or_else(|| panic!("PubNub client instance not set."));
+
+    // Defensive: client builder already rejects empty subscribe key.
+    if client.config.subscribe_key.is_empty() {
+        return Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into());
+    }
+
+    Ok(())
+}
+
+/// Validate that required string field has been provided and not empty.
+pub(in crate::dx::app_context::builders) fn validate_not_empty(
+    value: &Option<String>,
+    name: &str,
+) -> Result<(), String> {
+    value
+        .as_ref()
+        .filter(|value| !value.is_empty())
+        .map(|_| ())
+        .ok_or(crate::lib::alloc::format!("'{name}' can't be empty."))
+}
+
+/// Validate that optional string field, if provided, is not empty.
+pub(in crate::dx::app_context::builders) fn validate_optional_not_empty(
+    value: &Option<Option<String>>,
+    name: &str,
+) -> Result<(), String> {
+    match value {
+        Some(Some(value)) if value.is_empty() => {
+            Err(crate::lib::alloc::format!("'{name}' can't be empty."))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Query parameters with list of additional object fields which should be
+/// returned in response.
+pub(in crate::dx::app_context::builders) fn include_query(
+    include_custom: bool,
+) -> HashMap<String, String> {
+    let include = if include_custom {
+        "custom,status,type"
+    } else {
+        "status,type"
+    };
+
+    HashMap::from([("include".to_string(), include.to_string())])
+}
+
+/// Query parameters for `uuid` memberships list request.
+///
+/// Memberships include `channel` object fields, so they can be represented
+/// with [`ChannelMetadata`] (without modification date and hash if `channel`
+/// has no metadata).
+///
+/// [`ChannelMetadata`]: crate::dx::app_context::ChannelMetadata
+pub(in crate::dx::app_context::builders) fn memberships_query(
+    include_custom: bool,
+    include_total_count: bool,
+    limit: Option<usize>,
+    next: &Option<String>,
+    prev: &Option<String>,
+) -> HashMap<String, String> {
+    let include = if include_custom {
+        "custom,status,type,channel,channel.custom"
+    } else {
+        "status,type,channel"
+    };
+    let mut query = HashMap::from([("include".to_string(), include.to_string())]);
+
+    if include_total_count {
+        query.insert("count".into(), "true".into());
+    }
+    if let Some(limit) = limit {
+        query.insert("limit".into(), limit.to_string());
+    }
+    if let Some(next) = next {
+        query.insert("start".into(), next.clone());
+    }
+    if let Some(prev) = prev {
+        query.insert("end".into(), prev.clone());
+    }
+
+    query
+}
@@ -0,0 +1,220 @@
+//! # PubNub remove memberships module.
+//!
+//! The [`RemoveMembershipsRequestBuilder`] lets you make and execute requests
+//! that will remove `uuid` memberships from `channel` objects.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::url_encode,
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Serialize, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        app_context::{
+            builders::{self, ChannelIdPayload, MembershipPayload},
+            result::{AppContextResponseBody, Membership, MembershipsResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    },
+};
+
+/// The [`RemoveMembershipsRequestBuilder`] is used to build remove memberships
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`remove_memberships`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`remove_memberships`]: crate::dx::PubNubClient::remove_memberships
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::app_context)", validate = "Self::validate"),
+    no_std
+)]
+pub struct RemoveMembershipsRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(custom))]
+    pub(in crate::dx::app_context) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Identifier of `uuid` object for which memberships should be removed.
+    ///
+    /// Client's `user_id` will be used if not set.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) uuid: Option<String>,
+
+    /// List of `channel` names from which `uuid` memberships should be
+    /// removed.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"))]
+    pub(in crate::dx::app_context) channels: Vec<String>,
+
+    /// Whether `custom` fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "true")]
+    pub(in crate::dx::app_context) include_custom: bool,
+
+    /// Whether total number of memberships should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "false")]
+    pub(in crate::dx::app_context) include_total_count: bool,
+
+    /// Number of memberships to return in response.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) limit: Option<usize>,
+
+    /// Cursor of the next page which should be returned in response.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) next: Option<String>,
+
+    /// Cursor of the previous page which should be returned in response.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) prev: Option<String>,
+}
+
+/// Remove memberships request payload.
+#[derive(serde::Serialize)]
+struct RemoveMembershipsPayload<'request> {
+    /// List of `channel` memberships which should be removed.
+    delete: Vec<MembershipPayload<'request>>,
+}
+
+impl<T, D> RemoveMembershipsRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// remove memberships request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_optional_not_empty(&self.uuid, "uuid"))
+            .and_then(|_| match &self.channels {
+                Some(channels)
+                    if !channels.is_empty()
+                        && channels.iter().all(|channel| !channel.is_empty()) =>
+                {
+                    Ok(())
+                }
+                _ => Err("'channels' can't be empty.".into()),
+            })
+    }
+
+    /// Build [`RemoveMembershipsRequest`] from builder.
+    fn request(self) -> Result<RemoveMembershipsRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> RemoveMembershipsRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::app_context) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let uuid = self.uuid.as_ref().unwrap_or(config.user_id.as_ref());
+        let body = RemoveMembershipsPayload {
+            delete: self
+                .channels
+                .iter()
+                .map(|channel| MembershipPayload {
+                    channel: ChannelIdPayload { id: channel },
+                    custom: None,
+                    status: None,
+                })
+                .collect(),
+        }
+        .serialize()?;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/uuids/{}/channels",
+                &config.subscribe_key,
+                url_encode(uuid.as_bytes())
+            ),
+            query_parameters: builders::memberships_query(
+                self.include_custom,
+                self.include_total_count,
+                self.limit,
+                &self.next,
+                &self.prev,
+            ),
+            method: TransportMethod::Patch,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(body),
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+        })
+    }
+}
+
+impl<T, D> RemoveMembershipsRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<MembershipsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AppContextResponseBody<Vec<Membership>>, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> RemoveMembershipsRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<MembershipsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<AppContextResponseBody<Vec<Membership>>, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,222 @@
+//! # PubNub set channel metadata module.
+//!
+//! The [`SetChannelMetadataRequestBuilder`] lets you make and execute requests
+//! that will create or update metadata associated with `channel`
+//! object.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::url_encode,
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, ScalarValue, Serialize, Transport, TransportMethod,
+        TransportRequest,
+    },
+    dx::{
+        app_context::{
+            builders,
+            result::{AppContextResponseBody, ChannelMetadata, SetChannelMetadataResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`SetChannelMetadataRequestBuilder`] is used to build set `channel`
+/// metadata request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`set_channel_metadata`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`set_channel_metadata`]: crate::dx::PubNubClient::set_channel_metadata
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::app_context)", validate = "Self::validate"),
+    no_std
+)]
+pub struct SetChannelMetadataRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(custom))]
+    pub(in crate::dx::app_context) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of `channel` object for which metadata should be set.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(into))]
+    pub(in crate::dx::app_context) channel: String,
+
+    /// Given name of the metadata object.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) name: Option<String>,
+
+    /// Metadata additional description.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) description: Option<String>,
+
+    /// `Channel` object type information.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) r#type: Option<String>,
+
+    /// `Channel` object current status.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) status: Option<String>,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// `channel` object.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Whether `custom` fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "true")]
+    pub(in crate::dx::app_context) include_custom: bool,
+}
+
+/// Set `channel` metadata request payload.
+#[derive(serde::Serialize)]
+struct SetChannelMetadataPayload<'request> {
+    /// Given name of the metadata object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'request String>,
+
+    /// Metadata additional description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'request String>,
+
+    /// `Channel` object type information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<&'request String>,
+
+    /// `Channel` object current status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'request String>,
+
+    /// Additional information associated with `channel` object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom: Option<&'request HashMap<String, ScalarValue>>,
+}
+
+impl<T, D> SetChannelMetadataRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// set `channel` metadata request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+    }
+
+    /// Build [`SetChannelMetadataRequest`] from builder.
+    fn request(self) -> Result<SetChannelMetadataRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> SetChannelMetadataRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::app_context) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let body = SetChannelMetadataPayload {
+            name: self.name.as_ref(),
+            description: self.description.as_ref(),
+            r#type: self.r#type.as_ref(),
+            status: self.status.as_ref(),
+            custom: self.custom.as_ref(),
+        }
+        .serialize()?;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/channels/{}",
+                &config.subscribe_key,
+                url_encode(self.channel.as_bytes())
+            ),
+            query_parameters: builders::include_query(self.include_custom),
+            method: TransportMethod::Patch,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(body),
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+        })
+    }
+}
+
+impl<T, D> SetChannelMetadataRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<SetChannelMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AppContextResponseBody<ChannelMetadata>, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> SetChannelMetadataRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<SetChannelMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<AppContextResponseBody<ChannelMetadata>, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,240 @@
+//! # PubNub set memberships module.
+//!
+//! The [`SetMembershipsRequestBuilder`] lets you make and execute requests
+//! that will add or update `uuid` memberships in `channel` objects.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::url_encode,
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, ScalarValue, Serialize, Transport, TransportMethod,
+        TransportRequest,
+    },
+    dx::{
+        app_context::{
+            builders::{self, ChannelIdPayload, MembershipPayload},
+            result::{AppContextResponseBody, Membership, MembershipsResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// `Uuid` membership which should be set for `channel` object.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChannelMembership {
+    /// Name of `channel` object in which `uuid` should be registered as
+    /// member.
+    pub channel: String,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// `membership` object.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// `Membership` object current status.
+    pub status: Option<String>,
+}
+
+/// The [`SetMembershipsRequestBuilder`] is used to build set memberships
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`set_memberships`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`set_memberships`]: crate::dx::PubNubClient::set_memberships
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::app_context)", validate = "Self::validate"),
+    no_std
+)]
+pub struct SetMembershipsRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(custom))]
+    pub(in crate::dx::app_context) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Identifier of `uuid` object for which memberships should be set.
+    ///
+    /// Client's `user_id` will be used if not set.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) uuid: Option<String>,
+
+    /// List of `channel` memberships which should be set.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"))]
+    pub(in crate::dx::app_context) channels: Vec<ChannelMembership>,
+
+    /// Whether `custom` fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "true")]
+    pub(in crate::dx::app_context) include_custom: bool,
+
+    /// Whether total number of memberships should be included in response or
+    /// not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "false")]
+    pub(in crate::dx::app_context) include_total_count: bool,
+
+    /// Number of memberships to return in response.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) limit: Option<usize>,
+
+    /// Cursor of the next page which should be returned in response.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) next: Option<String>,
+
+    /// Cursor of the previous page which should be returned in response.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) prev: Option<String>,
+}
+
+/// Set memberships request payload.
+#[derive(serde::Serialize)]
+struct SetMembershipsPayload<'request> {
+    /// List of `channel` memberships which should be set.
+    set: Vec<MembershipPayload<'request>>,
+}
+
+impl<T, D> SetMembershipsRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// set memberships request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_optional_not_empty(&self.uuid, "uuid"))
+            .and_then(|_| match &self.channels {
+                Some(channels)
+                    if !channels.is_empty()
+                        && channels.iter().all(|member| !member.channel.is_empty()) =>
+                {
+                    Ok(())
+                }
+                _ => Err("'channels' can't be empty.".into()),
+            })
+    }
+
+    /// Build [`SetMembershipsRequest`] from builder.
+    fn request(self) -> Result<SetMembershipsRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> SetMembershipsRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::app_context) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let uuid = self.uuid.as_ref().unwrap_or(config.user_id.as_ref());
+        let body = SetMembershipsPayload {
+            set: self
+                .channels
+                .iter()
+                .map(|member| MembershipPayload {
+                    channel: ChannelIdPayload {
+                        id: &member.channel,
+                    },
+                    custom: member.custom.as_ref(),
+                    status: member.status.as_ref(),
+                })
+                .collect(),
+        }
+        .serialize()?;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/uuids/{}/channels",
+                &config.subscribe_key,
+                url_encode(uuid.as_bytes())
+            ),
+            query_parameters: builders::memberships_query(
+                self.include_custom,
+                self.include_total_count,
+                self.limit,
+                &self.next,
+                &self.prev,
+            ),
+            method: TransportMethod::Patch,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(body),
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+        })
+    }
+}
+
+impl<T, D> SetMembershipsRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<MembershipsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AppContextResponseBody<Vec<Membership>>, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> SetMembershipsRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<MembershipsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<AppContextResponseBody<Vec<Membership>>, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
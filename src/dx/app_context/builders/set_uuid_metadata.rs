@@ -0,0 +1,254 @@
+//! # PubNub set uuid metadata module.
+//!
+//! The [`SetUuidMetadataRequestBuilder`] lets you make and execute requests
+//! that will create or update metadata associated with `uuid` object.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::url_encode,
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, ScalarValue, Serialize, Transport, TransportMethod,
+        TransportRequest,
+    },
+    dx::{
+        app_context::{
+            builders,
+            result::{AppContextResponseBody, SetUuidMetadataResult, UuidMetadata},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`SetUuidMetadataRequestBuilder`] is used to build set `uuid` metadata
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`set_uuid_metadata`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`set_uuid_metadata`]: crate::dx::PubNubClient::set_uuid_metadata
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::app_context)", validate = "Self::validate"),
+    no_std
+)]
+pub struct SetUuidMetadataRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), setter(custom))]
+    pub(in crate::dx::app_context) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Identifier of `uuid` object for which metadata should be set.
+    ///
+    /// Client's `user_id` will be used if not set.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) uuid: Option<String>,
+
+    /// Give `uuid` object name.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) name: Option<String>,
+
+    /// Email address associated with `uuid` object.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) email: Option<String>,
+
+    /// `uuid` object identifier in external systems.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) external_id: Option<String>,
+
+    /// `uuid` object external profile URL.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) profile_url: Option<String>,
+
+    /// `Uuid` object type information.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) r#type: Option<String>,
+
+    /// `Uuid` object current status.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) status: Option<String>,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// `uuid` object.
+    #[builder(
+        field(vis = "pub(in crate::dx::app_context)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::app_context) custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Whether `custom` fields should be included in response or not.
+    #[builder(field(vis = "pub(in crate::dx::app_context)"), default = "true")]
+    pub(in crate::dx::app_context) include_custom: bool,
+}
+
+/// Set `uuid` metadata request payload.
+#[derive(serde::Serialize)]
+struct SetUuidMetadataPayload<'request> {
+    /// Give `uuid` object name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'request String>,
+
+    /// Email address associated with `uuid` object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<&'request String>,
+
+    /// `uuid` object identifier in external systems.
+    #[serde(rename = "externalId", skip_serializing_if = "Option::is_none")]
+    external_id: Option<&'request String>,
+
+    /// `uuid` object external profile URL.
+    #[serde(rename = "profileUrl", skip_serializing_if = "Option::is_none")]
+    profile_url: Option<&'request String>,
+
+    /// `Uuid` object type information.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<&'request String>,
+
+    /// `Uuid` object current status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'request String>,
+
+    /// Additional information associated with `uuid` object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom: Option<&'request HashMap<String, ScalarValue>>,
+}
+
+impl<T, D> SetUuidMetadataRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// set `uuid` metadata request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_optional_not_empty(&self.uuid, "uuid"))
+    }
+
+    /// Build [`SetUuidMetadataRequest`] from builder.
+    fn request(self) -> Result<SetUuidMetadataRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> SetUuidMetadataRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::app_context) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let uuid = self.uuid.as_ref().unwrap_or(config.user_id.as_ref());
+        let body = SetUuidMetadataPayload {
+            name: self.name.as_ref(),
+            email: self.email.as_ref(),
+            external_id: self.external_id.as_ref(),
+            profile_url: self.profile_url.as_ref(),
+            r#type: self.r#type.as_ref(),
+            status: self.status.as_ref(),
+            custom: self.custom.as_ref(),
+        }
+        .serialize()?;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v2/objects/{}/uuids/{}",
+                &config.subscribe_key,
+                url_encode(uuid.as_bytes())
+            ),
+            query_parameters: builders::include_query(self.include_custom),
+            method: TransportMethod::Patch,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(body),
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+        })
+    }
+}
+
+impl<T, D> SetUuidMetadataRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<SetUuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AppContextResponseBody<UuidMetadata>, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> SetUuidMetadataRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<SetUuidMetadataResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<AppContextResponseBody<UuidMetadata>, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,633 @@
+//! # App Context module.
+//!
+//! The App Context module allows managing metadata associated with `uuid` and
+//! `channel` objects and `uuid` memberships in `channel` objects.
+//! The App Context module contains [`SetUuidMetadataRequestBuilder`],
+//! [`GetUuidMetadataRequestBuilder`], [`RemoveUuidMetadataRequestBuilder`],
+//! [`SetChannelMetadataRequestBuilder`], [`GetChannelMetadataRequestBuilder`],
+//! [`RemoveChannelMetadataRequestBuilder`], [`SetMembershipsRequestBuilder`],
+//! [`RemoveMembershipsRequestBuilder`] and [`GetMembershipsRequestBuilder`]
+//! types.
+
+#[doc(inline)]
+pub use builders::*;
+pub mod builders;
+
+#[doc(inline)]
+pub use result::{
+    ChannelMetadata, GetChannelMetadataResult, GetUuidMetadataResult, Membership,
+    MembershipsResult, RemoveChannelMetadataResult, RemoveUuidMetadataResult,
+    SetChannelMetadataResult, SetUuidMetadataResult, UuidMetadata,
+};
+pub mod result;
+
+use crate::dx::pubnub_client::PubNubClientInstance;
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create a set `uuid` metadata request builder.
+    ///
+    /// This method is used to create or update metadata associated with `uuid`
+    /// object. Metadata will be associated with client's `user_id` if `uuid`
+    /// not set.
+    ///
+    /// Instance of [`SetUuidMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .set_uuid_metadata()
+    ///     .uuid("user-1")
+    ///     .name("John Doe")
+    ///     .email("john@example.com")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_uuid_metadata(&self) -> SetUuidMetadataRequestBuilder<T, D> {
+        SetUuidMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get `uuid` metadata request builder.
+    ///
+    /// This method is used to fetch metadata associated with `uuid` object.
+    /// Metadata of client's `user_id` will be fetched if `uuid` not set.
+    ///
+    /// Instance of [`GetUuidMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .get_uuid_metadata()
+    ///     .uuid("user-1")
+    ///     .execute()
+    ///     .await?;
+    /// println!("Metadata: {:?}", result.metadata);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_uuid_metadata(&self) -> GetUuidMetadataRequestBuilder<T, D> {
+        GetUuidMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove `uuid` metadata request builder.
+    ///
+    /// This method is used to remove metadata associated with `uuid` object.
+    /// Metadata of client's `user_id` will be removed if `uuid` not set.
+    ///
+    /// Instance of [`RemoveUuidMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .remove_uuid_metadata()
+    ///     .uuid("user-1")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_uuid_metadata(&self) -> RemoveUuidMetadataRequestBuilder<T, D> {
+        RemoveUuidMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a set `channel` metadata request builder.
+    ///
+    /// This method is used to create or update metadata associated with
+    /// `channel` object.
+    ///
+    /// Instance of [`SetChannelMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .set_channel_metadata()
+    ///     .channel("chat")
+    ///     .name("Chat room")
+    ///     .description("Place to discuss everything")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_channel_metadata(&self) -> SetChannelMetadataRequestBuilder<T, D> {
+        SetChannelMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get `channel` metadata request builder.
+    ///
+    /// This method is used to fetch metadata associated with `channel` object.
+    ///
+    /// Instance of [`GetChannelMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .get_channel_metadata()
+    ///     .channel("chat")
+    ///     .execute()
+    ///     .await?;
+    /// println!("Metadata: {:?}", result.metadata);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_channel_metadata(&self) -> GetChannelMetadataRequestBuilder<T, D> {
+        GetChannelMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove `channel` metadata request builder.
+    ///
+    /// This method is used to remove metadata associated with `channel` object.
+    ///
+    /// Instance of [`RemoveChannelMetadataRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .remove_channel_metadata()
+    ///     .channel("chat")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_channel_metadata(&self) -> RemoveChannelMetadataRequestBuilder<T, D> {
+        RemoveChannelMetadataRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a set memberships request builder.
+    ///
+    /// This method is used to add or update `uuid` memberships in `channel`
+    /// objects. Memberships of client's `user_id` will be changed if `uuid` not
+    /// set.
+    ///
+    /// Instance of [`SetMembershipsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder, app_context::ChannelMembership};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .set_memberships()
+    ///     .uuid("user-1")
+    ///     .channels(vec![ChannelMembership {
+    ///         channel: "chat".into(),
+    ///         ..Default::default()
+    ///     }])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn set_memberships(&self) -> SetMembershipsRequestBuilder<T, D> {
+        SetMembershipsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove memberships request builder.
+    ///
+    /// This method is used to remove `uuid` memberships from `channel`
+    /// objects. Memberships of client's `user_id` will be changed if `uuid` not
+    /// set.
+    ///
+    /// Instance of [`RemoveMembershipsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .remove_memberships()
+    ///     .uuid("user-1")
+    ///     .channels(vec!["chat".into()])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_memberships(&self) -> RemoveMembershipsRequestBuilder<T, D> {
+        RemoveMembershipsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get memberships request builder.
+    ///
+    /// This method is used to fetch list of `uuid` memberships in `channel`
+    /// objects. Memberships of client's `user_id` will be fetched if `uuid` not
+    /// set.
+    ///
+    /// Instance of [`GetMembershipsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .get_memberships()
+    ///     .uuid("user-1")
+    ///     .include_total_count(true)
+    ///     .limit(10)
+    ///     .execute()
+    ///     .await?;
+    /// println!("Memberships: {:?}", result.memberships);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_memberships(&self) -> GetMembershipsRequestBuilder<T, D> {
+        GetMembershipsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    use crate::{
+        core::{
+            PubNubError, ScalarValue, Transport, TransportMethod, TransportRequest,
+            TransportResponse,
+        },
+        lib::{
+            alloc::{boxed::Box, vec, vec::Vec},
+            collections::HashMap,
+        },
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    /// Requests handler function type.
+    type RequestHandler = Box<dyn Fn(&TransportRequest) + Send + Sync>;
+
+    struct MockTransport {
+        /// Response body which should be returned by transport.
+        response_body: &'static str,
+
+        /// Request handler function which will be called before returning
+        /// response.
+        ///
+        /// Use function to verify request parameters.
+        request_handler: RequestHandler,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            (self.request_handler)(&req);
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(Vec::from(self.response_body)),
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Construct test client with mocked transport.
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn set_uuid_metadata() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":{"id":"user 1","name":"John Doe","email":null,"externalId":null,"profileUrl":null,"custom":{"age":42},"updated":"2019-02-20T23:11:20.893755","eTag":"RTc1NUQwNUItREMyNy00Q0YxLUJCNDItMEZDMTZDMzVCN0VGCg=="}}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(req.path, "/v2/objects/demo/uuids/user%201");
+                assert!(matches!(req.method, TransportMethod::Patch));
+                assert_eq!(
+                    req.query_parameters.get("include"),
+                    Some(&"custom,status,type".into())
+                );
+                assert_eq!(
+                    req.body.as_deref(),
+                    Some(br#"{"name":"John Doe","custom":{"age":42}}"#.as_slice())
+                );
+            }),
+        };
+
+        let result = client(transport)
+            .set_uuid_metadata()
+            .uuid("user 1")
+            .name("John Doe")
+            .custom(HashMap::from([("age".into(), ScalarValue::Signed8(42))]))
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.metadata.id, "user 1");
+        assert_eq!(result.metadata.name, Some("John Doe".into()));
+        assert_eq!(
+            result.metadata.custom,
+            Some(HashMap::from([("age".into(), ScalarValue::Signed8(42))]))
+        );
+    }
+
+    #[tokio::test]
+    async fn get_uuid_metadata_for_client_user_id() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":{"id":"user","name":"John Doe","custom":null,"updated":"2019-02-20T23:11:20.893755","eTag":"RTc1NUQwNUItREMyNy00Q0YxLUJCNDItMEZDMTZDMzVCN0VGCg=="}}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(req.path, "/v2/objects/demo/uuids/user");
+                assert!(matches!(req.method, TransportMethod::Get));
+                assert_eq!(
+                    req.query_parameters.get("include"),
+                    Some(&"status,type".into())
+                );
+            }),
+        };
+
+        let result = client(transport)
+            .get_uuid_metadata()
+            .include_custom(false)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.metadata.id, "user");
+        assert_eq!(result.metadata.email, None);
+        assert_eq!(result.metadata.custom, None);
+    }
+
+    #[tokio::test]
+    async fn return_error_for_missing_uuid_metadata() {
+        let transport = MockTransport {
+            response_body: r#"{"status":404,"error":{"source":"objects","message":"Requested object was not found."}}"#,
+            request_handler: Box::new(|_| {}),
+        };
+
+        let result = client(transport)
+            .get_uuid_metadata()
+            .uuid("unknown")
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn remove_channel_metadata() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":null}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(req.path, "/v2/objects/demo/channels/chat");
+                assert!(matches!(req.method, TransportMethod::Delete));
+            }),
+        };
+
+        let result = client(transport)
+            .remove_channel_metadata()
+            .channel("chat")
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn not_send_request_without_channel() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":null}"#,
+            request_handler: Box::new(|_| panic!("Request shouldn't be sent")),
+        };
+
+        let result = client(transport)
+            .set_channel_metadata()
+            .name("Chat room")
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn set_memberships() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":[{"channel":{"id":"chat","name":null,"description":null,"custom":null,"updated":"2019-02-20T23:11:20.893755","eTag":"RTc1NUQwNUItREMyNy00Q0YxLUJCNDItMEZDMTZDMzVCN0VGCg=="},"custom":{"starred":true},"status":"active","updated":"2019-02-20T23:11:20.893755","eTag":"MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="}]}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(req.path, "/v2/objects/demo/uuids/user/channels");
+                assert!(matches!(req.method, TransportMethod::Patch));
+                assert_eq!(
+                    req.body.as_deref(),
+                    Some(
+                        br#"{"set":[{"channel":{"id":"chat"},"custom":{"starred":true},"status":"active"},{"channel":{"id":"news"}}]}"#
+                            .as_slice()
+                    )
+                );
+            }),
+        };
+
+        let result = client(transport)
+            .set_memberships()
+            .channels(vec![
+                ChannelMembership {
+                    channel: "chat".into(),
+                    custom: Some(HashMap::from([(
+                        "starred".into(),
+                        ScalarValue::Boolean(true),
+                    )])),
+                    status: Some("active".into()),
+                },
+                ChannelMembership {
+                    channel: "news".into(),
+                    ..Default::default()
+                },
+            ])
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.memberships.len(), 1);
+        assert_eq!(result.memberships[0].channel.id, "chat");
+        assert_eq!(result.memberships[0].status, Some("active".into()));
+    }
+
+    #[tokio::test]
+    async fn get_memberships_page() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":[],"totalCount":12,"next":"MTA=","prev":"NQ=="}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(req.path, "/v2/objects/demo/uuids/user-1/channels");
+                assert!(matches!(req.method, TransportMethod::Get));
+                assert_eq!(req.query_parameters.get("count"), Some(&"true".into()));
+                assert_eq!(req.query_parameters.get("limit"), Some(&"5".into()));
+                assert_eq!(req.query_parameters.get("start"), Some(&"NQ==".into()));
+            }),
+        };
+
+        let result = client(transport)
+            .get_memberships()
+            .uuid("user-1")
+            .include_total_count(true)
+            .limit(5)
+            .next("NQ==")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.total_count, Some(12));
+        assert_eq!(result.next, Some("MTA=".into()));
+        assert_eq!(result.prev, Some("NQ==".into()));
+    }
+
+    #[tokio::test]
+    async fn not_send_request_without_memberships() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":[]}"#,
+            request_handler: Box::new(|_| panic!("Request shouldn't be sent")),
+        };
+
+        let result = client(transport)
+            .remove_memberships()
+            .channels(vec![])
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
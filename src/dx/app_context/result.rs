@@ -0,0 +1,478 @@
+//! App Context result module.
+//!
+//! This module contains results of App Context API calls:
+//! [`SetUuidMetadataResult`], [`GetUuidMetadataResult`],
+//! [`RemoveUuidMetadataResult`], [`SetChannelMetadataResult`],
+//! [`GetChannelMetadataResult`], [`RemoveChannelMetadataResult`] and
+//! [`MembershipsResult`].
+
+use crate::{
+    core::{service_response::APIErrorBody, PubNubError, ScalarValue},
+    lib::{
+        alloc::{string::String, vec::Vec},
+        collections::HashMap,
+    },
+};
+
+/// `Uuid` metadata object.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct UuidMetadata {
+    /// Unique `uuid` object identifier.
+    pub id: String,
+
+    /// Give `uuid` object name.
+    pub name: Option<String>,
+
+    /// Email address associated with `uuid` object.
+    pub email: Option<String>,
+
+    /// `uuid` object identifier in external systems.
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "externalId")))]
+    pub external_id: Option<String>,
+
+    /// `uuid` object external profile URL.
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "profileUrl")))]
+    pub profile_url: Option<String>,
+
+    /// `Uuid` object type information.
+    pub r#type: Option<String>,
+
+    /// `Uuid` object current status.
+    pub status: Option<String>,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// `uuid` object.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Recent `uuid` object modification date.
+    pub updated: String,
+
+    /// Current `uuid` object state hash.
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "eTag")))]
+    pub tag: String,
+}
+
+/// `Channel` metadata object.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ChannelMetadata {
+    /// Unique `channel` object identifier.
+    pub id: String,
+
+    /// Given name of the metadata object.
+    pub name: Option<String>,
+
+    /// Metadata additional description.
+    pub description: Option<String>,
+
+    /// `Channel` object type information.
+    pub r#type: Option<String>,
+
+    /// `Channel` object current status.
+    pub status: Option<String>,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// `channel` object.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// Recent `channel` object modification date.
+    ///
+    /// Missing for `channel` object without metadata, which is referenced by
+    /// `membership`.
+    pub updated: Option<String>,
+
+    /// Current `channel` object state hash.
+    ///
+    /// Missing for `channel` object without metadata, which is referenced by
+    /// `membership`.
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "eTag")))]
+    pub tag: Option<String>,
+}
+
+/// `Uuid` membership in `channel` object.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct Membership {
+    /// `Channel` object within which `uuid` object registered as member.
+    pub channel: ChannelMetadata,
+
+    /// Flatten `HashMap` with additional information associated with
+    /// `membership` object.
+    pub custom: Option<HashMap<String, ScalarValue>>,
+
+    /// `Membership` object current status.
+    pub status: Option<String>,
+
+    /// `Membership` object type information.
+    pub r#type: Option<String>,
+
+    /// Recent `membership` object modification date.
+    pub updated: String,
+
+    /// Current `membership` object state hash.
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "eTag")))]
+    pub tag: String,
+}
+
+/// Result of successful App Context REST API endpoint call.
+///
+/// Body contains status code, response `data` and optional paging
+/// information for list responses.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct AppContextSuccessBody<D> {
+    /// Operation status (HTTP) code.
+    pub status: i32,
+
+    /// Operation response data.
+    pub data: D,
+
+    /// Total number of objects (if has been requested).
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "totalCount")))]
+    pub total_count: Option<usize>,
+
+    /// Cursor of next page (if any).
+    pub next: Option<String>,
+
+    /// Cursor of previous page (if any).
+    pub prev: Option<String>,
+}
+
+/// App Context service response body.
+///
+/// Error variant goes first because successful remove responses carry `null`
+/// `data` and would otherwise match any error body with `status` field.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AppContextResponseBody<D> {
+    /// This is an error response body for an App Context operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 404,
+    ///     "error": {
+    ///         "source": "objects",
+    ///         "message": "Requested object was not found."
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+
+    /// This is a success response body for an App Context operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {
+    ///         "id": "user-1",
+    ///         "name": "John Doe",
+    ///         "externalId": null,
+    ///         "profileUrl": null,
+    ///         "email": "jack@twitter.com",
+    ///         "custom": {
+    ///             "age": 42
+    ///         },
+    ///         "updated": "2019-02-20T23:11:20.893755",
+    ///         "eTag": "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(AppContextSuccessBody<D>),
+}
+
+/// The result of a set `uuid` metadata operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetUuidMetadataResult {
+    /// Updated `uuid` metadata object.
+    pub metadata: UuidMetadata,
+}
+
+impl TryFrom<AppContextResponseBody<UuidMetadata>> for SetUuidMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(value: AppContextResponseBody<UuidMetadata>) -> Result<Self, Self::Error> {
+        match value {
+            AppContextResponseBody::SuccessResponse(resp) => Ok(Self {
+                metadata: resp.data,
+            }),
+            AppContextResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a get `uuid` metadata operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetUuidMetadataResult {
+    /// Requested `uuid` metadata object.
+    pub metadata: UuidMetadata,
+}
+
+impl TryFrom<AppContextResponseBody<UuidMetadata>> for GetUuidMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(value: AppContextResponseBody<UuidMetadata>) -> Result<Self, Self::Error> {
+        match value {
+            AppContextResponseBody::SuccessResponse(resp) => Ok(Self {
+                metadata: resp.data,
+            }),
+            AppContextResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a remove `uuid` metadata operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveUuidMetadataResult;
+
+impl TryFrom<AppContextResponseBody<Option<HashMap<String, String>>>> for RemoveUuidMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(
+        value: AppContextResponseBody<Option<HashMap<String, String>>>,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            AppContextResponseBody::SuccessResponse(_) => Ok(Self),
+            AppContextResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a set `channel` metadata operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetChannelMetadataResult {
+    /// Updated `channel` metadata object.
+    pub metadata: ChannelMetadata,
+}
+
+impl TryFrom<AppContextResponseBody<ChannelMetadata>> for SetChannelMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(value: AppContextResponseBody<ChannelMetadata>) -> Result<Self, Self::Error> {
+        match value {
+            AppContextResponseBody::SuccessResponse(resp) => Ok(Self {
+                metadata: resp.data,
+            }),
+            AppContextResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a get `channel` metadata operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetChannelMetadataResult {
+    /// Requested `channel` metadata object.
+    pub metadata: ChannelMetadata,
+}
+
+impl TryFrom<AppContextResponseBody<ChannelMetadata>> for GetChannelMetadataResult {
+    type Error = PubNubError;
+
+    fn try_from(value: AppContextResponseBody<ChannelMetadata>) -> Result<Self, Self::Error> {
+        match value {
+            AppContextResponseBody::SuccessResponse(resp) => Ok(Self {
+                metadata: resp.data,
+            }),
+            AppContextResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a remove `channel` metadata operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveChannelMetadataResult;
+
+impl TryFrom<AppContextResponseBody<Option<HashMap<String, String>>>>
+    for RemoveChannelMetadataResult
+{
+    type Error = PubNubError;
+
+    fn try_from(
+        value: AppContextResponseBody<Option<HashMap<String, String>>>,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            AppContextResponseBody::SuccessResponse(_) => Ok(Self),
+            AppContextResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a memberships management operation.
+///
+/// Set, remove and get memberships operations return the same list of `uuid`
+/// memberships.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MembershipsResult {
+    /// List of `uuid` memberships in `channel` objects.
+    pub memberships: Vec<Membership>,
+
+    /// Total number of `uuid` memberships (if has been requested).
+    pub total_count: Option<usize>,
+
+    /// Cursor which should be used to fetch next page.
+    pub next: Option<String>,
+
+    /// Cursor which should be used to fetch previous page.
+    pub prev: Option<String>,
+}
+
+impl TryFrom<AppContextResponseBody<Vec<Membership>>> for MembershipsResult {
+    type Error = PubNubError;
+
+    fn try_from(value: AppContextResponseBody<Vec<Membership>>) -> Result<Self, Self::Error> {
+        match value {
+            AppContextResponseBody::SuccessResponse(resp) => Ok(Self {
+                memberships: resp.data,
+                total_count: resp.total_count,
+                next: resp.next,
+                prev: resp.prev,
+            }),
+            AppContextResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+
+    #[test]
+    fn parse_uuid_metadata_response() {
+        let body: AppContextResponseBody<UuidMetadata> = serde_json::from_str(
+            r#"{
+                "status": 200,
+                "data": {
+                    "id": "user-1",
+                    "name": "John Doe",
+                    "externalId": null,
+                    "profileUrl": "https://example.com/john",
+                    "email": "john@example.com",
+                    "type": "admin",
+                    "status": "active",
+                    "custom": {
+                        "age": 42,
+                        "verified": true
+                    },
+                    "updated": "2019-02-20T23:11:20.893755",
+                    "eTag": "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="
+                }
+            }"#,
+        )
+        .unwrap();
+        let result: GetUuidMetadataResult = body.try_into().unwrap();
+
+        assert_eq!(
+            result.metadata,
+            UuidMetadata {
+                id: "user-1".into(),
+                name: Some("John Doe".into()),
+                email: Some("john@example.com".into()),
+                external_id: None,
+                profile_url: Some("https://example.com/john".into()),
+                r#type: Some("admin".into()),
+                status: Some("active".into()),
+                custom: Some(HashMap::from([
+                    ("age".into(), ScalarValue::Signed8(42)),
+                    ("verified".into(), ScalarValue::Boolean(true)),
+                ])),
+                updated: "2019-02-20T23:11:20.893755".into(),
+                tag: "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg==".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_remove_metadata_response() {
+        let body: AppContextResponseBody<Option<HashMap<String, String>>> =
+            serde_json::from_str(r#"{"status": 200, "data": null}"#).unwrap();
+        let result: RemoveUuidMetadataResult = body.try_into().unwrap();
+
+        assert_eq!(result, RemoveUuidMetadataResult);
+    }
+
+    #[test]
+    fn return_error_for_error_response() {
+        let body: AppContextResponseBody<Option<HashMap<String, String>>> = serde_json::from_str(
+            r#"{"status": 404, "error": {"source": "objects", "message": "Requested object was not found."}}"#,
+        )
+        .unwrap();
+        let result: Result<RemoveChannelMetadataResult, PubNubError> = body.try_into();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_paged_memberships_response() {
+        let body: AppContextResponseBody<Vec<Membership>> = serde_json::from_str(
+            r#"{
+                "status": 200,
+                "data": [
+                    {
+                        "channel": {
+                            "id": "chat",
+                            "name": "Chat room",
+                            "description": null,
+                            "custom": null,
+                            "updated": "2019-02-20T23:11:20.893755",
+                            "eTag": "RTc1NUQwNUItREMyNy00Q0YxLUJCNDItMEZDMTZDMzVCN0VGCg=="
+                        },
+                        "custom": {
+                            "starred": true
+                        },
+                        "status": "active",
+                        "updated": "2019-02-20T23:11:20.893755",
+                        "eTag": "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="
+                    }
+                ],
+                "totalCount": 9,
+                "next": "MUIwQTAwMUItQkRBRC00NDkyLTgyMEMtODg2OUU1N0QwNTAxCg==",
+                "prev": "M0FFODRENzMtNjY2Qy00RUExLTk4QzktNkY1Q0I2MUJFNDRCCg=="
+            }"#,
+        )
+        .unwrap();
+        let result: MembershipsResult = body.try_into().unwrap();
+
+        assert_eq!(result.memberships.len(), 1);
+        assert_eq!(result.memberships[0].channel.id, "chat");
+        assert_eq!(
+            result.memberships[0].custom,
+            Some(HashMap::from([(
+                "starred".into(),
+                ScalarValue::Boolean(true)
+            )]))
+        );
+        assert_eq!(result.total_count, Some(9));
+        assert_eq!(
+            result.next,
+            Some("MUIwQTAwMUItQkRBRC00NDkyLTgyMEMtODg2OUU1N0QwNTAxCg==".into())
+        );
+        assert_eq!(
+            result.prev,
+            Some("M0FFODRENzMtNjY2Qy00RUExLTk4QzktNkY1Q0I2MUJFNDRCCg==".into())
+        );
+    }
+
+    #[test]
+    fn parse_membership_in_channel_without_metadata() {
+        let body: AppContextResponseBody<Vec<Membership>> = serde_json::from_str(
+            r#"{
+                "status": 200,
+                "data": [
+                    {
+                        "channel": {"id": "chat"},
+                        "updated": "2019-02-20T23:11:20.893755",
+                        "eTag": "MDcyQ0REOTUtNEVBOC00QkY2LTgwOUUtNDkwQzI4MjgzMTcwCg=="
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let result: MembershipsResult = body.try_into().unwrap();
+
+        assert_eq!(result.memberships[0].channel.id, "chat");
+        assert_eq!(result.memberships[0].channel.updated, None);
+        assert_eq!(result.memberships[0].channel.tag, None);
+    }
+}
@@ -0,0 +1,135 @@
+//! # PubNub add channels to the channel group module.
+//!
+//! The [`AddChannelsToGroupRequestBuilder`] lets you make and execute requests
+//! that will register channels in the channel group.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{Deserializer, PubNubError, Transport, TransportMethod, TransportRequest},
+    dx::{
+        channel_groups::{
+            builders,
+            result::{AddChannelsToGroupResult, ChannelGroupModifyResponseBody},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            string::{String, ToString},
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`AddChannelsToGroupRequestBuilder`] is used to build add channels to
+/// the channel group request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`add_channels_to_group`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`add_channels_to_group`]: crate::dx::PubNubClient::add_channels_to_group
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::channel_groups)", validate = "Self::validate"),
+    no_std
+)]
+pub struct AddChannelsToGroupRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::channel_groups)"), setter(custom))]
+    pub(in crate::dx::channel_groups) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Name of the channel group to which channels should be added.
+    #[builder(field(vis = "pub(in crate::dx::channel_groups)"), setter(into))]
+    pub(in crate::dx::channel_groups) channel_group: String,
+
+    /// List of channels which should be added to the channel group.
+    #[builder(field(vis = "pub(in crate::dx::channel_groups)"), setter(into))]
+    pub(in crate::dx::channel_groups) channels: Vec<String>,
+}
+
+impl<T, D> AddChannelsToGroupRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// add channels to the channel group request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_channel_group(&self.channel_group))
+            .and_then(|_| builders::validate_channels(&self.channels))
+    }
+
+    /// Build [`AddChannelsToGroupRequest`] from builder.
+    fn request(self) -> Result<AddChannelsToGroupRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> AddChannelsToGroupRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::channel_groups) fn transport_request(&self) -> TransportRequest {
+        let config = &self.pubnub_client.config;
+
+        TransportRequest {
+            path: builders::channel_group_path(&config.subscribe_key, &self.channel_group),
+            query_parameters: HashMap::from([("add".into(), self.channels.join(","))]),
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        }
+    }
+}
+
+impl<T, D> AddChannelsToGroupRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<AddChannelsToGroupResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<ChannelGroupModifyResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> AddChannelsToGroupRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<AddChannelsToGroupResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<ChannelGroupModifyResponseBody, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
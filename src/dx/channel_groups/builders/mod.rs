@@ -0,0 +1,84 @@
+//! # Channel groups API builders module.
+//!
+//! Module contains set of builders which provide access to [`PubNub`] channel
+//! groups management API: [`AddChannelsToGroupRequestBuilder`],
+//! [`RemoveChannelsFromGroupRequestBuilder`],
+//! [`ListChannelsInGroupRequestBuilder`] and [`RemoveGroupRequestBuilder`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use add_channels_to_group::{AddChannelsToGroupRequest, AddChannelsToGroupRequestBuilder};
+pub mod add_channels_to_group;
+
+#[doc(inline)]
+pub use remove_channels_from_group::{
+    RemoveChannelsFromGroupRequest, RemoveChannelsFromGroupRequestBuilder,
+};
+pub mod remove_channels_from_group;
+
+#[doc(inline)]
+pub use list_channels_in_group::{ListChannelsInGroupRequest, ListChannelsInGroupRequestBuilder};
+pub mod list_channels_in_group;
+
+#[doc(inline)]
+pub use remove_group::{RemoveGroupRequest, RemoveGroupRequestBuilder};
+pub mod remove_group;
+
+use crate::{
+    core::utils::encoding::url_encode,
+    dx::pubnub_client::PubNubClientInstance,
+    lib::alloc::{format, string::String, vec::Vec},
+};
+
+/// Validate [`PubNubClient`] configuration.
+///
+/// Check whether if the [`PubNubConfig`] contains all the required fields set
+/// for channel groups endpoint usage or not.
+pub(in crate::dx::channel_groups::builders) fn validate_configuration<T, D>(
+    client: &Option<PubNubClientInstance<T, D>>,
+) -> Result<(), String> {
+    let client = client
+        .as_ref()
+        .unwrap_or_else(|| panic!("PubNub client instance not set."));
+
+    // Defensive: client builder already rejects empty subscribe key.
+    if client.config.subscribe_key.is_empty() {
+        return Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into());
+    }
+
+    Ok(())
+}
+
+/// Validate that channel group name has been provided and not empty.
+pub(in crate::dx::channel_groups::builders) fn validate_channel_group(
+    channel_group: &Option<String>,
+) -> Result<(), String> {
+    channel_group
+        .as_ref()
+        .filter(|group| !group.is_empty())
+        .map(|_| ())
+        .ok_or("'channel_group' can't be empty.".into())
+}
+
+/// Validate that list of channels has been provided and not empty.
+pub(in crate::dx::channel_groups::builders) fn validate_channels(
+    channels: &Option<Vec<String>>,
+) -> Result<(), String> {
+    channels
+        .as_ref()
+        .filter(|channels| !channels.is_empty() && channels.iter().all(|ch| !ch.is_empty()))
+        .map(|_| ())
+        .ok_or("'channels' can't be empty.".into())
+}
+
+/// Channel group management endpoint path.
+pub(in crate::dx::channel_groups::builders) fn channel_group_path(
+    subscribe_key: &str,
+    channel_group: &str,
+) -> String {
+    format!(
+        "/v1/channel-registration/sub-key/{subscribe_key}/channel-group/{}",
+        url_encode(channel_group.as_bytes())
+    )
+}
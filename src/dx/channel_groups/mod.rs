@@ -0,0 +1,370 @@
+//! # Channel groups module.
+//!
+//! The channel groups module allows managing list of channels registered in
+//! channel groups, which can be used to subscribe to multiple channels at once.
+//! The channel groups module contains [`AddChannelsToGroupRequestBuilder`],
+//! [`RemoveChannelsFromGroupRequestBuilder`],
+//! [`ListChannelsInGroupRequestBuilder`] and [`RemoveGroupRequestBuilder`]
+//! types.
+
+#[doc(inline)]
+pub use builders::*;
+pub mod builders;
+
+#[doc(inline)]
+pub use result::{
+    AddChannelsToGroupResult, ListChannelsInGroupResult, RemoveChannelsFromGroupResult,
+    RemoveGroupResult,
+};
+pub mod result;
+
+use crate::dx::pubnub_client::PubNubClientInstance;
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create an add channels to the channel group request builder.
+    ///
+    /// This method is used to register channels in the channel group. Channel group
+    /// will be created if it doesn't exist yet.
+    ///
+    /// Instance of [`AddChannelsToGroupRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .add_channels_to_group()
+    ///     .channel_group("family")
+    ///     .channels(["wife".into(), "children".into()])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_channels_to_group(&self) -> AddChannelsToGroupRequestBuilder<T, D> {
+        AddChannelsToGroupRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove channels from the channel group request builder.
+    ///
+    /// This method is used to unregister channels from the channel group.
+    ///
+    /// Instance of [`RemoveChannelsFromGroupRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .remove_channels_from_group()
+    ///     .channel_group("family")
+    ///     .channels(["son".into()])
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_channels_from_group(&self) -> RemoveChannelsFromGroupRequestBuilder<T, D> {
+        RemoveChannelsFromGroupRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a list channels in the channel group request builder.
+    ///
+    /// This method is used to fetch list of channels which has been registered in
+    /// the channel group.
+    ///
+    /// Instance of [`ListChannelsInGroupRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .list_channels_in_group()
+    ///     .channel_group("family")
+    ///     .execute()
+    ///     .await?;
+    /// println!("Channels: {:?}", result.channels);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_channels_in_group(&self) -> ListChannelsInGroupRequestBuilder<T, D> {
+        ListChannelsInGroupRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove channel group request builder.
+    ///
+    /// This method is used to remove the channel group with all registered channels.
+    ///
+    /// Instance of [`RemoveGroupRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .remove_group()
+    ///     .channel_group("family")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_group(&self) -> RemoveGroupRequestBuilder<T, D> {
+        RemoveGroupRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse},
+        lib::alloc::{boxed::Box, vec::Vec},
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    /// Requests handler function type.
+    type RequestHandler = Box<dyn Fn(&TransportRequest) + Send + Sync>;
+
+    struct MockTransport {
+        /// Response status code which should be returned by transport.
+        response_status: u16,
+
+        /// Response body which should be returned by transport.
+        response_body: &'static str,
+
+        /// Request handler function which will be called before returning
+        /// response.
+        ///
+        /// Use function to verify request parameters.
+        request_handler: RequestHandler,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            (self.request_handler)(&req);
+
+            Ok(TransportResponse {
+                status: self.response_status,
+                body: Some(Vec::from(self.response_body)),
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Construct test client with mocked transport.
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn add_channels_to_group() {
+        let transport = MockTransport {
+            response_status: 200,
+            response_body: r#"{"status":200,"message":"OK","service":"channel-registry","error":false}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v1/channel-registration/sub-key/demo/channel-group/my%20family"
+                );
+                assert!(matches!(req.method, TransportMethod::Get));
+                assert_eq!(
+                    req.query_parameters.get("add"),
+                    Some(&"wife,children".into())
+                );
+            }),
+        };
+
+        let result = client(transport)
+            .add_channels_to_group()
+            .channel_group("my family")
+            .channels(["wife".into(), "children".into()])
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn remove_channels_from_group() {
+        let transport = MockTransport {
+            response_status: 200,
+            response_body: r#"{"status":200,"message":"OK","service":"channel-registry","error":false}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v1/channel-registration/sub-key/demo/channel-group/family"
+                );
+                assert_eq!(req.query_parameters.get("remove"), Some(&"son".into()));
+            }),
+        };
+
+        let result = client(transport)
+            .remove_channels_from_group()
+            .channel_group("family")
+            .channels(["son".into()])
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn list_channels_in_group() {
+        let transport = MockTransport {
+            response_status: 200,
+            response_body: r#"{"status":200,"payload":{"channels":["wife","son"],"group":"family"},"service":"channel-registry","error":false}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v1/channel-registration/sub-key/demo/channel-group/family"
+                );
+                assert!(!req.query_parameters.contains_key("add"));
+            }),
+        };
+
+        let result = client(transport)
+            .list_channels_in_group()
+            .channel_group("family")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.group, "family");
+        assert_eq!(result.channels, ["wife", "son"]);
+    }
+
+    #[tokio::test]
+    async fn remove_group() {
+        let transport = MockTransport {
+            response_status: 200,
+            response_body: r#"{"status":200,"message":"OK","service":"channel-registry","error":false}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v1/channel-registration/sub-key/demo/channel-group/family/remove"
+                );
+            }),
+        };
+
+        let result = client(transport)
+            .remove_group()
+            .channel_group("family")
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn return_api_error() {
+        let transport = MockTransport {
+            response_status: 403,
+            response_body: r#"{"status":403,"message":"Forbidden","service":"Access Manager","error":true}"#,
+            request_handler: Box::new(|_| {}),
+        };
+
+        let result = client(transport)
+            .list_channels_in_group()
+            .channel_group("family")
+            .execute()
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PubNubError::API {
+                status: 403,
+                ref message,
+                ..
+            }) if message == "Forbidden"
+        ));
+    }
+
+    #[tokio::test]
+    async fn not_send_request_without_channels() {
+        let transport = MockTransport {
+            response_status: 200,
+            response_body: r#"{"status":200,"message":"OK","service":"channel-registry","error":false}"#,
+            request_handler: Box::new(|_| panic!("Request shouldn't be sent")),
+        };
+
+        let result = client(transport)
+            .add_channels_to_group()
+            .channel_group("family")
+            .channels([])
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
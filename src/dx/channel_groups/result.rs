@@ -0,0 +1,235 @@
+//! Channel groups result module.
+//!
+//! This module contains results of channel groups management API calls:
+//! [`AddChannelsToGroupResult`], [`RemoveChannelsFromGroupResult`],
+//! [`ListChannelsInGroupResult`] and [`RemoveGroupResult`].
+
+use crate::{
+    core::{
+        service_response::{APIErrorBody, APISuccessBodyWithMessage},
+        PubNubError,
+    },
+    lib::alloc::{string::String, vec::Vec},
+};
+
+/// Channel groups service response body for channel group modification.
+///
+/// Channel registry service reports successful modification with the same
+/// body shape as errors, but with `error` set to `false`, so error variant
+/// should be checked with care.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelGroupModifyResponseBody {
+    /// This is an error response body for a channel group modification
+    /// operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "message": "Channel group or namespace not found.",
+    ///     "service": "channel-registry",
+    ///     "error": true
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+
+    /// This is a success response body for a channel group modification
+    /// operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "message": "OK",
+    ///     "service": "channel-registry"
+    /// }
+    /// ```
+    SuccessResponse(APISuccessBodyWithMessage),
+}
+
+impl ChannelGroupModifyResponseBody {
+    /// Check whether response body represents successful operation or not.
+    fn into_result(self) -> Result<(), PubNubError> {
+        match self {
+            ChannelGroupModifyResponseBody::SuccessResponse(_) => Ok(()),
+            ChannelGroupModifyResponseBody::ErrorResponse(APIErrorBody::AsObjectWithService {
+                error: false,
+                ..
+            }) => Ok(()),
+            ChannelGroupModifyResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of an add channels to the channel group operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddChannelsToGroupResult;
+
+impl TryFrom<ChannelGroupModifyResponseBody> for AddChannelsToGroupResult {
+    type Error = PubNubError;
+
+    fn try_from(value: ChannelGroupModifyResponseBody) -> Result<Self, Self::Error> {
+        value.into_result().map(|_| Self)
+    }
+}
+
+/// The result of a remove channels from the channel group operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveChannelsFromGroupResult;
+
+impl TryFrom<ChannelGroupModifyResponseBody> for RemoveChannelsFromGroupResult {
+    type Error = PubNubError;
+
+    fn try_from(value: ChannelGroupModifyResponseBody) -> Result<Self, Self::Error> {
+        value.into_result().map(|_| Self)
+    }
+}
+
+/// The result of a remove channel group operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveGroupResult;
+
+impl TryFrom<ChannelGroupModifyResponseBody> for RemoveGroupResult {
+    type Error = PubNubError;
+
+    fn try_from(value: ChannelGroupModifyResponseBody) -> Result<Self, Self::Error> {
+        value.into_result().map(|_| Self)
+    }
+}
+
+/// List of channels registered in the channel group.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ChannelGroupChannels {
+    /// Name of the channel group.
+    pub group: String,
+
+    /// List of channels registered in the channel group.
+    pub channels: Vec<String>,
+}
+
+/// Result of successful channel groups listing REST API endpoint call.
+///
+/// Unlike other services, channel registry doesn't include `message` into
+/// list response body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ChannelGroupsSuccessBody<D> {
+    /// Operation status (HTTP) code.
+    pub status: i32,
+
+    /// Operation response payload.
+    pub payload: D,
+
+    /// Service which provided response.
+    pub service: String,
+}
+
+/// The result of a list channels in the channel group operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListChannelsInGroupResult {
+    /// Name of the channel group.
+    pub group: String,
+
+    /// List of channels registered in the channel group.
+    pub channels: Vec<String>,
+}
+
+/// Channel groups service response body for list channels in the channel
+/// group.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ListChannelsInGroupResponseBody {
+    /// This is a success response body for a list channels in the channel
+    /// group operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "payload": {
+    ///         "channels": ["chat", "news"],
+    ///         "group": "family"
+    ///     },
+    ///     "service": "channel-registry",
+    ///     "error": false
+    /// }
+    /// ```
+    SuccessResponse(ChannelGroupsSuccessBody<ChannelGroupChannels>),
+
+    /// This is an error response body for a list channels in the channel
+    /// group operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 403,
+    ///     "message": "Forbidden",
+    ///     "service": "Access Manager",
+    ///     "error": true
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<ListChannelsInGroupResponseBody> for ListChannelsInGroupResult {
+    type Error = PubNubError;
+
+    fn try_from(value: ListChannelsInGroupResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            ListChannelsInGroupResponseBody::SuccessResponse(resp) => Ok(Self {
+                group: resp.payload.group,
+                channels: resp.payload.channels,
+            }),
+            ListChannelsInGroupResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod it_should {
+    use super::*;
+
+    #[test]
+    fn parse_list_channels_in_group_response() {
+        let body: ListChannelsInGroupResponseBody = serde_json::from_str(
+            r#"{
+                "status": 200,
+                "payload": {
+                    "channels": ["chat", "news"],
+                    "group": "family"
+                },
+                "service": "channel-registry",
+                "error": false
+            }"#,
+        )
+        .unwrap();
+        let result: ListChannelsInGroupResult = body.try_into().unwrap();
+
+        assert_eq!(result.group, "family");
+        assert_eq!(result.channels, vec!["chat", "news"]);
+    }
+
+    #[test]
+    fn parse_add_channels_to_group_response() {
+        let body: ChannelGroupModifyResponseBody = serde_json::from_str(
+            r#"{"status": 200, "message": "OK", "service": "channel-registry", "error": false}"#,
+        )
+        .unwrap();
+        let result: Result<AddChannelsToGroupResult, PubNubError> = body.try_into();
+
+        assert_eq!(result, Ok(AddChannelsToGroupResult));
+    }
+
+    #[test]
+    fn return_error_for_error_response() {
+        let body: ChannelGroupModifyResponseBody = serde_json::from_str(
+            r#"{"status": 400, "message": "Channel group or namespace not found.", "service": "channel-registry", "error": true}"#,
+        )
+        .unwrap();
+        let result: Result<RemoveGroupResult, PubNubError> = body.try_into();
+
+        assert!(matches!(result, Err(PubNubError::API { status: 400, .. })));
+    }
+}
@@ -0,0 +1,130 @@
+//! # PubNub delete file module.
+//!
+//! The [`DeleteFileRequestBuilder`] lets you make and execute requests that
+//! will remove previously shared file from the storage.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{Deserializer, PubNubError, Transport, TransportMethod, TransportRequest},
+    dx::{
+        files::{
+            builders,
+            result::{DeleteFileResponseBody, DeleteFileResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::string::{String, ToString},
+};
+
+/// The [`DeleteFileRequestBuilder`] is used to build delete file request that
+/// is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`delete_file`] method of the [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`delete_file`]: crate::dx::PubNubClient::delete_file
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::files)", validate = "Self::validate"),
+    no_std
+)]
+pub struct DeleteFileRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(custom))]
+    pub(in crate::dx::files) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channel where file has been shared.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) channel: String,
+
+    /// Unique identifier of uploaded file.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) id: String,
+
+    /// Actual name with which file has been stored.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) name: String,
+}
+
+impl<T, D> DeleteFileRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// delete file request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+            .and_then(|_| builders::validate_not_empty(&self.id, "id"))
+            .and_then(|_| builders::validate_not_empty(&self.name, "name"))
+    }
+
+    /// Build [`DeleteFileRequest`] from builder.
+    fn request(self) -> Result<DeleteFileRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> DeleteFileRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::files) fn transport_request(&self) -> TransportRequest {
+        let config = &self.pubnub_client.config;
+
+        TransportRequest {
+            path: builders::file_path(&config.subscribe_key, &self.channel, &self.id, &self.name),
+            method: TransportMethod::Delete,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        }
+    }
+}
+
+impl<T, D> DeleteFileRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<DeleteFileResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<DeleteFileResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> DeleteFileRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<DeleteFileResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request
+            .send_blocking::<DeleteFileResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
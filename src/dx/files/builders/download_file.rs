@@ -0,0 +1,151 @@
+//! # PubNub download file module.
+//!
+//! The [`DownloadFileRequestBuilder`] lets you make and execute requests that
+//! will download previously shared file from the storage.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        CryptoProvider, PubNubError, Transport, TransportMethod, TransportRequest,
+        TransportResponse,
+    },
+    dx::{
+        files::{builders, result::DownloadFileResult},
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{
+        boxed::Box,
+        format,
+        string::{String, ToString},
+        sync::Arc,
+    },
+};
+
+/// The [`DownloadFileRequestBuilder`] is used to build download file request that
+/// is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`download_file`] method of the [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`download_file`]: crate::dx::PubNubClient::download_file
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::files)", validate = "Self::validate"),
+    no_std
+)]
+pub struct DownloadFileRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(custom))]
+    pub(in crate::dx::files) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channel where file has been shared.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) channel: String,
+
+    /// Unique identifier of uploaded file.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) id: String,
+
+    /// Actual name with which file has been stored.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) name: String,
+}
+
+impl<T, D> DownloadFileRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// download file request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+            .and_then(|_| builders::validate_not_empty(&self.id, "id"))
+            .and_then(|_| builders::validate_not_empty(&self.name, "name"))
+    }
+
+    /// Build [`DownloadFileRequest`] from builder.
+    fn request(self) -> Result<DownloadFileRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> DownloadFileRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::files) fn transport_request(&self) -> TransportRequest {
+        let config = &self.pubnub_client.config;
+
+        TransportRequest {
+            path: builders::file_path(&config.subscribe_key, &self.channel, &self.id, &self.name),
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        }
+    }
+}
+
+/// Create download result from the file storage response.
+///
+/// Storage responds with raw file data, which is decrypted with client's
+/// cryptor (if set).
+fn download_result(
+    name: String,
+    response: TransportResponse,
+    cryptor: &Option<Arc<dyn CryptoProvider + Send + Sync>>,
+) -> Result<DownloadFileResult, PubNubError> {
+    if !(200..300).contains(&response.status) {
+        return Err(PubNubError::general_api_error(
+            format!("File download failed with status {}", response.status),
+            Some(response.status),
+            Some(Box::new(response)),
+        ));
+    }
+
+    let data = response.body.unwrap_or_default();
+    let data = match cryptor {
+        Some(cryptor) => cryptor.decrypt(data)?,
+        None => data,
+    };
+
+    Ok(DownloadFileResult { name, data })
+}
+
+impl<T, D> DownloadFileRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<DownloadFileResult, PubNubError> {
+        let request = self.request()?;
+        let response = request
+            .pubnub_client
+            .transport
+            .send(request.transport_request())
+            .await?;
+
+        download_result(request.name, response, &request.pubnub_client.cryptor)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> DownloadFileRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<DownloadFileResult, PubNubError> {
+        let request = self.request()?;
+        let response = crate::core::blocking::Transport::send(
+            &request.pubnub_client.transport,
+            request.transport_request(),
+        )?;
+
+        download_result(request.name, response, &request.pubnub_client.cryptor)
+    }
+}
@@ -0,0 +1,105 @@
+//! # PubNub get file URL module.
+//!
+//! The [`GetFileUrlRequestBuilder`] lets you build URL which can be used to
+//! download previously shared file from the storage.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{PubNubError, TransportRequest},
+    dx::{files::builders, pubnub_client::PubNubClientInstance},
+    lib::alloc::string::{String, ToString},
+};
+
+/// Default [`PubNub`] network origin.
+///
+/// [`PubNub`]:https://www.pubnub.com/
+const DEFAULT_ORIGIN: &str = "https://ps.pndsn.com";
+
+/// The [`GetFileUrlRequestBuilder`] is used to build shared file download URL
+/// for the [`PubNub`] network.
+///
+/// This struct is used by the [`get_file_url`] method of the [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`get_file_url`]: crate::dx::PubNubClient::get_file_url
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::files)", validate = "Self::validate"),
+    no_std
+)]
+pub struct GetFileUrlRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(custom))]
+    pub(in crate::dx::files) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channel where file has been shared.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) channel: String,
+
+    /// Unique identifier of uploaded file.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) id: String,
+
+    /// Actual name with which file has been stored.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) name: String,
+
+    /// [`PubNub`] network origin which should be used in URL.
+    ///
+    /// [`PubNub`]:https://www.pubnub.com/
+    #[builder(
+        field(vis = "pub(in crate::dx::files)"),
+        setter(into),
+        default = "DEFAULT_ORIGIN.into()"
+    )]
+    pub(in crate::dx::files) origin: String,
+}
+
+impl<T, D> GetFileUrlRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get file URL request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+            .and_then(|_| builders::validate_not_empty(&self.id, "id"))
+            .and_then(|_| builders::validate_not_empty(&self.name, "name"))
+    }
+
+    /// Build [`GetFileUrlRequest`] from builder.
+    fn request(self) -> Result<GetFileUrlRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> GetFileUrlRequestBuilder<T, D> {
+    /// Build shared file download URL.
+    ///
+    /// URL doesn't include access credentials, so [`download_file`] should be
+    /// used with key sets which has Access Manager enabled.
+    ///
+    /// [`download_file`]: crate::dx::PubNubClient::download_file
+    pub fn url(self) -> Result<String, PubNubError> {
+        let request = self.request()?;
+        let config = &request.pubnub_client.config;
+
+        Ok(TransportRequest {
+            path: builders::file_path(
+                &config.subscribe_key,
+                &request.channel,
+                &request.id,
+                &request.name,
+            ),
+            query_parameters: [("uuid".into(), config.user_id.to_string())].into(),
+            ..Default::default()
+        }
+        .url(request.origin.trim_end_matches('/')))
+    }
+}
@@ -0,0 +1,75 @@
+//! # Files API builders module.
+//!
+//! Module contains set of builders which provide access to [`PubNub`] file
+//! sharing API: [`SendFileRequestBuilder`], [`GetFileUrlRequestBuilder`],
+//! [`DownloadFileRequestBuilder`] and [`DeleteFileRequestBuilder`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use send_file::{SendFileRequest, SendFileRequestBuilder};
+pub mod send_file;
+
+#[doc(inline)]
+pub use get_file_url::{GetFileUrlRequest, GetFileUrlRequestBuilder};
+pub mod get_file_url;
+
+#[doc(inline)]
+pub use download_file::{DownloadFileRequest, DownloadFileRequestBuilder};
+pub mod download_file;
+
+#[doc(inline)]
+pub use delete_file::{DeleteFileRequest, DeleteFileRequestBuilder};
+pub mod delete_file;
+
+use crate::{
+    core::utils::encoding::url_encode,
+    dx::pubnub_client::PubNubClientInstance,
+    lib::alloc::{format, string::String},
+};
+
+/// Validate [`PubNubClient`] configuration.
+///
+/// Check whether if the [`PubNubConfig`] contains all the required fields set
+/// for files endpoint usage or not.
+pub(in crate::dx::files::builders) fn validate_configuration<T, D>(
+    client: &Option<PubNubClientInstance<T, D>>,
+) -> Result<(), String> {
+    let client = client
+        .as_ref()
+        .unwrap_or_else(|| panic!("PubNub client instance not set."));
+
+    // Defensive: client builder already rejects empty subscribe key.
+    if client.config.subscribe_key.is_empty() {
+        return Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into());
+    }
+
+    Ok(())
+}
+
+/// Validate that required string field has been provided and not empty.
+pub(in crate::dx::files::builders) fn validate_not_empty(
+    value: &Option<String>,
+    name: &str,
+) -> Result<(), String> {
+    value
+        .as_ref()
+        .filter(|value| !value.is_empty())
+        .map(|_| ())
+        .ok_or(format!("'{name}' can't be empty."))
+}
+
+/// Path to the shared file.
+pub(in crate::dx::files::builders) fn file_path(
+    subscribe_key: &str,
+    channel: &str,
+    id: &str,
+    name: &str,
+) -> String {
+    format!(
+        "/v1/files/{subscribe_key}/channels/{}/files/{}/{}",
+        url_encode(channel.as_bytes()),
+        url_encode(id.as_bytes()),
+        url_encode(name.as_bytes())
+    )
+}
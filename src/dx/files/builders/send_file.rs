@@ -0,0 +1,394 @@
+//! # PubNub send file module.
+//!
+//! The [`SendFileRequestBuilder`] lets you make and execute requests that will
+//! upload file to the storage and notify channel subscribers about it.
+
+use base64::{engine::general_purpose, Engine as _};
+use derive_builder::Builder;
+use uuid::Uuid;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::{url_encode, url_encode_extended, UrlEncodeExtension},
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Serialize, Transport, TransportMethod, TransportRequest,
+        TransportResponse,
+    },
+    dx::{
+        files::{
+            builders,
+            result::{
+                FileData, GenerateUploadUrlResponseBody, GenerateUploadUrlSuccessBody,
+                SendFileResult,
+            },
+        },
+        publish::{PublishResponseBody, PublishResult},
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            boxed::Box,
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`SendFileRequestBuilder`] is used to build send file request that is
+/// sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`send_file`] method of the [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`send_file`]: crate::dx::PubNubClient::send_file
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::files)", validate = "Self::validate"),
+    no_std
+)]
+pub struct SendFileRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(custom))]
+    pub(in crate::dx::files) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channel to which file should be sent.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) channel: String,
+
+    /// Name with which file should be stored.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) name: String,
+
+    /// File content.
+    #[builder(field(vis = "pub(in crate::dx::files)"), setter(into))]
+    pub(in crate::dx::files) data: Vec<u8>,
+
+    /// Message which should be associated with shared file.
+    #[builder(
+        field(vis = "pub(in crate::dx::files)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::files) message: Option<String>,
+
+    /// Whether file message should be stored in history or not.
+    ///
+    /// Key set configuration used if not set.
+    #[builder(
+        field(vis = "pub(in crate::dx::files)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::files) store: Option<bool>,
+
+    /// For how many hours file message should be stored in history.
+    #[builder(
+        field(vis = "pub(in crate::dx::files)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::files) ttl: Option<u32>,
+}
+
+/// Generate upload URL request payload.
+#[derive(serde::Serialize)]
+struct GenerateUploadUrlPayload<'request> {
+    /// Name with which file should be stored.
+    name: &'request str,
+}
+
+/// File message payload.
+#[derive(serde::Serialize)]
+struct FileMessagePayload<'request> {
+    /// Message which has been associated with shared file.
+    message: Option<&'request String>,
+
+    /// Information about shared file.
+    file: FileIdPayload<'request>,
+}
+
+/// Shared file identity payload.
+#[derive(serde::Serialize)]
+struct FileIdPayload<'request> {
+    /// Unique identifier of uploaded file.
+    id: &'request str,
+
+    /// Actual name with which file has been stored.
+    name: &'request str,
+}
+
+impl<T, D> SendFileRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// send file request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+            .and_then(|_| builders::validate_not_empty(&self.name, "name"))
+    }
+
+    /// Build [`SendFileRequest`] from builder.
+    fn request(self) -> Result<SendFileRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> SendFileRequest<T, D> {
+    /// Create generate upload URL transport request.
+    pub(in crate::dx::files) fn generate_upload_url_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let body = GenerateUploadUrlPayload { name: &self.name }.serialize()?;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/files/{}/channels/{}/generate-upload-url",
+                &config.subscribe_key,
+                url_encode(self.channel.as_bytes())
+            ),
+            method: TransportMethod::Post,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(body),
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+
+    /// Create file upload transport request.
+    ///
+    /// File sent as `multipart/form-data` with all form fields provided by
+    /// generate upload URL endpoint. File content should be the last part of
+    /// the form. Content encrypted with client's cryptor (if set).
+    pub(in crate::dx::files) fn upload_request(
+        &self,
+        upload: &GenerateUploadUrlSuccessBody,
+    ) -> Result<TransportRequest, PubNubError> {
+        // File shouldn't be uploaded as plain data if encryption failed.
+        let data = match &self.pubnub_client.cryptor {
+            Some(cryptor) => cryptor.encrypt(self.data.clone())?,
+            None => self.data.clone(),
+        };
+        let boundary = format!("--------pubnub{}", Uuid::new_v4().simple());
+        let mut body: Vec<u8> = Vec::new();
+
+        upload
+            .file_upload_request
+            .form_fields
+            .iter()
+            .for_each(|field| {
+                body.extend(
+                    format!(
+                        "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                        field.key, field.value
+                    )
+                    .into_bytes(),
+                )
+            });
+        body.extend(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; \
+                filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+                upload.data.name
+            )
+            .into_bytes(),
+        );
+        body.extend(data);
+        body.extend(format!("\r\n--{boundary}--\r\n").into_bytes());
+
+        Ok(TransportRequest {
+            path: upload.file_upload_request.url.clone(),
+            method: TransportMethod::Post,
+            headers: [(
+                CONTENT_TYPE.to_string(),
+                format!("multipart/form-data; boundary={boundary}"),
+            )]
+            .into(),
+            body: Some(body),
+            #[cfg(feature = "std")]
+            timeout: self.pubnub_client.config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+
+    /// Create publish file message transport request.
+    pub(in crate::dx::files) fn publish_file_message_request(
+        &self,
+        file: &FileData,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let pub_key = config
+            .publish_key
+            .as_ref()
+            .ok_or_else(|| PubNubError::NoKey {
+                details: "Publish key is not set".into(),
+            })?;
+        let mut message = FileMessagePayload {
+            message: self.message.as_ref(),
+            file: FileIdPayload {
+                id: &file.id,
+                name: &file.name,
+            },
+        }
+        .serialize()?;
+
+        // Same as regular message, file message is sent encrypted (if
+        // cryptor set).
+        if let Some(cryptor) = &self.pubnub_client.cryptor {
+            let encrypted = cryptor.encrypt(message)?;
+            message = format!("\"{}\"", general_purpose::STANDARD.encode(encrypted)).into_bytes();
+        }
+
+        let message = String::from_utf8(message).map_err(|err| PubNubError::Serialization {
+            details: err.to_string(),
+        })?;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        self.store
+            .and_then(|store| query.insert("store".into(), if store { "1" } else { "0" }.into()));
+        self.ttl
+            .and_then(|ttl| query.insert("ttl".into(), ttl.to_string()));
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/files/publish-file/{pub_key}/{}/0/{}/0/{}",
+                &config.subscribe_key,
+                url_encode(self.channel.as_bytes()),
+                url_encode_extended(message.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+/// Report failed file message publish.
+///
+/// File already stored at this moment, so its identity is included in error
+/// to let retry publish or remove file.
+fn publish_error(file: &FileData, error: PubNubError) -> PubNubError {
+    PubNubError::PublishError {
+        details: format!(
+            "File has been uploaded (id: {}, name: {}), but file message publish failed: {error}",
+            file.id, file.name
+        ),
+    }
+}
+
+/// Check whether file has been uploaded to the storage or not.
+fn upload_result(response: TransportResponse) -> Result<(), PubNubError> {
+    if (200..300).contains(&response.status) {
+        return Ok(());
+    }
+
+    Err(PubNubError::general_api_error(
+        format!("File upload failed with status {}", response.status),
+        Some(response.status),
+        Some(Box::new(response)),
+    ))
+}
+
+impl<T, D> SendFileRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<SendFileResult, PubNubError> {
+        let request = self.request()?;
+        let client = request.pubnub_client.clone();
+
+        let upload: GenerateUploadUrlSuccessBody = request
+            .generate_upload_url_request()?
+            .send::<GenerateUploadUrlResponseBody, _, _, _>(
+                &client.transport,
+                client.deserializer.clone(),
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await?;
+
+        upload_result(
+            client
+                .transport
+                .send(request.upload_request(&upload)?)
+                .await?,
+        )?;
+
+        let _: PublishResult = request
+            .publish_file_message_request(&upload.data)?
+            .send::<PublishResponseBody, _, _, _>(
+                &client.transport,
+                client.deserializer.clone(),
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+            .map_err(|error| publish_error(&upload.data, error))?;
+
+        Ok(SendFileResult {
+            id: upload.data.id,
+            name: upload.data.name,
+        })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> SendFileRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<SendFileResult, PubNubError> {
+        let request = self.request()?;
+        let client = request.pubnub_client.clone();
+
+        let upload: GenerateUploadUrlSuccessBody = request
+            .generate_upload_url_request()?
+            .send_blocking::<GenerateUploadUrlResponseBody, _, _, _>(
+            &client.transport,
+            client.deserializer.clone(),
+        )?;
+
+        upload_result(crate::core::blocking::Transport::send(
+            &client.transport,
+            request.upload_request(&upload)?,
+        )?)?;
+
+        let _: PublishResult = request
+            .publish_file_message_request(&upload.data)?
+            .send_blocking::<PublishResponseBody, _, _, _>(
+                &client.transport,
+                client.deserializer.clone(),
+            )
+            .map_err(|error| publish_error(&upload.data, error))?;
+
+        Ok(SendFileResult {
+            id: upload.data.id,
+            name: upload.data.name,
+        })
+    }
+}
@@ -0,0 +1,549 @@
+//! # Files module.
+//!
+//! The files module allows sharing files with channel subscribers. Shared file
+//! uploaded to the storage and subscribers receive message with information
+//! about it.
+//! The files module contains [`SendFileRequestBuilder`],
+//! [`GetFileUrlRequestBuilder`], [`DownloadFileRequestBuilder`] and
+//! [`DeleteFileRequestBuilder`] types.
+
+#[doc(inline)]
+pub use builders::*;
+pub mod builders;
+
+#[doc(inline)]
+pub use result::{DeleteFileResult, DownloadFileResult, SendFileResult};
+pub mod result;
+
+use crate::dx::pubnub_client::PubNubClientInstance;
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create a send file request builder.
+    ///
+    /// This method is used to upload file to the storage and publish message
+    /// with information about it to the channel.
+    ///
+    /// Instance of [`SendFileRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: Some("demo"),
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .send_file()
+    ///     .channel("family")
+    ///     .name("cat.txt")
+    ///     .data(b"Meow".to_vec())
+    ///     .message("Look at my cat!")
+    ///     .execute()
+    ///     .await?;
+    /// println!("File id: {}", result.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_file(&self) -> SendFileRequestBuilder<T, D> {
+        SendFileRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get file URL request builder.
+    ///
+    /// This method is used to build URL which can be used to download shared
+    /// file.
+    ///
+    /// Instance of [`GetFileUrlRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let url = pubnub
+    ///     .get_file_url()
+    ///     .channel("family")
+    ///     .id("0b4b3e8a-9d5f-4d1a-9d2b-4d6a4b4f0a4e")
+    ///     .name("cat.txt")
+    ///     .url()?;
+    /// println!("File URL: {url}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_file_url(&self) -> GetFileUrlRequestBuilder<T, D> {
+        GetFileUrlRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a download file request builder.
+    ///
+    /// This method is used to download shared file content.
+    ///
+    /// Instance of [`DownloadFileRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .download_file()
+    ///     .channel("family")
+    ///     .id("0b4b3e8a-9d5f-4d1a-9d2b-4d6a4b4f0a4e")
+    ///     .name("cat.txt")
+    ///     .execute()
+    ///     .await?;
+    /// println!("Downloaded {} bytes", result.data.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn download_file(&self) -> DownloadFileRequestBuilder<T, D> {
+        DownloadFileRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a delete file request builder.
+    ///
+    /// This method is used to remove shared file from the storage.
+    ///
+    /// Instance of [`DeleteFileRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .delete_file()
+    ///     .channel("family")
+    ///     .id("0b4b3e8a-9d5f-4d1a-9d2b-4d6a4b4f0a4e")
+    ///     .name("cat.txt")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_file(&self) -> DeleteFileRequestBuilder<T, D> {
+        DeleteFileRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    #[cfg(feature = "crypto")]
+    use crate::core::CryptoProvider;
+    use crate::{
+        core::{PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse},
+        lib::alloc::{boxed::Box, string::String, vec, vec::Vec},
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    /// Requests handler function type.
+    ///
+    /// Handler returns response status code and body for passed request.
+    type RequestHandler = Box<dyn Fn(&TransportRequest) -> (u16, Vec<u8>) + Send + Sync>;
+
+    const GENERATE_UPLOAD_URL_RESPONSE: &str = r#"{
+        "status": 200,
+        "data": {"id": "file-id", "name": "cat.txt"},
+        "file_upload_request": {
+            "url": "https://files.s3.amazonaws.com/",
+            "method": "POST",
+            "expiration_date": "2023-11-01T12:00:00Z",
+            "form_fields": [
+                {"key": "key", "value": "demo/file-id/cat.txt"},
+                {"key": "Policy", "value": "policy"}
+            ]
+        }
+    }"#;
+
+    struct MockTransport {
+        /// Sent requests.
+        requests: spin::Mutex<Vec<TransportRequest>>,
+
+        /// Request handler function which provides response for request.
+        ///
+        /// Use function to verify request parameters.
+        request_handler: RequestHandler,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            let (status, body) = (self.request_handler)(&req);
+            self.requests.lock().push(req);
+
+            Ok(TransportResponse {
+                status,
+                body: Some(body),
+                ..Default::default()
+            })
+        }
+    }
+
+    impl MockTransport {
+        fn new(request_handler: RequestHandler) -> Self {
+            Self {
+                requests: spin::Mutex::new(Vec::new()),
+                request_handler,
+            }
+        }
+    }
+
+    /// Construct test client with mocked transport.
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo-pub"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    /// Construct test client with mocked transport and cryptor.
+    #[cfg(feature = "crypto")]
+    fn client_with_cryptor(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo-pub"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .with_cryptor(
+                crate::providers::crypto::CryptoModule::new_aes_cbc_module("enigma", true).unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    /// Handler which emulates all services involved in file sharing.
+    fn file_sharing_handler(req: &TransportRequest) -> (u16, Vec<u8>) {
+        if req.path.ends_with("/generate-upload-url") {
+            (200, GENERATE_UPLOAD_URL_RESPONSE.into())
+        } else if req.path.starts_with("https://") {
+            (204, "".into())
+        } else {
+            (200, r#"[1,"Sent","17000000000000000"]"#.into())
+        }
+    }
+
+    #[tokio::test]
+    async fn request_upload_url() {
+        let client = client(MockTransport::new(Box::new(file_sharing_handler)));
+
+        let result = client
+            .send_file()
+            .channel("my channel")
+            .name("cat.txt")
+            .data(b"Meow".to_vec())
+            .execute()
+            .await;
+        assert!(result.is_ok());
+
+        let requests = client.transport.transport.requests.lock();
+        let req = &requests[0];
+        assert_eq!(
+            req.path,
+            "/v1/files/demo/channels/my%20channel/generate-upload-url"
+        );
+        assert!(matches!(req.method, TransportMethod::Post));
+        assert_eq!(
+            req.body,
+            Some(Vec::from(r#"{"name":"cat.txt"}"#.as_bytes()))
+        );
+    }
+
+    #[tokio::test]
+    async fn upload_file_with_form_fields() {
+        let client = client(MockTransport::new(Box::new(file_sharing_handler)));
+
+        client
+            .send_file()
+            .channel("family")
+            .name("cat.txt")
+            .data(b"Meow".to_vec())
+            .execute()
+            .await
+            .unwrap();
+
+        let requests = client.transport.transport.requests.lock();
+        let req = &requests[1];
+        let body = String::from_utf8(req.body.clone().unwrap()).unwrap();
+        assert_eq!(req.path, "https://files.s3.amazonaws.com/");
+        assert!(matches!(req.method, TransportMethod::Post));
+        assert!(req.query_parameters.is_empty());
+        assert!(body.find("name=\"key\"").unwrap() < body.find("name=\"file\"").unwrap());
+        assert!(body.contains("\r\n\r\nMeow\r\n"));
+    }
+
+    #[tokio::test]
+    async fn publish_file_message() {
+        let client = client(MockTransport::new(Box::new(file_sharing_handler)));
+
+        let result = client
+            .send_file()
+            .channel("family")
+            .name("cat.txt")
+            .data(b"Meow".to_vec())
+            .message("Look at my cat!")
+            .store(false)
+            .ttl(10)
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            SendFileResult {
+                id: "file-id".into(),
+                name: "cat.txt".into()
+            }
+        );
+
+        let requests = client.transport.transport.requests.lock();
+        let req = &requests[2];
+        assert_eq!(
+            req.path,
+            "/v1/files/publish-file/demo-pub/demo/0/family/0/\
+            %7B%22message%22%3A%22Look%20at%20my%20cat%21%22%2C%22file%22%3A\
+            %7B%22id%22%3A%22file-id%22%2C%22name%22%3A%22cat.txt%22%7D%7D"
+        );
+        assert_eq!(req.query_parameters.get("store"), Some(&"0".into()));
+        assert_eq!(req.query_parameters.get("ttl"), Some(&"10".into()));
+    }
+
+    #[tokio::test]
+    async fn not_publish_file_message_when_upload_failed() {
+        let client = client(MockTransport::new(Box::new(|req| {
+            if req.path.starts_with("https://") {
+                (403, "<Error><Code>AccessDenied</Code></Error>".into())
+            } else {
+                file_sharing_handler(req)
+            }
+        })));
+
+        let result = client
+            .send_file()
+            .channel("family")
+            .name("cat.txt")
+            .data(b"Meow".to_vec())
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(client.transport.transport.requests.lock().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn include_file_id_when_publish_failed() {
+        let client = client(MockTransport::new(Box::new(|req| {
+            if req.path.starts_with("/v1/files/publish-file") {
+                (
+                    400,
+                    r#"{"status":400,"error":true,"message":"Invalid"}"#.into(),
+                )
+            } else {
+                file_sharing_handler(req)
+            }
+        })));
+
+        let result = client
+            .send_file()
+            .channel("family")
+            .name("cat.txt")
+            .data(b"Meow".to_vec())
+            .execute()
+            .await;
+
+        let Err(PubNubError::PublishError { details }) = result else {
+            panic!("Expected publish error");
+        };
+        assert!(details.contains("file-id"));
+    }
+
+    #[cfg(feature = "crypto")]
+    #[tokio::test]
+    async fn send_encrypted_file_and_message() {
+        let client = client_with_cryptor(MockTransport::new(Box::new(file_sharing_handler)));
+
+        client
+            .send_file()
+            .channel("family")
+            .name("cat.txt")
+            .data(b"Meow".to_vec())
+            .message("Look at my cat!")
+            .execute()
+            .await
+            .unwrap();
+
+        let requests = client.transport.transport.requests.lock();
+        let body = requests[1].body.clone().unwrap();
+        let content_start = body
+            .windows(4)
+            .rposition(|window| window == b"\r\n\r\n")
+            .unwrap()
+            + 4;
+        let content_end = body
+            .windows(4)
+            .rposition(|window| window == b"\r\n--")
+            .unwrap();
+        let decrypted = client
+            .cryptor
+            .as_ref()
+            .unwrap()
+            .decrypt(body[content_start..content_end].to_vec())
+            .unwrap();
+        assert_eq!(decrypted, b"Meow");
+        assert!(!requests[2].path.contains("Look"));
+        assert!(!requests[2].path.contains("file-id"));
+    }
+
+    #[cfg(feature = "crypto")]
+    #[tokio::test]
+    async fn download_encrypted_file() {
+        let cryptor =
+            crate::providers::crypto::CryptoModule::new_aes_cbc_module("enigma", true).unwrap();
+        let encrypted = cryptor.encrypt(b"Meow".to_vec()).unwrap();
+        let client = client_with_cryptor(MockTransport::new(Box::new(move |_| {
+            (200, encrypted.clone())
+        })));
+
+        let result = client
+            .download_file()
+            .channel("family")
+            .id("file-id")
+            .name("cat.txt")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.data, b"Meow");
+    }
+
+    #[test]
+    fn build_file_url() {
+        let client = client(MockTransport::new(Box::new(|_| (200, "".into()))));
+
+        let url = client
+            .get_file_url()
+            .channel("family")
+            .id("file-id")
+            .name("cat picture.jpg")
+            .url()
+            .unwrap();
+
+        assert_eq!(
+            url,
+            "https://ps.pndsn.com/v1/files/demo/channels/family/files/file-id/\
+            cat%20picture.jpg?uuid=user"
+        );
+    }
+
+    #[tokio::test]
+    async fn download_file() {
+        let client = client(MockTransport::new(Box::new(|req| {
+            assert_eq!(
+                req.path,
+                "/v1/files/demo/channels/family/files/file-id/cat.txt"
+            );
+            assert!(matches!(req.method, TransportMethod::Get));
+            (200, "Meow".into())
+        })));
+
+        let result = client
+            .download_file()
+            .channel("family")
+            .id("file-id")
+            .name("cat.txt")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            DownloadFileResult {
+                name: "cat.txt".into(),
+                data: vec![b'M', b'e', b'o', b'w']
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_file() {
+        let client = client(MockTransport::new(Box::new(|req| {
+            assert_eq!(
+                req.path,
+                "/v1/files/demo/channels/family/files/file-id/cat.txt"
+            );
+            assert!(matches!(req.method, TransportMethod::Delete));
+            (200, r#"{"status":200}"#.into())
+        })));
+
+        let result = client
+            .delete_file()
+            .channel("family")
+            .id("file-id")
+            .name("cat.txt")
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,192 @@
+//! Files result module.
+//!
+//! This module contains results of file sharing API calls:
+//! [`SendFileResult`], [`DownloadFileResult`] and [`DeleteFileResult`].
+
+use crate::{
+    core::{service_response::APIErrorBody, PubNubError},
+    lib::alloc::{string::String, vec::Vec},
+};
+
+/// Information about shared file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct FileData {
+    /// Unique identifier of uploaded file.
+    pub id: String,
+
+    /// Actual name with which file has been stored.
+    pub name: String,
+}
+
+/// Single form field which should be sent along with uploaded file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct FormField {
+    /// Form field name.
+    pub key: String,
+
+    /// Form field value.
+    pub value: String,
+}
+
+/// Information required to upload file to the storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct FileUploadRequest {
+    /// Storage URL to which file should be uploaded.
+    pub url: String,
+
+    /// HTTP method which should be used to upload file.
+    pub method: String,
+
+    /// Date when upload URL will expire.
+    pub expiration_date: String,
+
+    /// List of form fields which should be sent along with uploaded file.
+    pub form_fields: Vec<FormField>,
+}
+
+/// Result of successful generate upload URL REST API endpoint call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct GenerateUploadUrlSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: i32,
+
+    /// Information about file which will be uploaded.
+    pub data: FileData,
+
+    /// Information required to upload file to the storage.
+    pub file_upload_request: FileUploadRequest,
+}
+
+/// Files service response body for generate upload URL.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenerateUploadUrlResponseBody {
+    /// This is a success response body for a generate upload URL operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {
+    ///         "id": "0b4b3e8a-9d5f-4d1a-9d2b-4d6a4b4f0a4e",
+    ///         "name": "cat.jpg"
+    ///     },
+    ///     "file_upload_request": {
+    ///         "url": "https://pubnub-mnemosyne-files-eu-central-1-prd.s3.amazonaws.com/",
+    ///         "method": "POST",
+    ///         "expiration_date": "2023-01-01T00:01:00Z",
+    ///         "form_fields": [
+    ///             {
+    ///                 "key": "Content-Type",
+    ///                 "value": "image/jpeg"
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(GenerateUploadUrlSuccessBody),
+
+    /// This is an error response body for a generate upload URL operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": {
+    ///         "source": "files",
+    ///         "message": "File name is missing"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<GenerateUploadUrlResponseBody> for GenerateUploadUrlSuccessBody {
+    type Error = PubNubError;
+
+    fn try_from(value: GenerateUploadUrlResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            GenerateUploadUrlResponseBody::SuccessResponse(resp) => Ok(resp),
+            GenerateUploadUrlResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a send file operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SendFileResult {
+    /// Unique identifier of uploaded file.
+    pub id: String,
+
+    /// Actual name with which file has been stored.
+    pub name: String,
+}
+
+/// The result of a download file operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadFileResult {
+    /// Name of downloaded file.
+    pub name: String,
+
+    /// Downloaded file content.
+    pub data: Vec<u8>,
+}
+
+/// The result of a delete file operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteFileResult;
+
+/// Result of successful delete file REST API endpoint call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct DeleteFileSuccessBody {
+    /// Operation status (HTTP) code.
+    pub status: i32,
+}
+
+/// Files service response body for delete file.
+///
+/// Error variant goes first because success response contains only `status`
+/// field, which is present in error response as well.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeleteFileResponseBody {
+    /// This is an error response body for a delete file operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 404,
+    ///     "error": {
+    ///         "source": "files",
+    ///         "message": "File not found"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+
+    /// This is a success response body for a delete file operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200
+    /// }
+    /// ```
+    SuccessResponse(DeleteFileSuccessBody),
+}
+
+impl TryFrom<DeleteFileResponseBody> for DeleteFileResult {
+    type Error = PubNubError;
+
+    fn try_from(value: DeleteFileResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            DeleteFileResponseBody::SuccessResponse(_) => Ok(Self),
+            DeleteFileResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! # PubNub fetch messages module.
+//!
+//! The [`FetchMessagesRequestBuilder`] lets you make and execute requests
+//! that will fetch messages stored in history for the provided list of
+//! channels.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::encoding::url_encoded_channels, Deserializer, PubNubError, Transport,
+        TransportMethod, TransportRequest,
+    },
+    dx::{
+        history::{
+            builders,
+            result::{FetchMessagesResponseBody, FetchMessagesResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`FetchMessagesRequestBuilder`] is used to build fetch messages
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`fetch_messages`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`fetch_messages`]: crate::dx::PubNubClient::fetch_messages
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::history)", validate = "Self::validate"),
+    no_std
+)]
+pub struct FetchMessagesRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::history)"), setter(custom))]
+    pub(in crate::dx::history) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channels for which messages should be fetched.
+    #[builder(field(vis = "pub(in crate::dx::history)"), setter(into))]
+    pub(in crate::dx::history) channels: Vec<String>,
+
+    /// Timetoken denoting the start of the range requested (exclusive).
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::history) start: Option<String>,
+
+    /// Timetoken denoting the end of the range requested (inclusive).
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::history) end: Option<String>,
+
+    /// Maximum number of messages which should be returned for each channel.
+    #[builder(
+        field(vis = "pub(in crate::dx::history)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::history) count: Option<usize>,
+
+    /// Whether metadata published along with messages should be included
+    /// into response or not.
+    #[builder(field(vis = "pub(in crate::dx::history)"), default = "false")]
+    pub(in crate::dx::history) include_meta: bool,
+}
+
+impl<T, D> FetchMessagesRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// fetch messages request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client).and_then(|_| {
+            self.channels
+                .as_ref()
+                .filter(|channels| !channels.is_empty())
+                .map(|_| ())
+                .ok_or("Channels should be provided".into())
+        })
+    }
+
+    /// Build [`FetchMessagesRequest`] from builder.
+    fn request(self) -> Result<FetchMessagesRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> FetchMessagesRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::history) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        self.start
+            .as_ref()
+            .and_then(|start| query.insert("start".into(), start.clone()));
+        self.end
+            .as_ref()
+            .and_then(|end| query.insert("end".into(), end.clone()));
+        self.count
+            .and_then(|count| query.insert("max".into(), count.to_string()));
+
+        if self.include_meta {
+            query.insert("include_meta".into(), "true".into());
+        }
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v3/history/sub-key/{}/channel/{}",
+                &config.subscribe_key,
+                url_encoded_channels(&self.channels)
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, D> FetchMessagesRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<FetchMessagesResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<FetchMessagesResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+            .map(|result: FetchMessagesResult| result.decrypt(&client.cryptor))
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> FetchMessagesRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<FetchMessagesResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request
+            .send_blocking::<FetchMessagesResponseBody, _, _, _>(&client.transport, deserializer)
+            .map(|result: FetchMessagesResult| result.decrypt(&client.cryptor))
+    }
+}
@@ -0,0 +1,166 @@
+//! # PubNub message counts module.
+//!
+//! The [`MessageCountsRequestBuilder`] lets you make and execute requests
+//! that will return number of messages published to the channels since
+//! provided timetoken(s).
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::encoding::url_encoded_channels, Deserializer, PubNubError, Transport,
+        TransportMethod, TransportRequest,
+    },
+    dx::{
+        history::{
+            builders,
+            result::{MessageCountsResponseBody, MessageCountsResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+            vec::Vec,
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`MessageCountsRequestBuilder`] is used to build message counts
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`message_counts`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`message_counts`]: crate::dx::PubNubClient::message_counts
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::history)", validate = "Self::validate"),
+    no_std
+)]
+pub struct MessageCountsRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::history)"), setter(custom))]
+    pub(in crate::dx::history) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channels for which messages should be counted.
+    #[builder(field(vis = "pub(in crate::dx::history)"), setter(into))]
+    pub(in crate::dx::history) channels: Vec<String>,
+
+    /// Timetokens since which messages should be counted.
+    ///
+    /// Either single timetoken which will be used for all channels or one
+    /// timetoken per each channel (in the same order as `channels`).
+    #[builder(field(vis = "pub(in crate::dx::history)"), setter(into))]
+    pub(in crate::dx::history) timetokens: Vec<String>,
+}
+
+impl<T, D> MessageCountsRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// message counts request instance.
+    fn validate(&self) -> Result<(), String> {
+        let channels_len = self.channels.as_ref().map(|v| v.len()).unwrap_or(0);
+        let timetokens_len = self.timetokens.as_ref().map(|v| v.len()).unwrap_or(0);
+
+        builders::validate_configuration(&self.pubnub_client).and_then(|_| {
+            if channels_len == 0 {
+                Err("Channels should be provided".into())
+            } else if timetokens_len != 1 && timetokens_len != channels_len {
+                Err(
+                    "Either single timetoken or timetoken for each channel should be provided"
+                        .into(),
+                )
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Build [`MessageCountsRequest`] from builder.
+    fn request(self) -> Result<MessageCountsRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> MessageCountsRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::history) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        if self.timetokens.len() == 1 {
+            query.insert("timetoken".into(), self.timetokens[0].clone());
+        } else {
+            query.insert("channelsTimetoken".into(), self.timetokens.join(","));
+        }
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v3/history/sub-key/{}/message-counts/{}",
+                &config.subscribe_key,
+                url_encoded_channels(&self.channels)
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, D> MessageCountsRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<MessageCountsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<MessageCountsResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> MessageCountsRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<MessageCountsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request
+            .send_blocking::<MessageCountsResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
@@ -0,0 +1,35 @@
+//! # History API builders module.
+//!
+//! Module contains set of builders which provide access to [`PubNub`] history
+//! API: [`FetchMessagesRequestBuilder`] and [`MessageCountsRequestBuilder`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use fetch_messages::{FetchMessagesRequest, FetchMessagesRequestBuilder};
+pub mod fetch_messages;
+
+#[doc(inline)]
+pub use message_counts::{MessageCountsRequest, MessageCountsRequestBuilder};
+pub mod message_counts;
+
+use crate::{dx::pubnub_client::PubNubClientInstance, lib::alloc::string::String};
+
+/// Validate [`PubNubClient`] configuration.
+///
+/// Check whether if the [`PubNubConfig`] contains all the required fields set
+/// for history endpoint usage or not.
+pub(in crate::dx::history::builders) fn validate_configuration<T, D>(
+    client: &Option<PubNubClientInstance<T, D>>,
+) -> Result<(), String> {
+    let client = client
+        .as_ref()
+        .unwrap_or_else(|| panic!("PubNub client instance not set."));
+
+    // Defensive: client builder already rejects empty subscribe key.
+    if client.config.subscribe_key.is_empty() {
+        return Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,296 @@
+//! # History module.
+//!
+//! The history module allows fetching messages stored for channels and
+//! counting messages published since specified point in time.
+//! The history module contains [`FetchMessagesRequestBuilder`] and
+//! [`MessageCountsRequestBuilder`] types.
+
+#[doc(inline)]
+pub use builders::*;
+pub mod builders;
+
+#[doc(inline)]
+pub use result::{FetchMessagesResult, HistoryMessage, MessageCountsResult, MoreHistoryMessages};
+pub mod result;
+
+use crate::dx::pubnub_client::PubNubClientInstance;
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create a fetch messages request builder.
+    ///
+    /// This method is used to fetch messages stored in history for the
+    /// provided list of channels.
+    ///
+    /// Instance of [`FetchMessagesRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .fetch_messages()
+    ///     .channels(["lobby".into(), "announce".into()])
+    ///     .count(25)
+    ///     .include_meta(true)
+    ///     .execute()
+    ///     .await?;
+    /// println!("Messages: {:?}", result.channels);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fetch_messages(&self) -> FetchMessagesRequestBuilder<T, D> {
+        FetchMessagesRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a message counts request builder.
+    ///
+    /// This method is used to count messages published to the channels since
+    /// provided timetoken(s).
+    ///
+    /// Instance of [`MessageCountsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .message_counts()
+    ///     .channels(["lobby".into(), "announce".into()])
+    ///     .timetokens(["16999999999999999".into()])
+    ///     .execute()
+    ///     .await?;
+    /// println!("Counts: {:?}", result.channels);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn message_counts(&self) -> MessageCountsRequestBuilder<T, D> {
+        MessageCountsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse},
+        dx::subscribe::result::EnvelopePayload,
+        lib::alloc::{boxed::Box, vec::Vec},
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    /// Requests handler function type.
+    type RequestHandler = Box<dyn Fn(&TransportRequest) + Send + Sync>;
+
+    struct MockTransport {
+        /// Response body which should be returned by transport.
+        response_body: &'static str,
+
+        /// Request handler function which will be called before returning
+        /// response.
+        ///
+        /// Use function to verify request parameters.
+        request_handler: RequestHandler,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            (self.request_handler)(&req);
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(Vec::from(self.response_body)),
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Construct test client with mocked transport.
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fetch_messages_for_multiple_channels() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"error":false,"error_message":"","channels":{"chat":[{"message":"Hello","timetoken":"16999999999999999","uuid":"user-1"}],"lobby":[]}}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(req.path, "/v3/history/sub-key/demo/channel/chat,lobby");
+                assert!(matches!(req.method, TransportMethod::Get));
+                assert_eq!(
+                    req.query_parameters.get("start"),
+                    Some(&"17000000000000000".into())
+                );
+                assert_eq!(req.query_parameters.get("max"), Some(&"10".into()));
+                assert_eq!(
+                    req.query_parameters.get("include_meta"),
+                    Some(&"true".into())
+                );
+                assert!(!req.query_parameters.contains_key("end"));
+            }),
+        };
+
+        let result = client(transport)
+            .fetch_messages()
+            .channels(["chat".into(), "lobby".into()])
+            .start("17000000000000000")
+            .count(10)
+            .include_meta(true)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.channels.get("chat").map(|m| m.len()), Some(1));
+        assert_eq!(result.channels.get("lobby").map(|m| m.len()), Some(0));
+    }
+
+    #[cfg(feature = "crypto")]
+    #[tokio::test]
+    async fn decrypt_fetched_messages() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"error":false,"error_message":"","channels":{"chat":[{"message":"4K7StI9dRz7utFsDHvuy082CQupbJvdwzrRja47qAV4=","timetoken":"16999999999999999"},{"message":"Hello","timetoken":"17000000000000000"}]}}"#,
+            request_handler: Box::new(|_| {}),
+        };
+        let client = PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .with_cryptor(
+                crate::providers::crypto::CryptoModule::new_legacy_module("enigma", false).unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let result = client
+            .fetch_messages()
+            .channels(["chat".into()])
+            .execute()
+            .await
+            .unwrap();
+
+        let messages = result.channels.get("chat").unwrap();
+        assert!(messages[0].decryption_error.is_none());
+        assert_eq!(
+            messages[0].payload,
+            EnvelopePayload::Message(serde_json::json!("Hello there 🙃"))
+        );
+        assert!(matches!(
+            messages[1].decryption_error,
+            Some(PubNubError::Decryption { .. })
+        ));
+        assert_eq!(
+            messages[1].payload,
+            EnvelopePayload::Message(serde_json::json!("Hello"))
+        );
+    }
+
+    #[tokio::test]
+    async fn count_messages_with_single_timetoken() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"error":false,"error_message":"","channels":{"chat":3,"lobby":0}}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v3/history/sub-key/demo/message-counts/chat,lobby"
+                );
+                assert_eq!(
+                    req.query_parameters.get("timetoken"),
+                    Some(&"16999999999999999".into())
+                );
+            }),
+        };
+
+        let result = client(transport)
+            .message_counts()
+            .channels(["chat".into(), "lobby".into()])
+            .timetokens(["16999999999999999".into()])
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.channels.get("chat"), Some(&3));
+    }
+
+    #[tokio::test]
+    async fn count_messages_with_timetoken_per_channel() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"error":false,"error_message":"","channels":{"chat":3,"lobby":1}}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.query_parameters.get("channelsTimetoken"),
+                    Some(&"16999999999999999,16999999999999998".into())
+                );
+                assert!(!req.query_parameters.contains_key("timetoken"));
+            }),
+        };
+
+        let result = client(transport)
+            .message_counts()
+            .channels(["chat".into(), "lobby".into()])
+            .timetokens(["16999999999999999".into(), "16999999999999998".into()])
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn not_count_messages_with_mismatched_timetokens() {
+        let transport = MockTransport {
+            response_body: "{}",
+            request_handler: Box::new(|_| panic!("Request shouldn't be sent")),
+        };
+
+        let result = client(transport)
+            .message_counts()
+            .channels(["chat".into(), "lobby".into(), "news".into()])
+            .timetokens(["16999999999999999".into(), "16999999999999998".into()])
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,463 @@
+//! History result module.
+//!
+//! This module contains results of history API calls: [`FetchMessagesResult`]
+//! and [`MessageCountsResult`].
+
+use base64::{engine::general_purpose, Engine as _};
+
+use crate::{
+    core::{service_response::APIErrorBody, CryptoProvider, PubNubError},
+    dx::subscribe::{result::EnvelopePayload, SubscribeMessageType},
+    lib::{
+        alloc::{
+            string::{String, ToString},
+            sync::Arc,
+            vec::Vec,
+        },
+        collections::HashMap,
+        core::mem,
+    },
+};
+
+/// Message which has been fetched from history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryMessage {
+    /// Message "publish" time.
+    pub timetoken: String,
+
+    /// PubNub defined message type.
+    ///
+    /// History contains only [`SubscribeMessageType::Message`] and
+    /// [`SubscribeMessageType::File`] messages.
+    pub message_type: SubscribeMessageType,
+
+    /// Identifier of client which sent message.
+    ///
+    /// Set only when history requested with `include_uuid`.
+    pub sender: Option<String>,
+
+    /// User provided message type (set only when [`publish`] called with
+    /// `r#type`).
+    ///
+    /// [`publish`]: crate::dx::publish
+    pub r#type: Option<String>,
+
+    /// Message payload.
+    pub payload: EnvelopePayload,
+
+    /// Metadata which has been published along with message.
+    ///
+    /// Set only when history requested with `include_meta`.
+    #[cfg(feature = "serde")]
+    pub meta: Option<serde_json::Value>,
+
+    /// Metadata which has been published along with message.
+    ///
+    /// Set only when history requested with `include_meta`.
+    #[cfg(not(feature = "serde"))]
+    pub meta: Option<Vec<u8>>,
+
+    /// Message decryption error.
+    ///
+    /// Set when client configured with cryptor and payload can't be
+    /// decrypted. Payload is kept as it has been received in this case.
+    pub decryption_error: Option<PubNubError>,
+}
+
+impl HistoryMessage {
+    /// Decrypt message payload if possible.
+    fn decrypt(mut self, cryptor: &Arc<dyn CryptoProvider + Send + Sync>) -> Self {
+        let EnvelopePayload::Message(payload) = &self.payload else {
+            return self;
+        };
+
+        let decryption_result = encrypted_payload(payload)
+            .and_then(|encrypted| {
+                general_purpose::STANDARD
+                    .decode(encrypted)
+                    .map_err(|err| PubNubError::Decryption {
+                        details: err.to_string(),
+                    })
+            })
+            .and_then(|base64_bytes| cryptor.decrypt(base64_bytes))
+            .and_then(decrypted_payload);
+
+        match decryption_result {
+            Ok(payload) => self.payload = EnvelopePayload::Message(payload),
+            Err(error) => self.decryption_error = Some(error),
+        }
+
+        self
+    }
+}
+
+/// Base64-encoded encrypted data from message payload.
+#[cfg(feature = "serde")]
+fn encrypted_payload(payload: &serde_json::Value) -> Result<&str, PubNubError> {
+    payload.as_str().ok_or(PubNubError::Decryption {
+        details: "Message payload is not encrypted string".into(),
+    })
+}
+
+/// Base64-encoded encrypted data from message payload.
+#[cfg(not(feature = "serde"))]
+fn encrypted_payload(payload: &[u8]) -> Result<String, PubNubError> {
+    Ok(String::from_utf8_lossy(payload)
+        .trim_matches('"')
+        .to_string())
+}
+
+/// Message payload from decrypted data.
+#[cfg(feature = "serde")]
+fn decrypted_payload(data: Vec<u8>) -> Result<serde_json::Value, PubNubError> {
+    serde_json::from_slice(&data).map_err(|err| PubNubError::Decryption {
+        details: err.to_string(),
+    })
+}
+
+/// Message payload from decrypted data.
+#[cfg(not(feature = "serde"))]
+fn decrypted_payload(data: Vec<u8>) -> Result<Vec<u8>, PubNubError> {
+    Ok(data)
+}
+
+/// Single entry from history response.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct HistoryMessageBody {
+    /// Message payload.
+    pub message: EnvelopePayload,
+
+    /// Message "publish" time.
+    pub timetoken: String,
+
+    /// PubNub defined message type (`null` for regular messages and `4` for
+    /// files).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub message_type: Option<u32>,
+
+    /// User provided message type.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom_message_type: Option<String>,
+
+    /// Identifier of client which sent message.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub uuid: Option<String>,
+
+    /// Metadata which has been published along with message.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub meta: Option<serde_json::Value>,
+
+    /// Metadata which has been published along with message.
+    #[cfg(not(feature = "serde"))]
+    pub meta: Option<Vec<u8>>,
+}
+
+impl From<HistoryMessageBody> for HistoryMessage {
+    fn from(value: HistoryMessageBody) -> Self {
+        Self {
+            timetoken: value.timetoken,
+            message_type: match value.message_type {
+                Some(4) => SubscribeMessageType::File,
+                _ => SubscribeMessageType::Message,
+            },
+            sender: value.uuid,
+            r#type: value.custom_message_type,
+            payload: value.message,
+            // Service returns empty string for messages published without
+            // metadata.
+            #[cfg(feature = "serde")]
+            meta: value
+                .meta
+                .filter(|meta| !matches!(meta, serde_json::Value::String(meta) if meta.is_empty())),
+            #[cfg(not(feature = "serde"))]
+            meta: value.meta,
+            decryption_error: None,
+        }
+    }
+}
+
+/// Information about next page of history.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct MoreHistoryMessages {
+    /// Timetoken which should be used as `start` for next page request.
+    pub start: String,
+
+    /// Maximum number of messages which has been requested.
+    pub max: usize,
+}
+
+/// Result of successful history REST API endpoint call.
+///
+/// Body contains status code, error flag with message and per-channel `data`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct HistorySuccessBody<D> {
+    /// Operation status (HTTP) code.
+    pub status: u16,
+
+    /// There is a flag that tells if this is an error response.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub error: bool,
+
+    /// A message explaining what went wrong.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub error_message: String,
+
+    /// Per-channel operation response data.
+    pub channels: HashMap<String, D>,
+
+    /// Information about next page (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub more: Option<MoreHistoryMessages>,
+}
+
+/// The result of a fetch messages operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchMessagesResult {
+    /// Map of channel names to the list of messages fetched for them.
+    pub channels: HashMap<String, Vec<HistoryMessage>>,
+
+    /// Information about next page (if more messages available).
+    pub more: Option<MoreHistoryMessages>,
+}
+
+impl FetchMessagesResult {
+    /// Decrypt fetched messages with client's `cryptor` (if any).
+    pub(in crate::dx::history) fn decrypt(
+        mut self,
+        cryptor: &Option<Arc<dyn CryptoProvider + Send + Sync>>,
+    ) -> Self {
+        let Some(cryptor) = cryptor else {
+            return self;
+        };
+
+        self.channels.values_mut().for_each(|messages| {
+            *messages = mem::take(messages)
+                .into_iter()
+                .map(|message| message.decrypt(cryptor))
+                .collect()
+        });
+
+        self
+    }
+}
+
+/// History service response body for fetch messages.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchMessagesResponseBody {
+    /// This is a success response body for a fetch messages operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "error": false,
+    ///     "error_message": "",
+    ///     "channels": {
+    ///         "chat": [
+    ///             {
+    ///                 "message": "Hello",
+    ///                 "timetoken": "16999999999999999",
+    ///                 "message_type": null,
+    ///                 "uuid": "user-1"
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(HistorySuccessBody<Vec<HistoryMessageBody>>),
+
+    /// This is an error response body for a fetch messages operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 403,
+    ///     "error": true,
+    ///     "error_message": "Forbidden"
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<FetchMessagesResponseBody> for FetchMessagesResult {
+    type Error = PubNubError;
+
+    fn try_from(value: FetchMessagesResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            FetchMessagesResponseBody::SuccessResponse(resp) if resp.error => Err(
+                PubNubError::general_api_error(resp.error_message, Some(resp.status), None),
+            ),
+            FetchMessagesResponseBody::SuccessResponse(resp) => Ok(Self {
+                channels: resp
+                    .channels
+                    .into_iter()
+                    .map(|(channel, messages)| {
+                        (channel, messages.into_iter().map(Into::into).collect())
+                    })
+                    .collect(),
+                more: resp.more,
+            }),
+            FetchMessagesResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a message counts operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageCountsResult {
+    /// Map of channel names to the number of messages published since
+    /// requested timetoken.
+    pub channels: HashMap<String, usize>,
+}
+
+/// History service response body for message counts.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MessageCountsResponseBody {
+    /// This is a success response body for a message counts operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "error": false,
+    ///     "error_message": "",
+    ///     "channels": {
+    ///         "chat": 2,
+    ///         "lobby": 0
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(HistorySuccessBody<usize>),
+
+    /// This is an error response body for a message counts operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": true,
+    ///     "error_message": "Invalid Arguments"
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<MessageCountsResponseBody> for MessageCountsResult {
+    type Error = PubNubError;
+
+    fn try_from(value: MessageCountsResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            MessageCountsResponseBody::SuccessResponse(resp) if resp.error => Err(
+                PubNubError::general_api_error(resp.error_message, Some(resp.status), None),
+            ),
+            MessageCountsResponseBody::SuccessResponse(resp) => Ok(Self {
+                channels: resp.channels,
+            }),
+            MessageCountsResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod it_should {
+    use super::*;
+
+    #[test]
+    fn parse_multi_channel_history_page() {
+        let body: FetchMessagesResponseBody = serde_json::from_str(
+            r#"{
+                "status": 200,
+                "error": false,
+                "error_message": "",
+                "channels": {
+                    "chat": [
+                        {
+                            "message": {"text": "Hello"},
+                            "timetoken": "16999999999999998",
+                            "message_type": null,
+                            "uuid": "user-1",
+                            "meta": {"mood": "happy"}
+                        },
+                        {
+                            "message": {"message": "Here is file", "file": {"id": "file-id", "name": "cat.png"}},
+                            "timetoken": "16999999999999999",
+                            "message_type": 4,
+                            "uuid": "user-2"
+                        }
+                    ],
+                    "lobby": [
+                        {
+                            "message": "Hi",
+                            "timetoken": "16999999999999997",
+                            "custom_message_type": "greeting",
+                            "meta": ""
+                        }
+                    ]
+                },
+                "more": {
+                    "url": "/v3/history/sub-key/demo/channel/chat,lobby?start=16999999999999997&max=2",
+                    "start": "16999999999999997",
+                    "max": 2
+                }
+            }"#,
+        )
+        .unwrap();
+        let result: FetchMessagesResult = body.try_into().unwrap();
+
+        let chat = result.channels.get("chat").unwrap();
+        assert_eq!(chat.len(), 2);
+        assert_eq!(chat[0].timetoken, "16999999999999998");
+        assert_eq!(chat[0].sender, Some("user-1".into()));
+        assert_eq!(chat[0].message_type, SubscribeMessageType::Message);
+        assert_eq!(
+            chat[0].payload,
+            EnvelopePayload::Message(serde_json::json!({"text": "Hello"}))
+        );
+        assert_eq!(chat[0].meta, Some(serde_json::json!({"mood": "happy"})));
+        assert_eq!(chat[1].message_type, SubscribeMessageType::File);
+        assert!(matches!(chat[1].payload, EnvelopePayload::File { .. }));
+
+        let lobby = result.channels.get("lobby").unwrap();
+        assert_eq!(lobby.len(), 1);
+        assert_eq!(lobby[0].r#type, Some("greeting".into()));
+        assert_eq!(lobby[0].sender, None);
+        assert_eq!(lobby[0].meta, None);
+
+        assert_eq!(
+            result.more,
+            Some(MoreHistoryMessages {
+                start: "16999999999999997".into(),
+                max: 2
+            })
+        );
+    }
+
+    #[test]
+    fn return_error_for_history_error_response() {
+        let body: FetchMessagesResponseBody = serde_json::from_str(
+            r#"{"status": 403, "error": true, "error_message": "Forbidden", "channels": {}}"#,
+        )
+        .unwrap();
+        let result: Result<FetchMessagesResult, PubNubError> = body.try_into();
+
+        assert!(matches!(result, Err(PubNubError::API { status: 403, .. })));
+    }
+
+    #[test]
+    fn parse_message_counts() {
+        let body: MessageCountsResponseBody = serde_json::from_str(
+            r#"{"status": 200, "error": false, "error_message": "", "channels": {"chat": 2, "lobby": 0}}"#,
+        )
+        .unwrap();
+        let result: MessageCountsResult = body.try_into().unwrap();
+
+        assert_eq!(result.channels.get("chat"), Some(&2));
+        assert_eq!(result.channels.get("lobby"), Some(&0));
+    }
+}
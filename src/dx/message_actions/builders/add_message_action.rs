@@ -0,0 +1,174 @@
+//! # PubNub add message action module.
+//!
+//! The [`AddMessageActionRequestBuilder`] lets you make and execute requests
+//! that will add action (like reaction or receipt) to the published message.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::{
+            encoding::url_encode,
+            headers::{APPLICATION_JSON, CONTENT_TYPE},
+        },
+        Deserializer, PubNubError, Serialize, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        message_actions::{
+            builders,
+            result::{AddMessageActionResponseBody, AddMessageActionResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+    },
+};
+
+/// The [`AddMessageActionRequestBuilder`] is used to build add message action
+/// request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`add_message_action`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`add_message_action`]: crate::dx::PubNubClient::add_message_action
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(
+        vis = "pub(in crate::dx::message_actions)",
+        validate = "Self::validate"
+    ),
+    no_std
+)]
+pub struct AddMessageActionRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(custom))]
+    pub(in crate::dx::message_actions) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channel where message has been published.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) channel: String,
+
+    /// Timetoken of message to which action should be added.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) message_timetoken: String,
+
+    /// Message action type.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) action_type: String,
+
+    /// Value associated with message action `type`.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) value: String,
+}
+
+/// Add message action request payload.
+#[derive(serde::Serialize)]
+struct AddMessageActionPayload<'request> {
+    /// Message action type.
+    r#type: &'request str,
+
+    /// Value associated with message action `type`.
+    value: &'request str,
+}
+
+impl<T, D> AddMessageActionRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// add message action request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+            .and_then(|_| {
+                builders::validate_not_empty(&self.message_timetoken, "message_timetoken")
+            })
+            .and_then(|_| builders::validate_not_empty(&self.action_type, "action_type"))
+            .and_then(|_| builders::validate_not_empty(&self.value, "value"))
+    }
+
+    /// Build [`AddMessageActionRequest`] from builder.
+    fn request(self) -> Result<AddMessageActionRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> AddMessageActionRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::message_actions) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let body = AddMessageActionPayload {
+            r#type: &self.action_type,
+            value: &self.value,
+        }
+        .serialize()?;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/message-actions/{}/channel/{}/message/{}",
+                &config.subscribe_key,
+                url_encode(self.channel.as_bytes()),
+                url_encode(self.message_timetoken.as_bytes())
+            ),
+            method: TransportMethod::Post,
+            headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+            body: Some(body),
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, D> AddMessageActionRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<AddMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<AddMessageActionResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> AddMessageActionRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<AddMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request
+            .send_blocking::<AddMessageActionResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
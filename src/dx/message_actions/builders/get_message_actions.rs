@@ -0,0 +1,179 @@
+//! # PubNub get message actions module.
+//!
+//! The [`GetMessageActionsRequestBuilder`] lets you make and execute requests
+//! that will fetch list of message actions added to messages in the channel
+//! within specified timetoken range.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::encoding::url_encode, Deserializer, PubNubError, Transport, TransportMethod,
+        TransportRequest,
+    },
+    dx::{
+        message_actions::{
+            builders,
+            result::{GetMessageActionsResponseBody, GetMessageActionsResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+};
+
+/// The [`GetMessageActionsRequestBuilder`] is used to build get message
+/// actions request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`get_message_actions`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`get_message_actions`]: crate::dx::PubNubClient::get_message_actions
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(
+        vis = "pub(in crate::dx::message_actions)",
+        validate = "Self::validate"
+    ),
+    no_std
+)]
+pub struct GetMessageActionsRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(custom))]
+    pub(in crate::dx::message_actions) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channel for which message actions should be retrieved.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) channel: String,
+
+    /// Message action timetoken denoting the start of the range requested
+    /// (exclusive).
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) start: Option<String>,
+
+    /// Message action timetoken denoting the end of the range requested
+    /// (inclusive).
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option, into),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) end: Option<String>,
+
+    /// Number of message actions to return in response.
+    #[builder(
+        field(vis = "pub(in crate::dx::message_actions)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::message_actions) limit: Option<usize>,
+}
+
+impl<T, D> GetMessageActionsRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// get message actions request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+    }
+
+    /// Build [`GetMessageActionsRequest`] from builder.
+    fn request(self) -> Result<GetMessageActionsRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> GetMessageActionsRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::message_actions) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let mut query: HashMap<String, String> = HashMap::new();
+
+        self.start
+            .as_ref()
+            .and_then(|start| query.insert("start".into(), start.clone()));
+        self.end
+            .as_ref()
+            .and_then(|end| query.insert("end".into(), end.clone()));
+        self.limit
+            .and_then(|limit| query.insert("limit".into(), limit.to_string()));
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/message-actions/{}/channel/{}",
+                &config.subscribe_key,
+                url_encode(self.channel.as_bytes())
+            ),
+            query_parameters: query,
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, D> GetMessageActionsRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<GetMessageActionsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<GetMessageActionsResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> GetMessageActionsRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<GetMessageActionsResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<GetMessageActionsResponseBody, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,53 @@
+//! # Message actions API builders module.
+//!
+//! Module contains set of builders which provide access to [`PubNub`] message
+//! actions API: [`AddMessageActionRequestBuilder`],
+//! [`RemoveMessageActionRequestBuilder`] and
+//! [`GetMessageActionsRequestBuilder`].
+//!
+//! [`PubNub`]: https://www.pubnub.com
+
+#[doc(inline)]
+pub use add_message_action::{AddMessageActionRequest, AddMessageActionRequestBuilder};
+pub mod add_message_action;
+
+#[doc(inline)]
+pub use remove_message_action::{RemoveMessageActionRequest, RemoveMessageActionRequestBuilder};
+pub mod remove_message_action;
+
+#[doc(inline)]
+pub use get_message_actions::{GetMessageActionsRequest, GetMessageActionsRequestBuilder};
+pub mod get_message_actions;
+
+use crate::{dx::pubnub_client::PubNubClientInstance, lib::alloc::string::String};
+
+/// Validate [`PubNubClient`] configuration.
+///
+/// Check whether if the [`PubNubConfig`] contains all the required fields set
+/// for message actions endpoint usage or not.
+pub(in crate::dx::message_actions::builders) fn validate_configuration<T, D>(
+    client: &Option<PubNubClientInstance<T, D>>,
+) -> Result<(), String> {
+    let client = client
+        .as_ref()
+        .unwrap_or_else(|| panic!("PubNub client instance not set."));
+
+    // Defensive: client builder already rejects empty subscribe key.
+    if client.config.subscribe_key.is_empty() {
+        return Err("Incomplete PubNub client configuration: 'subscribe_key' is empty.".into());
+    }
+
+    Ok(())
+}
+
+/// Validate that required string field has been provided and not empty.
+pub(in crate::dx::message_actions::builders) fn validate_not_empty(
+    value: &Option<String>,
+    name: &str,
+) -> Result<(), String> {
+    value
+        .as_ref()
+        .filter(|value| !value.is_empty())
+        .map(|_| ())
+        .ok_or(crate::lib::alloc::format!("'{name}' can't be empty."))
+}
@@ -0,0 +1,153 @@
+//! # PubNub remove message action module.
+//!
+//! The [`RemoveMessageActionRequestBuilder`] lets you make and execute
+//! requests that will remove previously added action from the published
+//! message.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        utils::encoding::url_encode, Deserializer, PubNubError, Transport, TransportMethod,
+        TransportRequest,
+    },
+    dx::{
+        message_actions::{
+            builders,
+            result::{RemoveMessageActionResponseBody, RemoveMessageActionResult},
+        },
+        pubnub_client::PubNubClientInstance,
+    },
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+    },
+};
+
+/// The [`RemoveMessageActionRequestBuilder`] is used to build remove message
+/// action request that is sent to the [`PubNub`] network.
+///
+/// This struct is used by the [`remove_message_action`] method of the
+/// [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`remove_message_action`]: crate::dx::PubNubClient::remove_message_action
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(
+        vis = "pub(in crate::dx::message_actions)",
+        validate = "Self::validate"
+    ),
+    no_std
+)]
+pub struct RemoveMessageActionRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(custom))]
+    pub(in crate::dx::message_actions) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Channel where message has been published.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) channel: String,
+
+    /// Timetoken of message from which action should be removed.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) message_timetoken: String,
+
+    /// Timetoken of message action which should be removed.
+    #[builder(field(vis = "pub(in crate::dx::message_actions)"), setter(into))]
+    pub(in crate::dx::message_actions) action_timetoken: String,
+}
+
+impl<T, D> RemoveMessageActionRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that list of provided data is enough to build valid
+    /// remove message action request instance.
+    fn validate(&self) -> Result<(), String> {
+        builders::validate_configuration(&self.pubnub_client)
+            .and_then(|_| builders::validate_not_empty(&self.channel, "channel"))
+            .and_then(|_| {
+                builders::validate_not_empty(&self.message_timetoken, "message_timetoken")
+            })
+            .and_then(|_| builders::validate_not_empty(&self.action_timetoken, "action_timetoken"))
+    }
+
+    /// Build [`RemoveMessageActionRequest`] from builder.
+    fn request(self) -> Result<RemoveMessageActionRequest<T, D>, PubNubError> {
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, D> RemoveMessageActionRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::message_actions) fn transport_request(
+        &self,
+    ) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/v1/message-actions/{}/channel/{}/message/{}/action/{}",
+                &config.subscribe_key,
+                url_encode(self.channel.as_bytes()),
+                url_encode(self.message_timetoken.as_bytes()),
+                url_encode(self.action_timetoken.as_bytes())
+            ),
+            method: TransportMethod::Delete,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, D> RemoveMessageActionRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<RemoveMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<RemoveMessageActionResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> RemoveMessageActionRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Build and call synchronous request.
+    pub fn execute_blocking(self) -> Result<RemoveMessageActionResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request.send_blocking::<RemoveMessageActionResponseBody, _, _, _>(
+            &client.transport,
+            deserializer,
+        )
+    }
+}
@@ -0,0 +1,304 @@
+//! # Message actions module.
+//!
+//! The message actions module allows adding, removing and fetching actions
+//! (like reactions or read receipts) associated with published messages.
+//! The message actions module contains [`AddMessageActionRequestBuilder`],
+//! [`RemoveMessageActionRequestBuilder`] and
+//! [`GetMessageActionsRequestBuilder`] types.
+
+#[doc(inline)]
+pub use builders::*;
+pub mod builders;
+
+#[doc(inline)]
+pub use result::{
+    AddMessageActionResult, GetMessageActionsResult, MessageActionData, MoreMessageActions,
+    RemoveMessageActionResult,
+};
+pub mod result;
+
+use crate::dx::pubnub_client::PubNubClientInstance;
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create an add message action request builder.
+    ///
+    /// This method is used to add action (like reaction) to the message which
+    /// has been published to the channel.
+    ///
+    /// Instance of [`AddMessageActionRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .add_message_action()
+    ///     .channel("chat")
+    ///     .message_timetoken("15610547826969050")
+    ///     .action_type("reaction")
+    ///     .value("smiley_face")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_message_action(&self) -> AddMessageActionRequestBuilder<T, D> {
+        AddMessageActionRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a remove message action request builder.
+    ///
+    /// This method is used to remove previously added action from the
+    /// message.
+    ///
+    /// Instance of [`RemoveMessageActionRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// pubnub
+    ///     .remove_message_action()
+    ///     .channel("chat")
+    ///     .message_timetoken("15610547826969050")
+    ///     .action_timetoken("15610547826970050")
+    ///     .execute()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn remove_message_action(&self) -> RemoveMessageActionRequestBuilder<T, D> {
+        RemoveMessageActionRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+
+    /// Create a get message actions request builder.
+    ///
+    /// This method is used to fetch actions which has been added to the
+    /// messages in the channel within specified timetoken range.
+    ///
+    /// Instance of [`GetMessageActionsRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: None,
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// let result = pubnub
+    ///     .get_message_actions()
+    ///     .channel("chat")
+    ///     .start("15645905639093361")
+    ///     .limit(10)
+    ///     .execute()
+    ///     .await?;
+    /// println!("Actions: {:?}", result.actions);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_message_actions(&self) -> GetMessageActionsRequestBuilder<T, D> {
+        GetMessageActionsRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse},
+        dx::subscribe::MessageActionEvent,
+        lib::alloc::{boxed::Box, vec::Vec},
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    /// Requests handler function type.
+    type RequestHandler = Box<dyn Fn(&TransportRequest) + Send + Sync>;
+
+    struct MockTransport {
+        /// Response body which should be returned by transport.
+        response_body: &'static str,
+
+        /// Request handler function which will be called before returning
+        /// response.
+        ///
+        /// Use function to verify request parameters.
+        request_handler: RequestHandler,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            (self.request_handler)(&req);
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(Vec::from(self.response_body)),
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Construct test client with mocked transport.
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn add_message_action() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":{"type":"reaction","value":"smiley","uuid":"user","actionTimetoken":"15610547826970050","messageTimetoken":"15610547826969050"}}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v1/message-actions/demo/channel/chat%20room/message/15610547826969050"
+                );
+                assert!(matches!(req.method, TransportMethod::Post));
+                assert_eq!(
+                    req.body.as_deref(),
+                    Some(br#"{"type":"reaction","value":"smiley"}"#.as_slice())
+                );
+            }),
+        };
+
+        let result = client(transport)
+            .add_message_action()
+            .channel("chat room")
+            .message_timetoken("15610547826969050")
+            .action_type("reaction")
+            .value("smiley")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.event, MessageActionEvent::Update);
+        assert_eq!(result.action.action_timetoken, "15610547826970050");
+        assert_eq!(result.action.value, "smiley");
+    }
+
+    #[tokio::test]
+    async fn remove_message_action() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":{}}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v1/message-actions/demo/channel/chat/message/15610547826969050/action/15610547826970050"
+                );
+                assert!(matches!(req.method, TransportMethod::Delete));
+            }),
+        };
+
+        let result = client(transport)
+            .remove_message_action()
+            .channel("chat")
+            .message_timetoken("15610547826969050")
+            .action_timetoken("15610547826970050")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.event, MessageActionEvent::Delete);
+    }
+
+    #[tokio::test]
+    async fn get_message_actions_in_range() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":[{"type":"receipt","value":"read","uuid":"user","actionTimetoken":"15610547826970050","messageTimetoken":"15610547826969050"}]}"#,
+            request_handler: Box::new(|req| {
+                assert_eq!(req.path, "/v1/message-actions/demo/channel/chat");
+                assert!(matches!(req.method, TransportMethod::Get));
+                assert_eq!(
+                    req.query_parameters.get("start"),
+                    Some(&"15645905639093361".into())
+                );
+                assert_eq!(
+                    req.query_parameters.get("end"),
+                    Some(&"15610547826969050".into())
+                );
+                assert_eq!(req.query_parameters.get("limit"), Some(&"10".into()));
+            }),
+        };
+
+        let result = client(transport)
+            .get_message_actions()
+            .channel("chat")
+            .start("15645905639093361")
+            .end("15610547826969050")
+            .limit(10)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.actions.len(), 1);
+        assert_eq!(result.actions[0].r#type, "receipt");
+        assert!(result.more.is_none());
+    }
+
+    #[tokio::test]
+    async fn not_send_request_without_message_timetoken() {
+        let transport = MockTransport {
+            response_body: r#"{"status":200,"data":{}}"#,
+            request_handler: Box::new(|_| panic!("Request shouldn't be sent")),
+        };
+
+        let result = client(transport)
+            .remove_message_action()
+            .channel("chat")
+            .action_timetoken("15610547826970050")
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,330 @@
+//! Message actions result module.
+//!
+//! This module contains results of message actions API calls:
+//! [`AddMessageActionResult`], [`RemoveMessageActionResult`] and
+//! [`GetMessageActionsResult`].
+
+use crate::{
+    core::{service_response::APIErrorBody, PubNubError},
+    dx::subscribe::MessageActionEvent,
+    lib::{
+        alloc::{string::String, vec::Vec},
+        collections::HashMap,
+    },
+};
+
+/// Information about action which has been added to the message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct MessageActionData {
+    /// Message action type.
+    pub r#type: String,
+
+    /// Value associated with message action `type`.
+    pub value: String,
+
+    /// Identifier of client which added message action.
+    pub uuid: String,
+
+    /// Timetoken of message action.
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "actionTimetoken")))]
+    pub action_timetoken: String,
+
+    /// Timetoken of message to which action has been added.
+    #[cfg_attr(feature = "serde", serde(rename(deserialize = "messageTimetoken")))]
+    pub message_timetoken: String,
+}
+
+/// Information about next page of message actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct MoreMessageActions {
+    /// Timetoken which should be used as `start` for next page request.
+    pub start: String,
+
+    /// Timetoken which should be used as `end` for next page request.
+    pub end: String,
+
+    /// Maximum number of actions which has been requested.
+    pub limit: usize,
+}
+
+/// Result of successful message actions REST API endpoint call.
+///
+/// Body contains status code, response `data` and optional information about
+/// next page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct MessageActionsSuccessBody<D> {
+    /// Operation status (HTTP) code.
+    pub status: i32,
+
+    /// Operation response data.
+    pub data: D,
+
+    /// Information about next page (if any).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub more: Option<MoreMessageActions>,
+}
+
+/// The result of an add message action operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddMessageActionResult {
+    /// The type of event that happened with message action.
+    ///
+    /// Always [`MessageActionEvent::Update`] for added message action.
+    pub event: MessageActionEvent,
+
+    /// Information about added message action.
+    pub action: MessageActionData,
+}
+
+/// Message actions service response body for add message action.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddMessageActionResponseBody {
+    /// This is a success response body for an add message action operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {
+    ///         "type": "reaction",
+    ///         "value": "smiley_face",
+    ///         "uuid": "user-456",
+    ///         "actionTimetoken": "15610547826970050",
+    ///         "messageTimetoken": "15610547826969050"
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(MessageActionsSuccessBody<MessageActionData>),
+
+    /// This is an error response body for an add message action operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 409,
+    ///     "error": {
+    ///         "source": "actions",
+    ///         "message": "Action Already Added"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<AddMessageActionResponseBody> for AddMessageActionResult {
+    type Error = PubNubError;
+
+    fn try_from(value: AddMessageActionResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            AddMessageActionResponseBody::SuccessResponse(resp) => Ok(Self {
+                event: MessageActionEvent::Update,
+                action: resp.data,
+            }),
+            AddMessageActionResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a remove message action operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoveMessageActionResult {
+    /// The type of event that happened with message action.
+    ///
+    /// Always [`MessageActionEvent::Delete`] for removed message action.
+    pub event: MessageActionEvent,
+}
+
+/// Message actions service response body for remove message action.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoveMessageActionResponseBody {
+    /// This is a success response body for a remove message action operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": {}
+    /// }
+    /// ```
+    SuccessResponse(MessageActionsSuccessBody<HashMap<String, String>>),
+
+    /// This is an error response body for a remove message action operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 403,
+    ///     "error": {
+    ///         "source": "actions",
+    ///         "message": "Forbidden"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<RemoveMessageActionResponseBody> for RemoveMessageActionResult {
+    type Error = PubNubError;
+
+    fn try_from(value: RemoveMessageActionResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            RemoveMessageActionResponseBody::SuccessResponse(_) => Ok(Self {
+                event: MessageActionEvent::Delete,
+            }),
+            RemoveMessageActionResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+/// The result of a get message actions operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetMessageActionsResult {
+    /// List of message actions in requested timetoken range.
+    pub actions: Vec<MessageActionData>,
+
+    /// Information about next page (if more actions available).
+    pub more: Option<MoreMessageActions>,
+}
+
+/// Message actions service response body for get message actions.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GetMessageActionsResponseBody {
+    /// This is a success response body for a get message actions operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 200,
+    ///     "data": [
+    ///         {
+    ///             "type": "reaction",
+    ///             "value": "smiley_face",
+    ///             "uuid": "user-456",
+    ///             "actionTimetoken": "15610547826970050",
+    ///             "messageTimetoken": "15610547826969050"
+    ///         }
+    ///     ],
+    ///     "more": {
+    ///         "url": "/v1/message-actions/sub-key/channel/chat?start=15610547826970050&limit=1",
+    ///         "start": "15610547826970050",
+    ///         "end": "15645905639093361",
+    ///         "limit": 1
+    ///     }
+    /// }
+    /// ```
+    SuccessResponse(MessageActionsSuccessBody<Vec<MessageActionData>>),
+
+    /// This is an error response body for a get message actions operation.
+    ///
+    /// # Example
+    /// ```json
+    /// {
+    ///     "status": 400,
+    ///     "error": {
+    ///         "source": "actions",
+    ///         "message": "Invalid Subkey"
+    ///     }
+    /// }
+    /// ```
+    ErrorResponse(APIErrorBody),
+}
+
+impl TryFrom<GetMessageActionsResponseBody> for GetMessageActionsResult {
+    type Error = PubNubError;
+
+    fn try_from(value: GetMessageActionsResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            GetMessageActionsResponseBody::SuccessResponse(resp) => Ok(Self {
+                actions: resp.data,
+                more: resp.more,
+            }),
+            GetMessageActionsResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+
+    #[test]
+    fn parse_get_message_actions_response() {
+        let body: GetMessageActionsResponseBody = serde_json::from_str(
+            r#"{
+                "status": 200,
+                "data": [
+                    {
+                        "type": "reaction",
+                        "value": "smiley_face",
+                        "uuid": "user-456",
+                        "actionTimetoken": "15610547826970050",
+                        "messageTimetoken": "15610547826969050"
+                    }
+                ],
+                "more": {
+                    "url": "/v1/message-actions/demo/channel/chat?start=15610547826970050&limit=1",
+                    "start": "15610547826970050",
+                    "end": "15645905639093361",
+                    "limit": 1
+                }
+            }"#,
+        )
+        .unwrap();
+        let result: GetMessageActionsResult = body.try_into().unwrap();
+
+        assert_eq!(
+            result.actions,
+            vec![MessageActionData {
+                r#type: "reaction".into(),
+                value: "smiley_face".into(),
+                uuid: "user-456".into(),
+                action_timetoken: "15610547826970050".into(),
+                message_timetoken: "15610547826969050".into(),
+            }]
+        );
+        assert_eq!(
+            result.more,
+            Some(MoreMessageActions {
+                start: "15610547826970050".into(),
+                end: "15645905639093361".into(),
+                limit: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_get_message_actions_response_without_more() {
+        let body: GetMessageActionsResponseBody =
+            serde_json::from_str(r#"{"status": 200, "data": []}"#).unwrap();
+        let result: GetMessageActionsResult = body.try_into().unwrap();
+
+        assert!(result.actions.is_empty());
+        assert!(result.more.is_none());
+    }
+
+    #[test]
+    fn parse_remove_message_action_response() {
+        let body: RemoveMessageActionResponseBody =
+            serde_json::from_str(r#"{"status": 200, "data": {}}"#).unwrap();
+        let result: RemoveMessageActionResult = body.try_into().unwrap();
+
+        assert_eq!(result.event, MessageActionEvent::Delete);
+    }
+
+    #[test]
+    fn return_error_for_error_response() {
+        let body: AddMessageActionResponseBody = serde_json::from_str(
+            r#"{"status": 409, "error": {"source": "actions", "message": "Action Already Added"}}"#,
+        )
+        .unwrap();
+        let result: Result<AddMessageActionResult, PubNubError> = body.try_into();
+
+        assert!(result.is_err());
+    }
+}
@@ -12,12 +12,32 @@ pub mod access;
 #[cfg(feature = "publish")]
 pub mod publish;
 
+#[cfg(feature = "publish")]
+pub mod signal;
+
+#[cfg(all(feature = "publish", feature = "subscribe", feature = "serde"))]
+pub mod message_actions;
+
+#[cfg(all(feature = "publish", feature = "subscribe"))]
+pub mod history;
+
+#[cfg(feature = "serde")]
+pub mod app_context;
+
+#[cfg(feature = "subscribe")]
+pub mod channel_groups;
+
+#[cfg(all(feature = "publish", feature = "serde"))]
+pub mod files;
+
 #[cfg(feature = "subscribe")]
 pub mod subscribe;
 
 #[cfg(feature = "presence")]
 pub mod presence;
 
+pub mod time;
+
 #[cfg(all(feature = "parse_token", feature = "serde"))]
 pub use parse_token::parse_token;
 #[cfg(feature = "parse_token")]
@@ -50,8 +50,9 @@ pub fn parse_token_with<D>(token: &str, deserializer: D) -> Result<Token, PubNub
 where
     D: Deserializer,
 {
+    let padding = (4 - token.len() % 4) % 4;
     let token_bytes = general_purpose::URL_SAFE
-        .decode(format!("{token}{}", "=".repeat(token.len() % 4)).as_bytes())
+        .decode(format!("{token}{}", "=".repeat(padding)).as_bytes())
         .map_err(|e| PubNubError::TokenDeserialization {
             details: e.to_string(),
         })?;
@@ -249,4 +250,35 @@ mod should {
             token
         );
     }
+
+    #[test]
+    fn return_error_for_malformed_base64_token() {
+        let result = parse_token("not a token!");
+
+        assert!(matches!(
+            result,
+            Err(PubNubError::TokenDeserialization { .. })
+        ));
+    }
+
+    #[test]
+    fn return_error_for_corrupted_token() {
+        // Valid base64 (`test-payload`), but not a CBOR-encoded token.
+        let result = parse_token("dGVzdC1wYXlsb2Fk");
+
+        assert!(matches!(
+            result,
+            Err(PubNubError::TokenDeserialization { .. })
+        ));
+    }
+
+    #[test]
+    fn return_error_for_truncated_token() {
+        let base64_token = "qEF2AkF0GmQ1YSpDdHRsGQU5Q3Jlc6VEY2hhbqFvY2hhbm5lbFJlc291cmNl";
+
+        assert!(matches!(
+            parse_token(base64_token),
+            Err(PubNubError::TokenDeserialization { .. })
+        ));
+    }
 }
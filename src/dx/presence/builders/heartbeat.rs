@@ -247,6 +247,8 @@ where
                 #[cfg(feature = "std")]
                 &client.config.transport.retry_configuration,
                 #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
                 &client.runtime,
             )
             .await
@@ -106,6 +106,7 @@ mod it_should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -123,6 +124,7 @@ mod it_should {
             0,
             Some(PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -133,6 +135,7 @@ mod it_should {
                 max_retry: 5,
                 delay: 2,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mocked_heartbeat_function,
         )
@@ -151,6 +154,7 @@ mod it_should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -168,6 +172,7 @@ mod it_should {
             5,
             Some(PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -178,6 +183,7 @@ mod it_should {
                 delay: 0,
                 max_retry: 1,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mocked_heartbeat_function,
         )
@@ -203,6 +209,7 @@ mod it_should {
             5,
             Some(PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -213,6 +220,7 @@ mod it_should {
                 max_retry: 5,
                 delay: 2,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mocked_heartbeat_function,
         )
@@ -227,6 +235,7 @@ mod it_should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -244,6 +253,7 @@ mod it_should {
             5,
             Some(PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -254,6 +264,7 @@ mod it_should {
                 delay: 0,
                 max_retry: 1,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mocked_heartbeat_function,
         )
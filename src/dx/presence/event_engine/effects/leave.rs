@@ -12,7 +12,6 @@ use crate::{
     },
 };
 
-#[allow(clippy::too_many_arguments, dead_code)]
 pub(super) async fn execute(
     input: &PresenceInput,
     effect_id: &str,
@@ -26,15 +25,19 @@ pub(super) async fn execute(
         channels, channel_groups
     );
 
-    let _ = executor(PresenceParameters {
+    if let Err(error) = executor(PresenceParameters {
         channels: &channels,
         channel_groups: &channel_groups,
         attempt: 0,
         reason: None,
         effect_id,
     })
-    .await;
+    .await
+    {
+        log::error!("Leave error: {:?}", error);
+    }
 
+    // Presence state machine doesn't have events for `leave` result.
     vec![]
 }
 
@@ -79,6 +82,7 @@ mod it_should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -266,9 +266,10 @@ impl Effect for PresenceEffect {
                     let mut cancelled_slot = cancelled.write();
                     *cancelled_slot = true;
                 }
-                cancellation_channel
-                    .send_blocking(id.clone())
-                    .expect("Cancellation pipe is broken!");
+                // Receiver can be dropped during event engine teardown.
+                if let Err(error) = cancellation_channel.send_blocking(id.clone()) {
+                    log::warn!("Unable to cancel presence effect {id}: {error}");
+                }
             }
             _ => { /* cannot cancel other effects */ }
         }
@@ -323,4 +324,21 @@ mod it_should {
         effect.cancel();
         assert_eq!(rx.recv().await.unwrap(), effect.id())
     }
+
+    #[test]
+    fn not_panic_on_cancel_when_receiver_dropped() {
+        let (tx, rx) = async_channel::bounded(1);
+        drop(rx);
+
+        let effect = PresenceEffect::Wait {
+            id: Uuid::new_v4().to_string(),
+            cancelled: RwLock::new(false),
+            input: PresenceInput::new(&None, &None),
+            executor: Arc::new(|_| Box::pin(async move { Ok(()) })),
+            cancellation_channel: tx,
+        };
+
+        effect.cancel();
+        assert!(effect.is_cancelled());
+    }
 }
@@ -412,7 +412,10 @@ mod it_should {
     use crate::presence::event_engine::effects::LeaveEffectExecutor;
     use crate::presence::LeaveResult;
     use crate::{
-        core::{event_engine::EventEngine, RequestRetryConfiguration},
+        core::{
+            event_engine::{CancellationTask, EventEngine},
+            RequestRetryConfiguration,
+        },
         lib::alloc::sync::Arc,
         presence::{
             event_engine::{
@@ -423,7 +426,11 @@ mod it_should {
         },
         providers::futures_tokio::RuntimeTokio,
     };
-    use futures::FutureExt;
+    use core::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+    use futures::{select_biased, FutureExt};
     use test_case::test_case;
 
     fn event_engine(start_state: PresenceState) -> Arc<PresenceEventEngine> {
@@ -451,6 +458,84 @@ mod it_should {
         )
     }
 
+    #[tokio::test]
+    async fn heartbeat_on_each_interval_tick() {
+        let heartbeats = Arc::new(AtomicUsize::new(0));
+        let heartbeats_counter = heartbeats.clone();
+        let (tick_tx, tick_rx) = async_channel::unbounded::<()>();
+        let (cancel_tx, cancel_rx) = async_channel::bounded::<String>(3);
+
+        let heartbeat_call: Arc<HeartbeatEffectExecutor> = Arc::new(move |_| {
+            heartbeats_counter.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(HeartbeatResult) }.boxed()
+        });
+        let delayed_heartbeat_call: Arc<HeartbeatEffectExecutor> =
+            Arc::new(|_| async move { Ok(HeartbeatResult) }.boxed());
+        let leave_call: Arc<LeaveEffectExecutor> =
+            Arc::new(|_| async move { Ok(LeaveResult) }.boxed());
+        // Mocked clock: every tick sent into the channel completes one wait.
+        let wait_call: Arc<WaitEffectExecutor> = Arc::new(move |effect_id| {
+            let cancel_task = CancellationTask::new(cancel_rx.clone(), effect_id.to_owned());
+            let tick_rx = tick_rx.clone();
+
+            async move {
+                select_biased! {
+                    _ = cancel_task.wait_for_cancel().fuse() => Err(PubNubError::EffectCanceled),
+                    _ = tick_rx.recv().fuse() => Ok(())
+                }
+            }
+            .boxed()
+        });
+
+        let engine = EventEngine::new(
+            PresenceEffectHandler::new(
+                heartbeat_call,
+                delayed_heartbeat_call,
+                leave_call,
+                wait_call,
+                RequestRetryConfiguration::None,
+                cancel_tx,
+            ),
+            PresenceState::Inactive,
+            RuntimeTokio,
+        );
+
+        let wait_for_heartbeats = |expected: usize| {
+            let heartbeats = heartbeats.clone();
+            async move {
+                for _ in 0..100 {
+                    if heartbeats.load(Ordering::SeqCst) >= expected {
+                        break;
+                    }
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+                assert_eq!(heartbeats.load(Ordering::SeqCst), expected);
+            }
+        };
+
+        engine.process(&PresenceEvent::Joined {
+            heartbeat_interval: 10,
+            channels: Some(vec!["ch1".to_string()]),
+            channel_groups: None,
+        });
+        wait_for_heartbeats(1).await;
+
+        for expected in 2..=4 {
+            tick_tx.send(()).await.unwrap();
+            wait_for_heartbeats(expected).await;
+        }
+
+        engine.process(&PresenceEvent::LeftAll {
+            suppress_leave_events: true,
+        });
+        assert_eq!(engine.current_state(), PresenceState::Inactive);
+
+        // Clock ticks after leave shouldn't trigger any more heartbeats.
+        tick_tx.send(()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(heartbeats.load(Ordering::SeqCst), 4);
+    }
+
     #[test_case(
         PresenceState::Inactive,
         PresenceEvent::Joined {
@@ -469,7 +554,7 @@ mod it_should {
     #[test_case(
         PresenceState::Inactive,
         PresenceEvent::HeartbeatFailure {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: "Test".to_string(), status: None, response: None }
         },
         PresenceState::Inactive;
         "to not change on unexpected event"
@@ -579,7 +664,7 @@ mod it_should {
             )
         },
         PresenceEvent::HeartbeatFailure {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: "Test".to_string(), status: None, response: None }
         },
         PresenceState::Reconnecting {
             input: PresenceInput::new(
@@ -587,7 +672,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: "Test".to_string(), status: None, response: None }
         };
         "to reconnect on heartbeat failure"
     )]
@@ -668,7 +753,7 @@ mod it_should {
             )
         },
         PresenceEvent::HeartbeatGiveUp {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: "Test".to_string(), status: None, response: None }
         },
         PresenceState::Heartbeating {
             input: PresenceInput::new(
@@ -842,7 +927,7 @@ mod it_should {
             )
         },
         PresenceEvent::HeartbeatGiveUp {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: "Test".to_string(), status: None, response: None }
         },
         PresenceState::Cooldown {
             input: PresenceInput::new(
@@ -875,10 +960,10 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::HeartbeatFailure {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         },
         PresenceState::Reconnecting {
             input: PresenceInput::new(
@@ -886,7 +971,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         };
         "to heartbeat reconnecting on heartbeat failure"
     )]
@@ -897,7 +982,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -919,7 +1004,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -941,7 +1026,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -958,7 +1043,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::HeartbeatSuccess,
         PresenceState::Cooldown {
@@ -976,17 +1061,17 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::HeartbeatGiveUp {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         },
         PresenceState::Failed {
             input: PresenceInput::new(
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         };
         "to failed on heartbeat give up"
     )]
@@ -997,7 +1082,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Disconnect,
         PresenceState::Stopped {
@@ -1015,7 +1100,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::LeftAll {
             suppress_leave_events: false,
@@ -1030,7 +1115,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -1043,7 +1128,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on joined with same channels and groups"
     )]
@@ -1054,7 +1139,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1067,7 +1152,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on left with unknown channels and groups"
     )]
@@ -1078,7 +1163,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Reconnect,
         PresenceState::Reconnecting {
@@ -1087,7 +1172,7 @@ mod it_should {
                 &Some(vec!["gr1".to_string()])
             ),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on unexpected event"
     )]
@@ -1269,7 +1354,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -1290,7 +1375,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string(), "ch2".to_string()]), 
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1311,7 +1396,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string(), "ch2".to_string()]), 
                 &Some(vec!["gr1".to_string(), "gr2".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1327,7 +1412,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Reconnect,
         PresenceState::Heartbeating {
@@ -1344,7 +1429,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Disconnect,
         PresenceState::Stopped {
@@ -1361,7 +1446,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::LeftAll {
             suppress_leave_events: false,
@@ -1375,7 +1460,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Joined {
             heartbeat_interval: 10,
@@ -1387,7 +1472,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on joined with same channels and groups"
     )]
@@ -1397,7 +1482,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::Left {
             suppress_leave_events: false,
@@ -1409,7 +1494,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on left with unknown channels and groups"
     )]
@@ -1419,7 +1504,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         PresenceEvent::HeartbeatSuccess,
         PresenceState::Failed {
@@ -1427,7 +1512,7 @@ mod it_should {
                 &Some(vec!["ch1".to_string()]), 
                 &Some(vec!["gr1".to_string()])
             ),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on unexpected event"
     )]
@@ -1446,4 +1531,178 @@ mod it_should {
 
         assert_eq!(engine.current_state(), target_state);
     }
+
+    #[test_case(
+        PresenceState::Heartbeating {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None)
+        },
+        PresenceEvent::HeartbeatSuccess,
+        &["WAIT"];
+        "wait on heartbeat success"
+    )]
+    #[test_case(
+        PresenceState::Heartbeating {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None)
+        },
+        PresenceEvent::HeartbeatFailure {
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        &["DELAYED_HEARTBEAT"];
+        "delayed heartbeat on heartbeat failure"
+    )]
+    #[test_case(
+        PresenceState::Reconnecting {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None),
+            attempts: 1,
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        PresenceEvent::HeartbeatFailure {
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        &["CANCEL_DELAYED_HEARTBEAT", "DELAYED_HEARTBEAT"];
+        "retry delayed heartbeat on heartbeat failure"
+    )]
+    #[test_case(
+        PresenceState::Reconnecting {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None),
+            attempts: 1,
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        PresenceEvent::HeartbeatSuccess,
+        &["CANCEL_DELAYED_HEARTBEAT", "WAIT"];
+        "wait on delayed heartbeat success"
+    )]
+    #[test_case(
+        PresenceState::Reconnecting {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None),
+            attempts: 1,
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        PresenceEvent::HeartbeatGiveUp {
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        &["CANCEL_DELAYED_HEARTBEAT"];
+        "stop delayed heartbeat on give up"
+    )]
+    #[test_case(
+        PresenceState::Cooldown {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None)
+        },
+        PresenceEvent::TimesUp,
+        &["CANCEL_WAIT", "HEARTBEAT"];
+        "heartbeat on times up"
+    )]
+    #[test_case(
+        PresenceState::Heartbeating {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string(), "ch2".to_string()]), &None)
+        },
+        PresenceEvent::Left {
+            suppress_leave_events: false,
+            channels: Some(vec!["ch1".to_string()]),
+            channel_groups: None,
+        },
+        &["LEAVE", "HEARTBEAT"];
+        "leave and heartbeat remaining on left"
+    )]
+    #[test_case(
+        PresenceState::Cooldown {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None)
+        },
+        PresenceEvent::LeftAll {
+            suppress_leave_events: false,
+        },
+        &["CANCEL_WAIT", "LEAVE"];
+        "stop wait and leave on left all"
+    )]
+    #[test_case(
+        PresenceState::Cooldown {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None)
+        },
+        PresenceEvent::LeftAll {
+            suppress_leave_events: true,
+        },
+        &["CANCEL_WAIT"];
+        "not leave on left all with suppressed leave events"
+    )]
+    #[test_case(
+        PresenceState::Heartbeating {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None)
+        },
+        PresenceEvent::Disconnect,
+        &["LEAVE"];
+        "leave on disconnect"
+    )]
+    fn request_effects_on_transition(
+        init_state: PresenceState,
+        event: PresenceEvent,
+        expected_invocations: &[&str],
+    ) {
+        let transition = init_state.transition(&event).unwrap();
+        let invocations = transition
+            .invocations
+            .iter()
+            .map(|invocation| invocation.id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(invocations, expected_invocations);
+    }
+
+    #[test]
+    fn leave_only_removed_channels() {
+        let state = PresenceState::Heartbeating {
+            input: PresenceInput::new(
+                &Some(vec!["ch1".to_string(), "ch2".to_string()]),
+                &Some(vec!["gr1".to_string()]),
+            ),
+        };
+        let transition = state
+            .transition(&PresenceEvent::Left {
+                suppress_leave_events: false,
+                channels: Some(vec!["ch1".to_string()]),
+                channel_groups: None,
+            })
+            .unwrap();
+
+        assert!(matches!(
+            transition.invocations.first(),
+            Some(Leave { input }) if *input == PresenceInput::new(&Some(vec!["ch1".to_string()]), &None)
+        ));
+        assert_eq!(
+            transition.state,
+            Some(PresenceState::Heartbeating {
+                input: PresenceInput::new(
+                    &Some(vec!["ch2".to_string()]),
+                    &Some(vec!["gr1".to_string()])
+                ),
+            })
+        );
+    }
+
+    #[test]
+    fn increment_delayed_heartbeat_attempts() {
+        let state = PresenceState::Reconnecting {
+            input: PresenceInput::new(&Some(vec!["ch1".to_string()]), &None),
+            attempts: 2,
+            reason: PubNubError::Transport {
+                details: "Test reason".to_string(),
+                status: None,
+                response: None,
+            },
+        };
+        let transition = state
+            .transition(&PresenceEvent::HeartbeatFailure {
+                reason: PubNubError::Transport {
+                    details: "Test reason".to_string(),
+                    status: Some(500),
+                    response: None,
+                },
+            })
+            .unwrap();
+
+        assert!(matches!(
+            transition.invocations.last(),
+            Some(DelayedHeartbeat { attempts: 3, reason, .. })
+                if matches!(reason, PubNubError::Transport { status: Some(500), .. })
+        ));
+    }
 }
@@ -39,7 +39,7 @@ pub(crate) mod event_engine;
 use crate::{
     core::{
         event_engine::{cancel::CancellationTask, EventEngine},
-        Deserializer, PubNubError, Runtime, Transport,
+        Deserializer, PubNubError, RequestRetryConfiguration, Runtime, Transport,
     },
     lib::alloc::sync::Arc,
 };
@@ -562,11 +562,11 @@ where
         let delayed_heartbeat_call_client = self.clone();
         let heartbeat_call_client = self.clone();
         let leave_call_client = self.clone();
-        let wait_call_client = self.clone();
         let request_retry = self.config.transport.retry_configuration.clone();
         let request_delayed_retry = request_retry.clone();
-        let delayed_heartbeat_runtime_sleep = runtime.clone();
-        let wait_runtime_sleep = runtime.clone();
+        let delayed_heartbeat_runtime = runtime.clone();
+        let wait_runtime = runtime.clone();
+        let heartbeat_interval = self.config.presence.heartbeat_interval;
 
         EventEngine::new(
             PresenceEffectHandler::new(
@@ -574,26 +574,11 @@ where
                     Self::heartbeat_call(heartbeat_call_client.clone(), parameters.clone())
                 }),
                 Arc::new(move |parameters| {
-                    let delay_in_microseconds = request_delayed_retry.retry_delay(
-                        Some("/v2/presence".to_string()),
-                        &parameters.attempt,
-                        parameters.reason.as_ref(),
-                    );
-                    let inner_runtime_sleep = delayed_heartbeat_runtime_sleep.clone();
-
                     Self::delayed_heartbeat_call(
                         delayed_heartbeat_call_client.clone(),
                         parameters.clone(),
-                        Arc::new(move || {
-                            if let Some(delay) = delay_in_microseconds {
-                                inner_runtime_sleep
-                                    .clone()
-                                    .sleep_microseconds(delay)
-                                    .boxed()
-                            } else {
-                                ready(()).boxed()
-                            }
-                        }),
+                        &request_delayed_retry,
+                        delayed_heartbeat_runtime.clone(),
                         delayed_heartbeat_cancel_rx.clone(),
                     )
                 }),
@@ -601,18 +586,10 @@ where
                     Self::leave_call(leave_call_client.clone(), parameters.clone())
                 }),
                 Arc::new(move |effect_id| {
-                    let delay_in_secs = wait_call_client.config.presence.heartbeat_interval;
-                    let inner_runtime_sleep = wait_runtime_sleep.clone();
-
                     Self::wait_call(
                         effect_id,
-                        Arc::new(move || {
-                            if let Some(delay) = delay_in_secs {
-                                inner_runtime_sleep.clone().sleep(delay).boxed()
-                            } else {
-                                ready(()).boxed()
-                            }
-                        }),
+                        heartbeat_interval,
+                        wait_runtime.clone(),
                         wait_cancel_rx.clone(),
                     )
                 }),
@@ -639,21 +616,40 @@ where
     }
 
     /// Call delayed announce of `user_id` presence.
-    pub(crate) fn delayed_heartbeat_call<F>(
+    ///
+    /// Delay computed by `retry_configuration` for current attempt and failure
+    /// reason. Call can be cancelled with `cancel_rx` while waiting or before
+    /// request completion.
+    pub(crate) fn delayed_heartbeat_call<R>(
         client: Self,
         params: PresenceParameters,
-        delay: Arc<F>,
+        retry_configuration: &RequestRetryConfiguration,
+        runtime: R,
         cancel_rx: async_channel::Receiver<String>,
     ) -> BoxFuture<'static, Result<HeartbeatResult, PubNubError>>
     where
-        F: Fn() -> BoxFuture<'static, ()> + Send + Sync + 'static,
+        R: Runtime + Sync + 'static,
     {
+        let delay_in_microseconds = retry_configuration.retry_delay(
+            Some("/v2/presence".to_string()),
+            &params.attempt,
+            params.reason.as_ref(),
+        );
         let effect_id = params.effect_id.to_owned();
         let cancel_task = CancellationTask::new(cancel_rx, effect_id);
 
         client
             .heartbeat_request(params)
-            .execute_with_cancel_and_delay(delay, cancel_task)
+            .execute_with_cancel_and_delay(
+                Arc::new(move || {
+                    if let Some(delay) = delay_in_microseconds {
+                        runtime.clone().sleep_microseconds(delay).boxed()
+                    } else {
+                        ready(()).boxed()
+                    }
+                }),
+                cancel_task,
+            )
             .boxed()
     }
 
@@ -680,22 +676,30 @@ where
     }
 
     /// Heartbeat idle.
-    pub(crate) fn wait_call<F>(
+    ///
+    /// Resolves after `interval` seconds (immediately if not set) or with
+    /// [`PubNubError::EffectCanceled`] error if cancelled with `cancel_rx`.
+    pub(crate) fn wait_call<R>(
         effect_id: &str,
-        delay: Arc<F>,
+        interval: Option<u64>,
+        runtime: R,
         cancel_rx: async_channel::Receiver<String>,
     ) -> BoxFuture<'static, Result<(), PubNubError>>
     where
-        F: Fn() -> BoxFuture<'static, ()> + Send + Sync + 'static,
+        R: Runtime + 'static,
     {
         let cancel_task = CancellationTask::new(cancel_rx, effect_id.to_owned());
+        let delay = match interval {
+            Some(interval) => runtime.sleep(interval),
+            None => ready(()).boxed(),
+        };
 
         async move {
             select_biased! {
                 _ = cancel_task.wait_for_cancel().fuse() => {
                     Err(PubNubError::EffectCanceled)
                 },
-                _ = delay().fuse() => Ok(())
+                _ = delay.fuse() => Ok(())
             }
         }
         .boxed()
@@ -776,7 +780,6 @@ mod it_should {
 
     /// Construct test client with mocked transport.
     fn client(
-        with_subscribe_key: bool,
         transport: Option<MockTransport>,
     ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
         PubNubClientBuilder::with_transport(transport.unwrap_or(MockTransport {
@@ -784,7 +787,7 @@ mod it_should {
             request_handler: None,
         }))
         .with_keyset(Keyset {
-            subscribe_key: if with_subscribe_key { "demo" } else { "" },
+            subscribe_key: "demo",
             publish_key: None,
             secret_key: None,
         })
@@ -795,11 +798,19 @@ mod it_should {
 
     #[test]
     fn not_heartbeat_when_subscribe_key_missing() {
-        let client = client(false, None);
-        let request = client.heartbeat().channels(["test".into()]).build();
+        let client = PubNubClientBuilder::with_transport(MockTransport {
+            response: None,
+            request_handler: None,
+        })
+        .with_keyset(Keyset {
+            subscribe_key: "",
+            publish_key: None,
+            secret_key: None,
+        })
+        .with_user_id("user")
+        .build();
 
-        assert!(&client.config.subscribe_key.is_empty());
-        assert!(request.is_err())
+        assert!(matches!(client, Err(PubNubError::NoKey { .. })));
     }
 
     #[tokio::test]
@@ -848,7 +859,7 @@ mod it_should {
             })),
         };
 
-        let _ = client(true, Some(transport))
+        let _ = client(Some(transport))
             .heartbeat()
             .state(HashMap::<String, Vec<u8>>::from([
                 (
@@ -876,4 +887,331 @@ mod it_should {
             .execute()
             .await;
     }
+
+    #[tokio::test]
+    async fn where_now_for_user() {
+        let transport = MockTransport {
+            response: Some(TransportResponse {
+                status: 200,
+                body: Some(Vec::from(
+                    "{\"status\": 200, \"message\": \"OK\", \"payload\": {\"channels\": [\"channel_a\", \"channel_b\"]}, \"service\": \"Presence\"}",
+                )),
+                ..Default::default()
+            }),
+            request_handler: Some(Box::new(|req| {
+                assert_eq!(req.path, "/v2/presence/sub-key/demo/uuid/other_user");
+            })),
+        };
+
+        let result = client(Some(transport))
+            .where_now()
+            .user_id("other_user")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.channels, vec!["channel_a", "channel_b"]);
+    }
+
+    #[tokio::test]
+    async fn here_now_for_multiple_channels() {
+        let transport = MockTransport {
+            response: Some(TransportResponse {
+                status: 200,
+                body: Some(Vec::from(
+                    "{\"status\": 200, \"message\": \"OK\", \"payload\": {\"channels\": {\"channel_a\": {\"occupancy\": 1, \"uuids\": [{\"uuid\": \"user_a\", \"state\": {\"mood\": \"happy\"}}]}, \"channel_b\": {\"occupancy\": 0, \"uuids\": []}}, \"total_channels\": 2, \"total_occupancy\": 1}, \"service\": \"Presence\"}",
+                )),
+                ..Default::default()
+            }),
+            request_handler: Some(Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v2/presence/sub-key/demo/channel/channel_a,channel_b"
+                );
+                assert_eq!(req.query_parameters.get("state"), Some(&"1".to_string()));
+                assert!(!req.query_parameters.contains_key("disable_uuids"));
+            })),
+        };
+
+        let result = client(Some(transport))
+            .here_now()
+            .channels(["channel_a".into(), "channel_b".into()])
+            .include_state(true)
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.total_channels, 2);
+        assert_eq!(result.total_occupancy, 1);
+
+        let channel_a = result
+            .iter()
+            .find(|channel| channel.name == "channel_a")
+            .unwrap();
+        assert_eq!(channel_a.occupancy, 1);
+        assert_eq!(channel_a.occupants.first().unwrap().user_id, "user_a");
+        assert_eq!(
+            channel_a.occupants.first().unwrap().state,
+            Some(serde_json::json!({"mood": "happy"}))
+        );
+    }
+
+    #[tokio::test]
+    async fn set_presence_state_for_user() {
+        let transport = MockTransport {
+            response: Some(TransportResponse {
+                status: 200,
+                body: Some(Vec::from(
+                    "{\"status\": 200, \"message\": \"OK\", \"payload\": {\"is_admin\": false}, \"service\": \"Presence\"}",
+                )),
+                ..Default::default()
+            }),
+            request_handler: Some(Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v2/presence/sub-key/demo/channel/channel_a/uuid/other_user/data"
+                );
+                assert_eq!(
+                    req.query_parameters.get("state"),
+                    Some(&"{\"is_admin\":false}".to_string())
+                );
+            })),
+        };
+
+        let result = client(Some(transport))
+            .set_presence_state(HashMap::<String, bool>::from([(
+                String::from("is_admin"),
+                false,
+            )]))
+            .channels(["channel_a".into()])
+            .user_id("other_user")
+            .execute()
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn get_presence_state_for_user() {
+        let transport = MockTransport {
+            response: Some(TransportResponse {
+                status: 200,
+                body: Some(Vec::from(
+                    "{\"status\": 200, \"message\": \"OK\", \"payload\": {\"channels\": {\"channel_a\": {\"is_admin\": false}, \"channel_b\": {\"is_admin\": true}}}, \"service\": \"Presence\"}",
+                )),
+                ..Default::default()
+            }),
+            request_handler: Some(Box::new(|req| {
+                assert_eq!(
+                    req.path,
+                    "/v2/presence/sub-key/demo/channel/channel_a,channel_b/uuid/other_user"
+                );
+            })),
+        };
+
+        let result = client(Some(transport))
+            .get_presence_state()
+            .channels(["channel_a".into(), "channel_b".into()])
+            .user_id("other_user")
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert!(result.iter().any(|info| info.channel == "channel_b"
+            && info.state == serde_json::json!({"is_admin": true})));
+    }
+
+    /// Delayed calls which depend on runtime and retry policy.
+    #[cfg(feature = "std")]
+    mod delayed_calls {
+        use super::*;
+        use crate::core::event_engine::Effect;
+        use crate::dx::presence::event_engine::{PresenceEffect, PresenceEvent, PresenceInput};
+
+        /// Runtime with fake clock which only records requested delays.
+        #[derive(Clone, Default)]
+        struct FakeClockRuntime {
+            /// Delays (in microseconds) requested by caller.
+            sleeps: Arc<RwLock<Vec<u64>>>,
+
+            /// Whether requested delay should never elapse.
+            frozen: bool,
+        }
+
+        #[async_trait::async_trait]
+        impl Runtime for FakeClockRuntime {
+            fn spawn<R>(&self, future: impl futures::Future<Output = R> + Send + 'static)
+            where
+                R: Send + 'static,
+            {
+                tokio::spawn(future);
+            }
+
+            async fn sleep(self, delay: u64) {
+                self.sleep_microseconds(delay * 1_000_000).await
+            }
+
+            async fn sleep_microseconds(self, delay: u64) {
+                self.sleeps.write().push(delay);
+
+                if self.frozen {
+                    futures::future::pending::<()>().await
+                }
+            }
+        }
+
+        /// Transport which records number of delays requested before heartbeat.
+        fn heartbeat_recording_transport(
+            sleeps: Arc<RwLock<Vec<u64>>>,
+            heartbeats: Arc<RwLock<Vec<usize>>>,
+        ) -> MockTransport {
+            MockTransport {
+                response: None,
+                request_handler: Some(Box::new(move |req| {
+                    if req.path.ends_with("/heartbeat") {
+                        heartbeats.write().push(sleeps.read().len());
+                    }
+                })),
+            }
+        }
+
+        #[tokio::test]
+        async fn delay_heartbeat_with_retry_policy() {
+            let runtime = FakeClockRuntime::default();
+            let sleeps = runtime.sleeps.clone();
+            let heartbeats: Arc<RwLock<Vec<usize>>> = Default::default();
+            let client = client(Some(heartbeat_recording_transport(
+                sleeps.clone(),
+                heartbeats.clone(),
+            )));
+            let retry_policy = RequestRetryConfiguration::Linear {
+                delay: 2,
+                max_retry: 3,
+                excluded_endpoints: None,
+                max_elapsed: None,
+            };
+            let reason = PubNubError::Transport {
+                details: "test".into(),
+                status: Some(500),
+                response: Some(Box::new(transport_response(500))),
+            };
+            let (_cancel_tx, cancel_rx) = async_channel::bounded(1);
+
+            let result = PubNubClientInstance::delayed_heartbeat_call(
+                client,
+                PresenceParameters {
+                    channels: &Some(vec!["ch1".into()]),
+                    channel_groups: &None,
+                    attempt: 1,
+                    reason: Some(reason),
+                    effect_id: "id",
+                },
+                &retry_policy,
+                runtime,
+                cancel_rx,
+            )
+            .await;
+
+            // Linear policy delay with up to one second of random addition.
+            assert!(result.is_ok());
+            assert_eq!(sleeps.read().len(), 1);
+            assert!((2_000_000..3_000_000).contains(&sleeps.read()[0]));
+            assert_eq!(*heartbeats.read(), vec![1]);
+        }
+
+        #[tokio::test]
+        async fn not_heartbeat_when_delay_cancelled() {
+            let runtime = FakeClockRuntime {
+                frozen: true,
+                ..Default::default()
+            };
+            let heartbeats: Arc<RwLock<Vec<usize>>> = Default::default();
+            let client = client(Some(heartbeat_recording_transport(
+                runtime.sleeps.clone(),
+                heartbeats.clone(),
+            )));
+            let (cancel_tx, cancel_rx) = async_channel::bounded(1);
+
+            let call = PubNubClientInstance::delayed_heartbeat_call(
+                client,
+                PresenceParameters {
+                    channels: &Some(vec!["ch1".into()]),
+                    channel_groups: &None,
+                    attempt: 1,
+                    reason: Some(PubNubError::Transport {
+                        details: "test".into(),
+                        status: Some(500),
+                        response: Some(Box::new(transport_response(500))),
+                    }),
+                    effect_id: "id",
+                },
+                &RequestRetryConfiguration::Linear {
+                    delay: 2,
+                    max_retry: 3,
+                    excluded_endpoints: None,
+                    max_elapsed: None,
+                },
+                runtime,
+                cancel_rx,
+            );
+            cancel_tx.send("id".into()).await.unwrap();
+
+            assert!(matches!(call.await, Err(PubNubError::EffectCanceled)));
+            assert!(heartbeats.read().is_empty());
+        }
+
+        #[tokio::test]
+        async fn times_up_after_heartbeat_interval() {
+            let runtime = FakeClockRuntime::default();
+            let sleeps = runtime.sleeps.clone();
+            let (cancel_tx, cancel_rx) = async_channel::bounded(1);
+            let effect = PresenceEffect::Wait {
+                id: "id".into(),
+                cancelled: RwLock::new(false),
+                input: PresenceInput::new(&Some(vec!["ch1".into()]), &None),
+                cancellation_channel: cancel_tx,
+                executor: Arc::new(move |effect_id| {
+                    PubNubClientInstance::<MockTransport, DeserializerSerde>::wait_call(
+                        effect_id,
+                        Some(10),
+                        runtime.clone(),
+                        cancel_rx.clone(),
+                    )
+                }),
+            };
+
+            let events = effect.run().await;
+
+            assert!(matches!(events.as_slice(), [PresenceEvent::TimesUp]));
+            assert_eq!(*sleeps.read(), vec![10_000_000]);
+        }
+
+        #[tokio::test]
+        async fn not_times_up_when_wait_cancelled() {
+            let runtime = FakeClockRuntime {
+                frozen: true,
+                ..Default::default()
+            };
+            let (cancel_tx, cancel_rx) = async_channel::bounded(1);
+            let effect = PresenceEffect::Wait {
+                id: "id".into(),
+                cancelled: RwLock::new(false),
+                input: PresenceInput::new(&Some(vec!["ch1".into()]), &None),
+                cancellation_channel: cancel_tx,
+                executor: Arc::new(move |effect_id| {
+                    PubNubClientInstance::<MockTransport, DeserializerSerde>::wait_call(
+                        effect_id,
+                        Some(10),
+                        runtime.clone(),
+                        cancel_rx.clone(),
+                    )
+                }),
+            };
+
+            effect.cancel();
+
+            assert!(effect.run().await.is_empty());
+        }
+    }
 }
@@ -1267,10 +1267,10 @@ mod it_should {
             .try_into()
             .unwrap();
 
-        result
+        assert!(result
             .channels
             .iter()
-            .any(|channel| channel == "my_channel");
+            .any(|channel| channel == "my_channel"));
     }
 
     #[test]
@@ -1313,14 +1313,14 @@ mod it_should {
             .try_into()
             .unwrap();
 
-        result.iter().any(|channel| {
+        assert!(result.iter().any(|channel| {
             channel.channel == "channel-1"
                 && channel.state
                     == json!({
                         "key-1": "value-1",
                         "key-2": "value-2"
                     })
-        });
+        }));
     }
 
     #[test]
@@ -4,10 +4,18 @@
 
 use derive_builder::Builder;
 
+#[cfg(feature = "std")]
+use crate::core::RequestRetryConfiguration;
 use crate::{
-    core::Serialize,
+    core::{PubNubError, Serialize},
     dx::pubnub_client::PubNubClientInstance,
-    lib::{alloc::string::String, collections::HashMap},
+    lib::{
+        alloc::{
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
 };
 
 /// The [`PublishMessageBuilder`] is used to publish a message to a channel.
@@ -116,7 +124,10 @@ where
 /// [`PubNubClient`]: crate::dx::PubNubClient
 /// [`PubNub`]:https://www.pubnub.com/
 #[derive(Builder)]
-#[builder(pattern = "owned", build_fn(vis = "pub(super)"))]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(super)", validate = "Self::validate")
+)]
 #[cfg_attr(not(feature = "std"), builder(no_std))]
 pub struct PublishMessageViaChannel<T, M, D>
 where
@@ -147,11 +158,18 @@ where
     ///
     /// See more at [`PubNub replication rules`]
     ///
+    /// Messages which aren't replicated (`replicate(false)`) are also not
+    /// stored in history, so it can't be combined with `store(true)` or
+    /// `ttl`.
+    ///
     /// [`PubNub replication rules`]:https://www.pubnub.com/pricing/transaction-classification/
     #[builder(default = "true")]
     pub(super) replicate: bool,
 
     /// Set a per-message TTL time to live in Message Persistence.
+    ///
+    /// TTL is specified in hours and can't be used along with disabled
+    /// message storage (`store(false)`).
     #[builder(setter(strip_option), default = "None")]
     pub(super) ttl: Option<u32>,
 
@@ -159,6 +177,16 @@ where
     #[builder(setter(strip_option), default = "false")]
     pub(super) use_post: bool,
 
+    /// Switch that decide if the message body should be compressed.
+    ///
+    /// Compression is applied only when message is published using POST
+    /// method and serialized message size exceeds [`COMPRESSION_THRESHOLD`].
+    ///
+    /// [`COMPRESSION_THRESHOLD`]: crate::dx::publish::COMPRESSION_THRESHOLD
+    #[cfg(feature = "compression")]
+    #[builder(default = "false")]
+    pub(super) compress: bool,
+
     /// Object to send additional information about the message.
     #[builder(setter(strip_option), default = "None")]
     pub(super) meta: Option<HashMap<String, String>>,
@@ -168,6 +196,116 @@ where
     pub(super) space_id: Option<String>,
 
     /// Message type to publish.
+    ///
+    /// User-defined message type should be 3-50 characters long and contain
+    /// only alphanumeric characters, `_` and `-`.
     #[builder(setter(strip_option, into), default = "None")]
     pub(super) r#type: Option<String>,
+
+    /// Request retry policy override.
+    ///
+    /// Policy which should be used to retry this publish instead of the one
+    /// configured for the client.
+    #[cfg(feature = "std")]
+    #[builder(setter(strip_option), default = "None")]
+    pub(super) retry_configuration: Option<RequestRetryConfiguration>,
+}
+
+impl<T, M, D> PublishMessageViaChannelBuilder<T, M, D>
+where
+    M: Serialize,
+{
+    /// User-defined message type.
+    ///
+    /// Custom message type should be 3-50 characters long and contain only
+    /// alphanumeric characters, `_` and `-`.
+    pub fn custom_message_type<S>(self, message_type: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.r#type(message_type)
+    }
+
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that provided custom message type matches
+    /// `[A-Za-z0-9_-]{3,50}` pattern and storage options don't conflict with
+    /// each other.
+    fn validate(&self) -> Result<(), String> {
+        let store = self.store.flatten();
+        let ttl = self.ttl.flatten();
+
+        if let (Some(false), Some(true)) = (self.replicate, store) {
+            return Err(
+                "Message can't be stored when replication is disabled (`replicate(false)`)".into(),
+            );
+        }
+
+        // Not replicated messages also not stored.
+        let store = store.or(self.replicate.filter(|replicate| !replicate));
+
+        if let (Some(false), Some(ttl)) = (store, ttl) {
+            return Err(format!(
+                "Message TTL ({ttl} hours) can't be set when message storage is disabled"
+            ));
+        }
+
+        match self.r#type.as_ref().and_then(|r#type| r#type.as_ref()) {
+            Some(r#type) if !is_valid_custom_message_type(r#type) => Err(format!(
+                "Custom message type '{}' should be 3-50 characters long and contain only \
+                alphanumeric characters, '_' and '-'",
+                r#type
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T, M, D> PublishMessageViaChannelBuilder<T, M, D>
+where
+    M: Serialize,
+{
+    /// Build [`PublishMessageViaChannel`] from builder.
+    ///
+    /// Client configuration checked before user-provided data, so missing
+    /// publish key reported as [`PubNubError::NoKey`].
+    pub(super) fn request(self) -> Result<PublishMessageViaChannel<T, M, D>, PubNubError> {
+        validate_publish_configuration(&self.pub_nub_client)?;
+
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+/// Validate [`PubNubClient`] configuration.
+///
+/// Check whether if the [`PubNubConfig`] contains non-empty `publish_key`
+/// required for publish and signal endpoints usage or not.
+///
+/// [`PubNubClient`]: crate::PubNubClient
+/// [`PubNubConfig`]: crate::dx::pubnub_client::PubNubConfig
+pub(in crate::dx) fn validate_publish_configuration<T, D>(
+    client: &Option<PubNubClientInstance<T, D>>,
+) -> Result<(), PubNubError> {
+    let Some(client) = client else {
+        return Ok(());
+    };
+
+    client
+        .config
+        .publish_key
+        .as_ref()
+        .filter(|publish_key| !publish_key.is_empty())
+        .map(|_| ())
+        .ok_or(PubNubError::NoKey {
+            details: "Incomplete PubNub client configuration: 'publish_key' is empty.".into(),
+        })
+}
+
+/// Check whether custom message type matches `[A-Za-z0-9_-]{3,50}` pattern.
+fn is_valid_custom_message_type(message_type: &str) -> bool {
+    (3..=50).contains(&message_type.len())
+        && message_type
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
 }
@@ -45,6 +45,43 @@ use crate::{
 
 use base64::{engine::general_purpose, Engine as _};
 
+#[cfg(feature = "std")]
+use crate::core::RequestRetryConfiguration;
+#[cfg(feature = "compression")]
+use crate::{
+    core::utils::headers::{CONTENT_ENCODING, GZIP},
+    lib::alloc::vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Minimum size of serialized message (in bytes) for which compression will
+/// be applied.
+#[cfg(feature = "compression")]
+pub const COMPRESSION_THRESHOLD: usize = 1024;
+
+/// In-flight publish request marker.
+///
+/// Publish request counted as pending while marker is alive, so client
+/// shutdown can wait for its completion.
+#[cfg(feature = "std")]
+struct PendingPublish(Arc<AtomicUsize>);
+
+#[cfg(feature = "std")]
+impl PendingPublish {
+    fn new(counter: &Arc<AtomicUsize>) -> Self {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Self(counter.clone())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for PendingPublish {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 impl<T, D> PubNubClientInstance<T, D>
 where
     D: Deserializer,
@@ -113,9 +150,7 @@ where
     fn prepare_context_with_request(
         self,
     ) -> Result<PublishMessageContext<T, D, TransportRequest>, PubNubError> {
-        let instance = self
-            .build()
-            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+        let instance = self.request()?;
 
         PublishMessageContext::from(instance)
             .map_data(|client, params| {
@@ -125,6 +160,8 @@ where
                 Ok(PublishMessageContext {
                     client: ctx.client,
                     data: ctx.data?,
+                    #[cfg(feature = "std")]
+                    retry_configuration: ctx.retry_configuration,
                 })
             })
     }
@@ -171,13 +208,19 @@ where
         self.prepare_context_with_request()?
             .map(|some| async move {
                 let deserializer = some.client.deserializer.clone();
+                #[cfg(feature = "std")]
+                let _pending = PendingPublish::new(&some.client.pending_publishes);
 
                 some.data
                     .send::<PublishResponseBody, _, _, _>(
                         &some.client.transport,
                         deserializer,
                         #[cfg(feature = "std")]
-                        &some.client.config.transport.retry_configuration,
+                        some.retry_configuration
+                            .as_ref()
+                            .unwrap_or(&some.client.config.transport.retry_configuration),
+                        #[cfg(feature = "std")]
+                        some.client.config.transport.retry_budget.as_ref(),
                         #[cfg(feature = "std")]
                         &some.client.runtime,
                     )
@@ -229,6 +272,8 @@ where
             .map_data(|client, request| {
                 let client = client.clone();
                 let deserializer = client.deserializer.clone();
+                #[cfg(feature = "std")]
+                let _pending = PendingPublish::new(&client.pending_publishes);
                 request
                     .send_blocking::<PublishResponseBody, _, _, _>(&client.transport, deserializer)
             })
@@ -243,7 +288,9 @@ where
     fn prepare_publish_query_params(&self) -> HashMap<String, String> {
         let mut query_params: HashMap<String, String> = HashMap::new();
 
+        // Not replicated messages also shouldn't be stored.
         self.store
+            .or(self.replicate.not().then_some(false))
             .and_then(|s| query_params.insert("store".to_string(), bool_to_numeric(s)));
 
         self.ttl
@@ -281,17 +328,30 @@ where
         let pub_key = config
             .publish_key
             .as_ref()
-            .ok_or_else(|| PubNubError::general_api_error("Publish key is not set", None, None))?;
+            .ok_or_else(|| PubNubError::NoKey {
+                details: "Publish key is not set".into(),
+            })?;
         let sub_key = &config.subscribe_key;
 
         let mut m_vec = self.message.serialize()?;
+
+        // Message shouldn't be sent as plain text if encryption failed.
         if let Some(cryptor) = cryptor {
-            if let Ok(encrypted) = cryptor.encrypt(m_vec.to_vec()) {
-                m_vec = format!("\"{}\"", general_purpose::STANDARD.encode(encrypted)).into_bytes();
-            }
+            let encrypted = cryptor.encrypt(m_vec)?;
+            m_vec = format!("\"{}\"", general_purpose::STANDARD.encode(encrypted)).into_bytes();
         }
 
         if self.use_post {
+            #[cfg_attr(not(feature = "compression"), allow(unused_mut))]
+            let mut headers: HashMap<String, String> =
+                [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into();
+
+            #[cfg(feature = "compression")]
+            if self.compress && m_vec.len() > COMPRESSION_THRESHOLD {
+                m_vec = gzip(&m_vec)?;
+                headers.insert(CONTENT_ENCODING.to_string(), GZIP.to_string());
+            }
+
             Ok(TransportRequest {
                 path: format!(
                     "/publish/{pub_key}/{sub_key}/0/{}/0",
@@ -300,7 +360,7 @@ where
                 method: TransportMethod::Post,
                 query_parameters: query_params,
                 body: Some(m_vec),
-                headers: [(CONTENT_TYPE.to_string(), APPLICATION_JSON.to_string())].into(),
+                headers,
                 #[cfg(feature = "std")]
                 timeout: config.transport.request_timeout,
             })
@@ -330,6 +390,8 @@ where
 struct PublishMessageContext<T, D, X> {
     client: PubNubClientInstance<T, D>,
     data: X,
+    #[cfg(feature = "std")]
+    retry_configuration: Option<RequestRetryConfiguration>,
 }
 
 impl<T, D, M> From<PublishMessageViaChannel<T, M, D>>
@@ -350,9 +412,13 @@ where
                 seqn: value.seqn,
                 replicate: value.replicate,
                 use_post: value.use_post,
+                #[cfg(feature = "compression")]
+                compress: value.compress,
                 space_id: value.space_id,
                 r#type: value.r#type,
             },
+            #[cfg(feature = "std")]
+            retry_configuration: value.retry_configuration,
         }
     }
 }
@@ -365,7 +431,12 @@ impl<T, D, X> PublishMessageContext<T, D, X> {
         let client = self.client;
         let data = f(client.clone(), self.data);
 
-        PublishMessageContext { client, data }
+        PublishMessageContext {
+            client,
+            data,
+            #[cfg(feature = "std")]
+            retry_configuration: self.retry_configuration,
+        }
     }
 
     fn map<F, Y>(self, f: F) -> Y
@@ -384,6 +455,8 @@ struct PublishMessageParams<M> {
     replicate: bool,
     ttl: Option<u32>,
     use_post: bool,
+    #[cfg(feature = "compression")]
+    compress: bool,
     meta: Option<HashMap<String, String>>,
     space_id: Option<String>,
     r#type: Option<String>,
@@ -393,6 +466,20 @@ fn bool_to_numeric(value: bool) -> String {
     if value { "1" } else { "0" }.to_string()
 }
 
+/// Compress provided data using gzip.
+#[cfg(feature = "compression")]
+fn gzip(data: &[u8]) -> Result<Vec<u8>, PubNubError> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(data)
+        .and_then(|_| encoder.finish())
+        .map_err(|err| PubNubError::Serialization {
+            details: err.to_string(),
+        })
+}
+
 fn serialize_meta(meta: &HashMap<String, String>) -> String {
     let mut result = String::new();
     result.push('{');
@@ -413,7 +500,10 @@ mod should {
     use crate::{
         core::TransportResponse,
         dx::pubnub_client::PubNubClientInstance,
-        lib::{alloc::vec, collections::HashMap},
+        lib::{
+            alloc::{vec, vec::Vec},
+            collections::HashMap,
+        },
         transport::middleware::PubNubMiddleware,
         Keyset, PubNubClientBuilder,
     };
@@ -439,11 +529,11 @@ mod should {
 
         PubNubClientBuilder::with_transport(MockTransport)
             .with_keyset(Keyset {
-                publish_key: Some(""),
-                subscribe_key: "",
+                publish_key: Some("pub_key"),
+                subscribe_key: "demo",
                 secret_key: None,
             })
-            .with_user_id("")
+            .with_user_id("user_id")
             .build()
             .unwrap()
     }
@@ -475,6 +565,56 @@ mod should {
         assert!(result.is_ok());
     }
 
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn use_retry_configuration_override() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct MockTransport(Arc<AtomicUsize>);
+
+        #[async_trait::async_trait]
+        impl Transport for MockTransport {
+            async fn send(
+                &self,
+                _request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(TransportResponse {
+                    status: 500,
+                    ..Default::default()
+                })
+            }
+        }
+
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let client = PubNubClientBuilder::with_transport(MockTransport(attempts.clone()))
+            .with_keyset(Keyset {
+                publish_key: Some("pub_key"),
+                subscribe_key: "demo",
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .build()
+            .unwrap();
+
+        let _ = client
+            .publish_message("message")
+            .channel("channel")
+            .execute()
+            .await;
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        let _ = client
+            .publish_message("message")
+            .channel("channel")
+            .retry_configuration(RequestRetryConfiguration::Fixed { max_retry: 2 })
+            .execute()
+            .await;
+        // Failed request has been retried twice according to the override.
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
     #[test]
     fn verify_all_query_parameters() {
         let client = client();
@@ -482,7 +622,6 @@ mod should {
         let result = client
             .publish_message("message")
             .channel("chan")
-            .replicate(false)
             .ttl(50)
             .store(true)
             .space_id("space_id")
@@ -493,7 +632,6 @@ mod should {
 
         assert_eq!(
             HashMap::<String, String>::from([
-                ("norep".to_string(), "true".to_string()),
                 ("store".to_string(), "1".to_string()),
                 ("space-id".to_string(), "space_id".to_string()),
                 ("type".to_string(), "message_type".to_string()),
@@ -505,6 +643,154 @@ mod should {
         );
     }
 
+    #[test_case(None ; "default store")]
+    #[test_case(Some(true) ; "enabled store")]
+    fn add_ttl_to_query(store: Option<bool>) {
+        let client = client();
+        let mut builder = client.publish_message("message").channel("chan").ttl(24);
+        if let Some(store) = store {
+            builder = builder.store(store);
+        }
+
+        let result = builder.prepare_context_with_request().unwrap();
+
+        assert_eq!(
+            result.data.query_parameters.get("ttl"),
+            Some(&"24".to_string())
+        );
+    }
+
+    #[test]
+    fn return_error_for_ttl_with_disabled_store() {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .store(false)
+            .ttl(24)
+            .prepare_context_with_request();
+
+        assert!(matches!(result, Err(PubNubError::API { .. })));
+    }
+
+    #[test]
+    fn add_norep_and_disable_store_when_not_replicated() {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .replicate(false)
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(
+            result.data.query_parameters.get("norep"),
+            Some(&"true".to_string())
+        );
+        assert_eq!(
+            result.data.query_parameters.get("store"),
+            Some(&"0".to_string())
+        );
+    }
+
+    #[test]
+    fn not_add_norep_when_replicated() {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert!(!result.data.query_parameters.contains_key("norep"));
+        assert!(!result.data.query_parameters.contains_key("store"));
+    }
+
+    #[test]
+    fn return_error_for_stored_not_replicated_message() {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .replicate(false)
+            .store(true)
+            .prepare_context_with_request();
+
+        assert!(matches!(result, Err(PubNubError::API { .. })));
+    }
+
+    #[test]
+    fn return_error_for_ttl_with_not_replicated_message() {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .replicate(false)
+            .ttl(24)
+            .prepare_context_with_request();
+
+        assert!(matches!(result, Err(PubNubError::API { .. })));
+    }
+
+    #[test]
+    fn add_custom_message_type_to_query() {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .space_id("space id")
+            .custom_message_type("chat-message_v2")
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(
+            result.data.query_parameters.get("type"),
+            Some(&"chat-message_v2".to_string())
+        );
+        assert_eq!(
+            result.data.query_parameters.get("space-id"),
+            Some(&"space id".to_string())
+        );
+    }
+
+    #[test_case("ab" ; "too short")]
+    #[test_case(&"a".repeat(51) ; "too long")]
+    #[test_case("chat message" ; "with space")]
+    #[test_case("chat.message" ; "with dot")]
+    #[test_case("чат" ; "non ascii")]
+    fn return_error_for_invalid_custom_message_type(message_type: &str) {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .custom_message_type(message_type)
+            .prepare_context_with_request();
+
+        assert!(matches!(result, Err(PubNubError::API { .. })));
+    }
+
+    #[test_case("abc" ; "min length")]
+    #[test_case(&"a".repeat(50) ; "max length")]
+    #[test_case("Chat_Message-01" ; "all allowed characters")]
+    fn accept_valid_custom_message_type(message_type: &str) {
+        let client = client();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .custom_message_type(message_type)
+            .prepare_context_with_request();
+
+        assert!(result.is_ok());
+    }
+
     #[test]
     fn verify_seqn_is_incrementing() {
         let client = client();
@@ -517,33 +803,88 @@ mod should {
         assert_eq!(vec![1, 2], received_sequence_numbers);
     }
 
-    // TODO: REMOVE THIS TEST
-    // #[tokio::test]
-    // async fn return_err_if_publish_key_is_not_provided() {
-    //     let client = {
-    //         let default_client = client();
-    //         let ref_client = default_client.inner.as_ref();
-    //         // i.config
-    //         // let ref_client = Arc::try_unwrap(default_client.inner).unwrap();
-    //
-    //         PubNubClientInstance {
-    //             inner: Arc::new(PubNubClientRef {
-    //                 config: PubNubConfig {
-    //                     publish_key: None,
-    //                     ..default_client.config.clone()
-    //                 },
-    //                 transport: default_client.clone().transport,
-    //             }),
-    //         }
-    //     };
-    //
-    //     assert!(client
-    //         .publish_message("message")
-    //         .channel("chan")
-    //         .execute()
-    //         .await
-    //         .is_err());
-    // }
+    #[test]
+    fn send_incrementing_seqn_with_requests() {
+        let client = client();
+
+        let sequence_numbers = (0..5)
+            .map(|_| {
+                client
+                    .publish_message("message")
+                    .channel("chan")
+                    .prepare_context_with_request()
+                    .unwrap()
+                    .data
+                    .query_parameters
+                    .get("seqn")
+                    .unwrap()
+                    .parse::<u16>()
+                    .unwrap()
+            })
+            .collect::<Vec<u16>>();
+
+        assert_eq!(vec![1, 2, 3, 4, 5], sequence_numbers);
+    }
+
+    #[test]
+    fn wrap_seqn_after_max_value() {
+        let client = client();
+        *client.next_seqn.lock() = u16::MAX;
+
+        assert_eq!(client.publish_message("message").seqn, u16::MAX);
+        assert_eq!(client.publish_message("message").seqn, 1);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn assign_distinct_seqn_for_concurrent_publishes() {
+        let client = client();
+
+        let handles = (0..8)
+            .map(|_| {
+                let client = client.clone();
+                std::thread::spawn(move || {
+                    (0..100)
+                        .map(|_| client.publish_message("message").seqn)
+                        .collect::<Vec<u16>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut sequence_numbers = vec![];
+        for handle in handles {
+            let thread_sequence_numbers = handle.join().unwrap();
+            assert!(thread_sequence_numbers.windows(2).all(|w| w[0] < w[1]));
+            sequence_numbers.extend(thread_sequence_numbers);
+        }
+
+        sequence_numbers.sort();
+        sequence_numbers.dedup();
+        assert_eq!(sequence_numbers, (1..=800).collect::<Vec<u16>>());
+    }
+
+    #[test_case(None ; "without publish key")]
+    #[test_case(Some("") ; "with empty publish key")]
+    #[tokio::test]
+    async fn return_err_if_publish_key_is_not_provided(publish_key: Option<&str>) {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                publish_key,
+                subscribe_key: "sub_key",
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .build()
+            .unwrap();
+
+        let result = client
+            .publish_message("message")
+            .channel("chan")
+            .execute()
+            .await;
+
+        assert!(matches!(result, Err(PubNubError::NoKey { .. })));
+    }
 
     #[test]
     fn test_send_string_when_get() {
@@ -559,7 +900,7 @@ mod should {
 
         assert_eq!(
             format!(
-                "/publish///0/{}/0/{}",
+                "/publish/pub_key/demo/0/{}/0/{}",
                 channel,
                 url_encode_extended(
                     format!("\"{}\"", message).as_bytes(),
@@ -584,7 +925,7 @@ mod should {
 
         assert_eq!(
             format!(
-                "/publish///0/{}/0/{}",
+                "/publish/pub_key/demo/0/{}/0/{}",
                 channel,
                 url_encode_extended(
                     "{\"a\":\"b\"}".as_bytes(),
@@ -609,7 +950,10 @@ mod should {
             .unwrap();
 
         let result_data = result.data;
-        assert_eq!(format!("/publish///0/{}/0", channel), result_data.path);
+        assert_eq!(
+            format!("/publish/pub_key/demo/0/{}/0", channel),
+            result_data.path
+        );
         assert_eq!(
             format!("\"{}\"", message),
             String::from_utf8(result_data.body.unwrap()).unwrap()
@@ -630,7 +974,7 @@ mod should {
 
         assert_eq!(
             format!(
-                "/publish///0/{}/0/{}",
+                "/publish/pub_key/demo/0/{}/0/{}",
                 channel,
                 url_encode_extended(
                     "{\"number\":7}".as_bytes(),
@@ -653,7 +997,142 @@ mod should {
             .use_post(true)
             .prepare_context_with_request()
             .unwrap();
-        assert_eq!(format!("/publish///0/{}/0", channel), result.data.path);
+        assert_eq!(
+            format!("/publish/pub_key/demo/0/{}/0", channel),
+            result.data.path
+        );
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn compress_large_message_when_post() {
+        use std::io::Read;
+
+        let client = client();
+        let message = "a".repeat(COMPRESSION_THRESHOLD * 2);
+
+        let result = client
+            .publish_message(message.clone())
+            .channel("ch")
+            .use_post(true)
+            .compress(true)
+            .prepare_context_with_request()
+            .unwrap();
+
+        let body = result.data.body.unwrap();
+        assert_eq!(&body[..2], &[0x1f, 0x8b]);
+        assert!(body.len() < message.len());
+        assert_eq!(
+            result.data.headers.get(CONTENT_ENCODING),
+            Some(&GZIP.to_string())
+        );
+
+        let mut decompressed = String::new();
+        flate2::read::GzDecoder::new(body.as_slice())
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, format!("\"{}\"", message));
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn not_compress_small_message_when_post() {
+        let client = client();
+        let message = "this is message";
+
+        let result = client
+            .publish_message(message)
+            .channel("ch")
+            .use_post(true)
+            .compress(true)
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(result.data.body.unwrap()).unwrap(),
+            format!("\"{}\"", message)
+        );
+        assert!(!result.data.headers.contains_key(CONTENT_ENCODING));
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn not_compress_message_when_get() {
+        let client = client();
+        let message = "a".repeat(COMPRESSION_THRESHOLD * 2);
+
+        let result = client
+            .publish_message(message)
+            .channel("ch")
+            .compress(true)
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert!(result.data.body.is_none());
+        assert!(!result.data.headers.contains_key(CONTENT_ENCODING));
+    }
+
+    #[cfg(feature = "crypto")]
+    fn client_with_cryptor(
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                publish_key: Some("pub_key"),
+                subscribe_key: "demo",
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .with_cryptor(
+                crate::providers::crypto::CryptoModule::new_aes_cbc_module("enigma", true).unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn send_encrypted_message_when_post() {
+        let client = client_with_cryptor();
+        let message = "this is message";
+
+        let result = client
+            .publish_message(message)
+            .channel("ch")
+            .use_post(true)
+            .meta(HashMap::from([("k".to_string(), "v".to_string())]))
+            .prepare_context_with_request()
+            .unwrap();
+
+        let body = String::from_utf8(result.data.body.unwrap()).unwrap();
+        assert!(!body.contains(message));
+        let encrypted = general_purpose::STANDARD
+            .decode(body.trim_matches('"'))
+            .expect("Encrypted message should be base64 encoded");
+        let decrypted = client.cryptor.as_ref().unwrap().decrypt(encrypted).unwrap();
+        assert_eq!(decrypted, format!("\"{}\"", message).as_bytes());
+        assert_eq!(
+            result.data.query_parameters.get("meta"),
+            Some(&"{\"k\":\"v\"}".to_string())
+        );
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn send_encrypted_message_when_get() {
+        let client = client_with_cryptor();
+        let message = "this is message";
+
+        let result = client
+            .publish_message(message)
+            .channel("ch")
+            .prepare_context_with_request()
+            .unwrap();
+
+        assert!(result
+            .data
+            .path
+            .starts_with("/publish/pub_key/demo/0/ch/0/"));
+        assert!(!result.data.path.contains("this%20is%20message"));
     }
 
     #[test_case(HashMap::from([("k".to_string(), "v".to_string())]), "{\"k\":\"v\"}" ; "hash map with elements")]
@@ -687,8 +1166,8 @@ mod should {
 
         let client = PubNubClientBuilder::with_transport(MockTransport)
             .with_keyset(Keyset {
-                publish_key: Some(""),
-                subscribe_key: "",
+                publish_key: Some("pub_key"),
+                subscribe_key: "demo",
                 secret_key: None,
             })
             .with_user_id("user_id")
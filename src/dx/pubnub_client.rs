@@ -18,9 +18,13 @@ use uuid::Uuid;
     feature = "tokio"
 ))]
 use crate::providers::futures_tokio::RuntimeTokio;
+#[cfg(all(feature = "subscribe", feature = "std"))]
+use crate::subscribe::SubscriptionCursorStore;
 #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
 use crate::subscribe::{EventDispatcher, SubscriptionCursor, SubscriptionManager};
 
+#[cfg(feature = "subscribe")]
+use crate::core::DataStreamOverflowPolicy;
 #[cfg(feature = "presence")]
 use crate::lib::alloc::vec::Vec;
 #[cfg(all(feature = "presence", feature = "std"))]
@@ -35,7 +39,14 @@ use crate::transport::TransportReqwest;
 
 // TODO: Retry policy would be implemented for `no_std` event engine
 #[cfg(feature = "std")]
-use crate::core::{runtime::RuntimeSupport, RequestRetryConfiguration};
+use crate::core::{retry_policy::RetryBudget, runtime::RuntimeSupport, RequestRetryConfiguration};
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+#[cfg(all(feature = "publish", feature = "std"))]
+use crate::core::Runtime;
+#[cfg(all(feature = "publish", feature = "std"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use crate::{
     core::{CryptoProvider, PubNubEntity, PubNubError},
@@ -49,6 +60,7 @@ use crate::{
         collections::HashMap,
         core::{
             cmp::max,
+            num::NonZeroUsize,
             ops::{Deref, DerefMut},
         },
     },
@@ -233,6 +245,9 @@ pub type PubNubClient = PubNubGenericClient<TransportReqwest, DeserializerSerde>
 /// This struct contains the actual client state.
 /// It shouldn't be used directly. Use [`PubNubGenericClient`] or
 /// [`PubNubClient`] instead.
+///
+/// Clones are cheap and share the same state, so subscription made with one
+/// clone delivers real-time updates to listeners of the others.
 #[derive(Debug)]
 pub struct PubNubClientInstance<T, D> {
     pub(crate) inner: Arc<PubNubClientRef<T, D>>,
@@ -267,7 +282,7 @@ impl<T, D> Clone for PubNubClientInstance<T, D> {
             inner: Arc::clone(&self.inner),
 
             #[cfg(all(feature = "subscribe", feature = "std"))]
-            cursor: Default::default(),
+            cursor: Arc::clone(&self.cursor),
 
             #[cfg(all(feature = "subscribe", feature = "std"))]
             event_dispatcher: Arc::clone(&self.event_dispatcher),
@@ -286,7 +301,7 @@ impl<T, D> Clone for PubNubClientInstance<T, D> {
 #[builder(
     pattern = "owned",
     name = "PubNubClientConfigBuilder",
-    build_fn(private, name = "build_internal"),
+    build_fn(private, name = "build_internal", validate = "Self::validate"),
     setter(prefix = "with"),
     no_std
 )]
@@ -373,6 +388,17 @@ pub struct PubNubClientRef<T, D> {
     #[builder(setter(custom), field(vis = "pub(crate)"))]
     pub(crate) runtime: RuntimeSupport,
 
+    /// Subscription time cursor store.
+    ///
+    /// Store used to restore subscription after client restart.
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[builder(
+        setter(custom, strip_option),
+        field(vis = "pub(crate)"),
+        default = "None"
+    )]
+    pub(crate) cursor_store: Option<Arc<dyn SubscriptionCursorStore>>,
+
     /// Subscription module configuration
     ///
     /// > **Important**: Use `.subscription_manager()` to access it instead of
@@ -391,6 +417,11 @@ pub struct PubNubClientRef<T, D> {
     #[builder(setter(skip), field(vis = "pub(crate)"))]
     pub(crate) presence: Arc<RwLock<Option<PresenceManager>>>,
 
+    /// Number of publish requests which are currently in flight.
+    #[cfg(all(feature = "publish", feature = "std"))]
+    #[builder(setter(skip), field(vis = "pub(crate)"))]
+    pub(crate) pending_publishes: Arc<AtomicUsize>,
+
     /// Created entities.
     ///
     /// Map of entities which has been created to access [`PubNub API`].
@@ -905,9 +936,122 @@ where
             *manager_slot = None;
         }
     }
+
+    /// Gracefully shut down the client.
+    ///
+    /// Unsubscribes from all channels and groups, announces `leave` for them,
+    /// waits for in-flight publish requests (not longer than configured
+    /// request timeout) and terminates subscription and presence managers.
+    ///
+    /// Managers terminated even if `leave` request failed, and its error
+    /// returned afterwards.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{Keyset, PubNubClientBuilder};
+    ///
+    /// #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #         PubNubClientBuilder::with_reqwest_transport()
+    /// #             .with_keyset(Keyset {
+    /// #                 subscribe_key: "demo",
+    /// #                 publish_key: Some("demo"),
+    /// #                 secret_key: None
+    /// #             })
+    /// #             .with_user_id("uuid")
+    /// #             .build()?;
+    /// // ...
+    /// pubnub.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub async fn shutdown(self) -> Result<(), PubNubError> {
+        #[cfg(all(feature = "subscribe", feature = "presence"))]
+        let (channels, channel_groups) = self
+            .subscription_manager(false)
+            .read()
+            .as_ref()
+            .map(|manager| {
+                let input = manager.current_input();
+                (
+                    Self::presence_filtered_entries(input.channels()),
+                    Self::presence_filtered_entries(input.channel_groups()),
+                )
+            })
+            .unwrap_or_default();
+
+        // Stop presence event engine first, so it won't announce `leave` in
+        // parallel with explicit request below.
+        #[cfg(feature = "presence")]
+        if let Some(manager) = self.presence_manager(false).write().take() {
+            manager.terminate();
+        }
+
+        #[cfg(feature = "subscribe")]
+        self.unsubscribe_all();
+
+        #[cfg_attr(
+            not(all(feature = "subscribe", feature = "presence")),
+            allow(unused_mut)
+        )]
+        let mut result = Ok(());
+
+        #[cfg(all(feature = "subscribe", feature = "presence"))]
+        {
+            let channels = channels.filter(|channels| !channels.is_empty());
+            let channel_groups = channel_groups.filter(|groups| !groups.is_empty());
+
+            if (channels.is_some() || channel_groups.is_some())
+                && !self.config.presence.suppress_leave_events
+            {
+                let mut request = self.leave();
+                if let Some(channels) = channels {
+                    request = request.channels(channels);
+                }
+                if let Some(channel_groups) = channel_groups {
+                    request = request.channel_groups(channel_groups);
+                }
+                result = request.execute().await.map(|_| ());
+            }
+        }
+
+        #[cfg(feature = "publish")]
+        {
+            const CHECK_INTERVAL: u64 = 10_000;
+            let mut remaining = self.config.transport.request_timeout * 1_000_000;
+
+            while self.pending_publishes.load(Ordering::SeqCst) > 0 && remaining > 0 {
+                self.runtime
+                    .clone()
+                    .sleep_microseconds(CHECK_INTERVAL)
+                    .await;
+                remaining = remaining.saturating_sub(CHECK_INTERVAL);
+            }
+        }
+
+        #[cfg(any(feature = "subscribe", feature = "presence"))]
+        self.terminate();
+
+        result
+    }
 }
 
 impl<T, D> PubNubClientConfigBuilder<T, D> {
+    /// Validate client configuration before build.
+    fn validate(&self) -> Result<(), String> {
+        let empty_user_id = self
+            .config
+            .as_ref()
+            .map(|config| config.user_id.trim().is_empty())
+            .unwrap_or_default();
+
+        (!empty_user_id)
+            .then_some(())
+            .ok_or_else(|| "User ID can't be empty".into())
+    }
+
     /// Set client authentication key.
     ///
     /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
@@ -924,6 +1068,23 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
         self
     }
 
+    /// Requests and responses logging.
+    ///
+    /// When enabled, each outgoing request's method and path and each
+    /// response's status and latency will be logged at `debug` level. Values
+    /// of `auth` and `signature` query parameters are redacted.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    pub fn with_request_logging(mut self, enabled: bool) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.log_requests = enabled;
+        }
+
+        self
+    }
+
     /// `user_id` presence heartbeat.
     ///
     /// Used to set the presence timeout period. It overrides the default value
@@ -979,6 +1140,51 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
         self
     }
 
+    /// Maximum number of updates delivered by single receive call.
+    ///
+    /// When the service returns more updates than specified, the remaining
+    /// updates will be fetched with the next subscription loop iteration.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "subscribe")]
+    pub fn with_max_messages_per_receive(mut self, max_messages: NonZeroUsize) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.subscribe.max_messages_per_receive = Some(max_messages);
+        }
+        self
+    }
+
+    /// Maximum number of updates queued by each listener data stream.
+    ///
+    /// Queue size is used by status and real-time updates streams created
+    /// for client, subscriptions and subscription sets.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "subscribe")]
+    pub fn with_stream_queue_size(mut self, size: NonZeroUsize) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.subscribe.stream_queue_size = Some(size);
+        }
+        self
+    }
+
+    /// Policy which is used when listener data stream queue is full.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "subscribe")]
+    pub fn with_stream_overflow_policy(mut self, policy: DataStreamOverflowPolicy) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.subscribe.stream_overflow_policy = policy;
+        }
+        self
+    }
+
     /// Requests automatic retry configuration.
     ///
     /// The retry configuration regulates the frequency of request retry
@@ -999,6 +1205,44 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
         self
     }
 
+    /// Client-wide retry budget.
+    ///
+    /// The retry budget limits the total number of automatic retry attempts
+    /// made by the client for regular (non-subscribe) requests. When the
+    /// budget is exhausted, failed requests fail fast with
+    /// [`PubNubError::Transport`] until the budget refills.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "std")]
+    pub fn with_retry_budget(mut self, retry_budget: RetryBudget) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.transport.retry_budget = Some(retry_budget);
+        }
+
+        self
+    }
+
+    /// Non-subscribe requests timeout.
+    ///
+    /// Timeout after which any non-subscribe request will be cancelled.
+    /// Timeout is rounded up to whole seconds, so sub-second value won't
+    /// fall back to the default timeout.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(feature = "std")]
+    pub fn with_transport_timeout(mut self, timeout: Duration) -> Self {
+        if let Some(configuration) = self.config.as_mut() {
+            configuration.transport.request_timeout =
+                timeout.as_secs() + u64::from(timeout.subsec_nanos() > 0);
+        }
+
+        self
+    }
+
     /// Data encryption / decryption
     ///
     /// Crypto module used by client when publish messages / signals and receive
@@ -1016,6 +1260,25 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
         self
     }
 
+    /// Subscription time cursor store.
+    ///
+    /// Store is used to restore subscription from the last received time
+    /// cursor (for example, after application restart) and to save a new
+    /// cursor each time when real-time updates received.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use to set the
+    /// configuration for the client. This is a part of the
+    /// [`PubNubClientConfigBuilder`].
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    pub fn with_cursor_store<S>(mut self, store: S) -> Self
+    where
+        S: SubscriptionCursorStore + 'static,
+    {
+        self.cursor_store = Some(Some(Arc::new(store)));
+
+        self
+    }
+
     /// Real-time events filtering expression.
     ///
     /// # Arguments
@@ -1046,6 +1309,12 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
                 details: err.to_string(),
             })
             .and_then(|pre_build| {
+                if pre_build.config.subscribe_key.trim().is_empty() {
+                    return Err(PubNubError::NoKey {
+                        details: "Subscribe key can't be empty".into(),
+                    });
+                }
+
                 let token = Arc::new(RwLock::new(String::new()));
                 #[cfg(all(feature = "subscribe", feature = "std"))]
                 let subscription = Arc::new(RwLock::new(None));
@@ -1068,6 +1337,7 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
                         user_id: pre_build.config.user_id.clone(),
                         transport: pre_build.transport,
                         auth_token: token.clone(),
+                        log_requests: pre_build.config.log_requests,
                     },
                     deserializer: pre_build.deserializer,
                     instance_id: pre_build.instance_id,
@@ -1085,16 +1355,26 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
                     #[cfg(feature = "std")]
                     runtime: pre_build.runtime,
 
+                    #[cfg(all(feature = "subscribe", feature = "std"))]
+                    cursor_store: pre_build.cursor_store,
+
                     #[cfg(all(feature = "subscribe", feature = "std"))]
                     subscription: subscription.clone(),
 
                     #[cfg(all(feature = "presence", feature = "std"))]
                     presence: presence.clone(),
 
+                    #[cfg(all(feature = "publish", feature = "std"))]
+                    pending_publishes: Default::default(),
+
                     entities: RwLock::new(HashMap::new()),
                 })
             })
             .map(|client| {
+                #[cfg(all(feature = "subscribe", feature = "std"))]
+                let event_dispatcher =
+                    Arc::new(EventDispatcher::with_configuration(&client.config.subscribe));
+
                 PubNubClientInstance {
                     inner: Arc::new(client),
 
@@ -1102,7 +1382,7 @@ impl<T, D> PubNubClientConfigBuilder<T, D> {
                     cursor: Default::default(),
 
                     #[cfg(all(feature = "subscribe", feature = "std"))]
-                    event_dispatcher: Default::default(),
+                    event_dispatcher,
                 }
             })
     }
@@ -1129,6 +1409,12 @@ pub struct TransportConfiguration {
     /// used to calculate retry delays and the number of attempts that
     /// should be made.
     pub(crate) retry_configuration: RequestRetryConfiguration,
+
+    /// Client-wide retry budget.
+    ///
+    /// Optional budget which limits total number of retry attempts made by
+    /// the client.
+    pub(crate) retry_budget: Option<RetryBudget>,
 }
 
 #[cfg(feature = "std")]
@@ -1138,6 +1424,7 @@ impl Default for TransportConfiguration {
             subscribe_request_timeout: 310,
             request_timeout: 10,
             retry_configuration: RequestRetryConfiguration::None,
+            retry_budget: None,
         }
     }
 }
@@ -1185,6 +1472,33 @@ impl Default for PresenceConfiguration {
     }
 }
 
+/// `user_id` subscription configuration.
+///
+/// The configuration contains parameters to control how real-time updates are
+/// received by the subscription loop.
+#[cfg(feature = "subscribe")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SubscribeConfiguration {
+    /// Maximum number of updates delivered by single receive call.
+    ///
+    /// When the service returns more updates than specified (for example,
+    /// because of large channel backlog), the remaining updates will be
+    /// fetched with the next subscription loop iteration.
+    ///
+    /// **Default:** `None` (all received updates delivered at once)
+    pub max_messages_per_receive: Option<NonZeroUsize>,
+
+    /// Maximum number of updates queued by each listener data stream.
+    ///
+    /// **Default:** `None` (`100` updates)
+    pub stream_queue_size: Option<NonZeroUsize>,
+
+    /// Policy which is used when listener data stream queue is full.
+    ///
+    /// **Default:** [`DataStreamOverflowPolicy::DropOldest`]
+    pub stream_overflow_policy: DataStreamOverflowPolicy,
+}
+
 /// PubNub configuration
 ///
 /// Configuration for [`PubNubClient`].
@@ -1206,6 +1520,9 @@ pub struct PubNubConfig {
     /// Authorization key
     pub(crate) auth_key: Option<Arc<String>>,
 
+    /// Whether requests and responses should be logged or not.
+    pub(crate) log_requests: bool,
+
     /// Transport configuration.
     ///
     /// Configuration allow to configure request processing aspects like:
@@ -1222,6 +1539,14 @@ pub struct PubNubConfig {
     /// * whether `user_id` _leave_ event should be announced or not.
     #[cfg(any(feature = "subscribe", feature = "presence"))]
     pub presence: PresenceConfiguration,
+
+    /// Subscribe configuration.
+    ///
+    /// The configuration allows you to set up how real-time updates are
+    /// received:
+    /// * maximum number of updates delivered by single receive call.
+    #[cfg(feature = "subscribe")]
+    pub subscribe: SubscribeConfiguration,
 }
 
 impl PubNubConfig {
@@ -1908,12 +2233,16 @@ where
                 secret_key,
                 user_id: Arc::new(user_id.into()),
                 auth_key: None,
+                log_requests: false,
 
                 #[cfg(feature = "std")]
                 transport: Default::default(),
 
                 #[cfg(any(feature = "subscribe", feature = "presence"))]
                 presence: Default::default(),
+
+                #[cfg(feature = "subscribe")]
+                subscribe: Default::default(),
             }),
 
             #[cfg(all(any(feature = "subscribe", feature = "presence"), feature = "std"))]
@@ -1923,6 +2252,33 @@ where
             ..Default::default()
         }
     }
+
+    /// Set randomly generated user id for the client.
+    ///
+    /// Random UUID v4 will be used as user id. Keep in mind that each new
+    /// user id counts as new user (for example in presence and billing), so
+    /// use it only when there is no stable identifier for the user.
+    ///
+    /// It returns [`PubNubClientConfigBuilder`] that you can use
+    /// to set the configuration for the client.
+    ///
+    /// # Examples
+    /// ```
+    /// use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// let builder = PubNubClientBuilder::with_reqwest_transport()
+    ///     .with_keyset(Keyset {
+    ///         subscribe_key: "sub-c-abc123",
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         secret_key: None,
+    ///     })
+    ///     .with_random_user_id();
+    /// ```
+    ///
+    /// [`PubNubClientConfigBuilder`]: struct.PubNubClientConfigBuilder.html
+    pub fn with_random_user_id(self) -> PubNubClientConfigBuilder<T, D> {
+        self.with_user_id(Uuid::new_v4().to_string())
+    }
 }
 
 /// Keyset for the PubNub client
@@ -1957,29 +2313,27 @@ mod should {
     use super::*;
     use crate::core::{TransportRequest, TransportResponse};
     use std::any::type_name;
+    use test_case::test_case;
 
-    #[test]
-    fn include_pubnub_middleware() {
-        #[derive(Default)]
-        struct MockTransport;
-
-        #[async_trait::async_trait]
-        impl crate::core::Transport for MockTransport {
-            async fn send(
-                &self,
-                _request: TransportRequest,
-            ) -> Result<TransportResponse, PubNubError> {
-                Ok(TransportResponse::default())
-            }
+    #[derive(Default)]
+    struct MockTransport;
+
+    #[async_trait::async_trait]
+    impl crate::core::Transport for MockTransport {
+        async fn send(&self, _request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            Ok(TransportResponse::default())
         }
+    }
 
+    #[test]
+    fn include_pubnub_middleware() {
         fn type_of<T>(_: &T) -> &'static str {
             type_name::<T>()
         }
 
         let client = PubNubClientBuilder::with_transport(MockTransport)
             .with_keyset(Keyset {
-                subscribe_key: "",
+                subscribe_key: "demo",
                 publish_key: Some(""),
                 secret_key: None,
             })
@@ -1993,6 +2347,168 @@ mod should {
         );
     }
 
+    #[test_case("" ; "empty")]
+    #[test_case("  " ; "whitespaces only")]
+    fn reject_empty_user_id(user_id: &str) {
+        let result = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id(user_id)
+            .build();
+
+        assert!(matches!(
+            result,
+            Err(PubNubError::ClientInitialization { details }) if details.contains("User ID")
+        ));
+    }
+
+    #[test]
+    fn generate_random_user_id() {
+        let builder = || {
+            PubNubClientBuilder::with_transport(MockTransport)
+                .with_keyset(Keyset {
+                    subscribe_key: "demo",
+                    publish_key: Some(""),
+                    secret_key: None,
+                })
+                .with_random_user_id()
+                .build()
+                .unwrap()
+        };
+        let (client, other_client) = (builder(), builder());
+
+        assert!(Uuid::parse_str(&client.config.user_id).is_ok());
+        assert_ne!(client.config.user_id, other_client.config.user_id);
+    }
+
+    #[test]
+    fn accept_borrowed_keyset() {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "sub_key",
+                publish_key: Some("pub_key"),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.config.subscribe_key, "sub_key");
+        assert_eq!(client.config.publish_key, Some("pub_key".into()));
+    }
+
+    #[test]
+    fn accept_owned_keyset() {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: String::from("sub_key"),
+                publish_key: Some(String::from("pub_key")),
+                secret_key: Some(String::from("sec_key")),
+            })
+            .with_user_id("my-user_id")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.config.subscribe_key, "sub_key");
+        assert_eq!(client.config.publish_key, Some("pub_key".into()));
+        assert_eq!(client.config.secret_key, Some("sec_key".into()));
+    }
+
+    #[test]
+    fn reject_empty_subscribe_key() {
+        let result = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "",
+                publish_key: Some("pub_key"),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .build();
+
+        assert!(matches!(result, Err(PubNubError::NoKey { .. })));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn store_transport_configuration() {
+        let retry_configuration = RequestRetryConfiguration::Linear {
+            delay: 2,
+            max_retry: 3,
+            excluded_endpoints: None,
+            max_elapsed: None,
+        };
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .with_retry_configuration(retry_configuration.clone())
+            .with_transport_timeout(Duration::from_secs(30))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            client.config.transport.retry_configuration,
+            retry_configuration
+        );
+        assert_eq!(client.config.transport.request_timeout, 30);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn round_up_sub_second_transport_timeout() {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .with_transport_timeout(Duration::from_millis(500))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.config.transport.request_timeout, 1);
+    }
+
+    #[cfg(all(feature = "subscribe", feature = "std"))]
+    #[test]
+    fn create_listener_streams_with_configured_queue() {
+        use crate::subscribe::{EventEmitter, Subscriber};
+
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some(""),
+                secret_key: None,
+            })
+            .with_user_id("my-user_id")
+            .with_stream_queue_size(NonZeroUsize::new(5).unwrap())
+            .with_stream_overflow_policy(DataStreamOverflowPolicy::Error)
+            .build()
+            .unwrap();
+        let subscription = client.channel("channel").subscription(None);
+
+        let status_stream = client.status_stream();
+        assert_eq!(status_stream.capacity(), 5);
+        assert_eq!(
+            status_stream.overflow_policy(),
+            DataStreamOverflowPolicy::Error
+        );
+        let messages_stream = subscription.messages_stream();
+        assert_eq!(messages_stream.capacity(), 5);
+        assert_eq!(
+            messages_stream.overflow_policy(),
+            DataStreamOverflowPolicy::Error
+        );
+        assert_eq!(subscription.clone_empty().stream().capacity(), 5);
+    }
+
     #[test]
     fn publish_key_is_required_if_secret_is_set() {
         let config = PubNubConfig {
@@ -2001,12 +2517,16 @@ mod should {
             secret_key: Some("sec_key".into()),
             user_id: Arc::new("".into()),
             auth_key: None,
+            log_requests: false,
 
             #[cfg(feature = "std")]
             transport: Default::default(),
 
             #[cfg(any(feature = "subscribe", feature = "presence"))]
             presence: Default::default(),
+
+            #[cfg(feature = "subscribe")]
+            subscribe: Default::default(),
         };
 
         assert!(config.signature_key_set().is_err());
@@ -0,0 +1,200 @@
+//! Signal module.
+//!
+//! This module contains `Signal` request builder.
+
+use derive_builder::Builder;
+
+use crate::{
+    core::{
+        error::PubNubError,
+        utils::encoding::{url_encode, url_encode_extended, UrlEncodeExtension},
+        Deserializer, Serialize, Transport, TransportMethod, TransportRequest,
+    },
+    dx::{
+        publish::{builders::validate_publish_configuration, PublishResponseBody},
+        pubnub_client::PubNubClientInstance,
+        signal::SignalResult,
+    },
+    lib::alloc::{
+        format,
+        string::{String, ToString},
+    },
+};
+
+/// Maximum size of serialized signal payload (in bytes).
+pub(in crate::dx::signal) const SIGNAL_MAX_SIZE: usize = 64;
+
+/// The [`SignalRequestBuilder`] is used to build signal request which will
+/// be sent to the [`PubNub`] network.
+///
+/// This struct used by the [`signal`] method of the [`PubNubClient`].
+/// Signals are lightweight messages which aren't stored in history and
+/// limited by size of serialized payload.
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`signal`]: crate::dx::PubNubClient::signal
+/// [`PubNubClient`]: crate::PubNubClient
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::signal)", validate = "Self::validate")
+)]
+#[cfg_attr(not(feature = "std"), builder(no_std))]
+pub struct SignalRequest<T, M, D>
+where
+    M: Serialize,
+{
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::signal)"), setter(custom))]
+    pub(in crate::dx::signal) pubnub_client: PubNubClientInstance<T, D>,
+
+    /// Signal payload.
+    #[builder(field(vis = "pub(in crate::dx::signal)"), setter(custom))]
+    pub(in crate::dx::signal) message: M,
+
+    /// Channel to which signal should be sent.
+    #[builder(field(vis = "pub(in crate::dx::signal)"), setter(into))]
+    pub(in crate::dx::signal) channel: String,
+}
+
+impl<T, M, D> SignalRequest<T, M, D>
+where
+    M: Serialize,
+{
+    /// Create transport request from the request builder.
+    pub(in crate::dx::signal) fn transport_request(&self) -> Result<TransportRequest, PubNubError> {
+        let config = &self.pubnub_client.config;
+        let pub_key = config
+            .publish_key
+            .as_ref()
+            .ok_or_else(|| PubNubError::NoKey {
+                details: "Publish key is not set".into(),
+            })?;
+        let sub_key = &config.subscribe_key;
+
+        let payload = self.message.serialize()?;
+        if payload.len() > SIGNAL_MAX_SIZE {
+            return Err(PubNubError::general_api_error(
+                format!(
+                    "Signal payload is too large: {} bytes ({} bytes is allowed)",
+                    payload.len(),
+                    SIGNAL_MAX_SIZE
+                ),
+                None,
+                None,
+            ));
+        }
+
+        let payload = String::from_utf8(payload).map_err(|err| PubNubError::Serialization {
+            details: err.to_string(),
+        })?;
+
+        Ok(TransportRequest {
+            path: format!(
+                "/signal/{pub_key}/{sub_key}/0/{}/0/{}",
+                url_encode(self.channel.as_bytes()),
+                url_encode_extended(payload.as_bytes(), UrlEncodeExtension::NonChannelPath)
+            ),
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: config.transport.request_timeout,
+            ..Default::default()
+        })
+    }
+}
+
+impl<T, M, D> SignalRequestBuilder<T, M, D>
+where
+    M: Serialize,
+{
+    /// Validate user-provided data for request builder.
+    ///
+    /// Validator ensure that provided information is enough to build valid
+    /// signal request instance.
+    fn validate(&self) -> Result<(), String> {
+        self.channel
+            .as_ref()
+            .filter(|channel| !channel.is_empty())
+            .map(|_| ())
+            .ok_or("Channel can't be empty.".into())
+    }
+
+    /// Build [`SignalRequest`] from builder.
+    fn request(self) -> Result<SignalRequest<T, M, D>, PubNubError> {
+        validate_publish_configuration(&self.pubnub_client)?;
+
+        self.build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))
+    }
+}
+
+impl<T, M, D> SignalRequestBuilder<T, M, D>
+where
+    T: Transport + 'static,
+    M: Serialize,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<SignalResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<PublishResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, M, D> SignalRequestBuilder<T, M, D>
+where
+    T: crate::core::blocking::Transport,
+    M: Serialize,
+    D: Deserializer + 'static,
+{
+    /// Execute synchronous request and return the result.
+    ///
+    /// This method is synchronous and will return result which will resolve to
+    /// a [`SignalResult`] or [`PubNubError`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_blocking_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: None,
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// let result = pubnub.signal("typing").channel("chat").execute_blocking()?;
+    /// println!("Signal timetoken: {}", result.timetoken);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn execute_blocking(self) -> Result<SignalResult, PubNubError> {
+        let request = self.request()?;
+        let transport_request = request.transport_request()?;
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request
+            .send_blocking::<PublishResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
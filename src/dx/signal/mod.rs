@@ -0,0 +1,160 @@
+//! Signal module.
+//!
+//! Send lightweight signal to a channel.
+//! The signal module contains the [`SignalRequestBuilder`] which is used to
+//! send small real-time updates (like typing indicators) which aren't stored
+//! in history.
+//!
+//! [`PubNub`]:https://www.pubnub.com/
+
+#[doc(inline)]
+pub use result::SignalResult;
+pub mod result;
+
+#[doc(inline)]
+pub use builders::{SignalRequest, SignalRequestBuilder};
+pub mod builders;
+
+use crate::{core::Serialize, dx::pubnub_client::PubNubClientInstance};
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create signal request builder.
+    ///
+    /// This method is used to send lightweight signal to a channel. Serialized
+    /// signal payload can't be larger than 64 bytes.
+    ///
+    /// Instance of [`SignalRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: None,
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// let result = pubnub.signal("typing").channel("chat").execute().await?;
+    /// println!("Signal timetoken: {}", result.timetoken);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn signal<M>(&self, message: M) -> SignalRequestBuilder<T, M, D>
+    where
+        M: Serialize,
+    {
+        SignalRequestBuilder {
+            pubnub_client: Some(self.clone()),
+            message: Some(message),
+            channel: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse},
+        lib::alloc::{boxed::Box, string::String, vec::Vec},
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    /// Requests handler function type.
+    type RequestHandler = Box<dyn Fn(&TransportRequest) + Send + Sync>;
+
+    #[derive(Default)]
+    struct MockTransport {
+        /// Request handler function which will be called before returning
+        /// response.
+        ///
+        /// Use function to verify request parameters.
+        request_handler: Option<RequestHandler>,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            // Calling request handler (if provided).
+            if let Some(handler) = &self.request_handler {
+                handler(&req);
+            }
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(Vec::from("[1,\"Sent\",\"15815800000000000\"]")),
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Construct test client with mocked transport.
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo-pub"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn send_signal() {
+        let transport = MockTransport {
+            request_handler: Some(Box::new(|req| {
+                assert_eq!(req.path, "/signal/demo-pub/demo/0/chat/0/%22typing%22");
+                assert!(matches!(req.method, TransportMethod::Get));
+            })),
+        };
+        let result = client(transport)
+            .signal("typing")
+            .channel("chat")
+            .execute()
+            .await;
+
+        match result {
+            Ok(result) => assert_eq!(result.timetoken, "15815800000000000"),
+            Err(err) => panic!("Request should not fail: {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn return_error_for_oversized_signal() {
+        let transport = MockTransport {
+            request_handler: Some(Box::new(|_| {
+                panic!("Oversized signal shouldn't be sent");
+            })),
+        };
+        let result = client(transport)
+            .signal(String::from_utf8(vec![b'a'; 63]).unwrap())
+            .channel("chat")
+            .execute()
+            .await;
+
+        assert!(matches!(result, Err(PubNubError::API { .. })));
+    }
+
+    #[tokio::test]
+    async fn return_error_for_empty_channel() {
+        let result = client(Default::default())
+            .signal("typing")
+            .channel("")
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+    }
+}
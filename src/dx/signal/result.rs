@@ -0,0 +1,71 @@
+//! Signal result module.
+//!
+//! This module contains [`SignalResult`] type which is used to represent the
+//! result of a signal operation.
+
+use crate::{core::PubNubError, dx::publish::PublishResponseBody, lib::alloc::string::String};
+
+/// The result of a signal operation.
+/// It contains the timetoken of the sent signal.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SignalResult {
+    /// The timetoken of the sent signal.
+    pub timetoken: String,
+}
+
+/// Signal service responds with the same payload as the publish service.
+///
+/// # Example
+/// ```json
+/// [1, "Sent", "15815800000000000"]
+/// ```
+impl TryFrom<PublishResponseBody> for SignalResult {
+    type Error = PubNubError;
+
+    fn try_from(value: PublishResponseBody) -> Result<Self, Self::Error> {
+        match value {
+            PublishResponseBody::SuccessResponse(error_indicator, message, timetoken) => {
+                if error_indicator == 1 {
+                    Ok(SignalResult { timetoken })
+                } else {
+                    Err(PubNubError::general_api_error(message, None, None))
+                }
+            }
+            PublishResponseBody::ErrorResponse(resp) => Err(resp.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::core::service_response::APIErrorBody;
+
+    #[test]
+    fn parse_signal_response() {
+        let result: Result<SignalResult, PubNubError> =
+            PublishResponseBody::SuccessResponse(1, "Sent".into(), "15815800000000000".into())
+                .try_into();
+
+        assert_eq!(
+            result,
+            Ok(SignalResult {
+                timetoken: "15815800000000000".into()
+            })
+        );
+    }
+
+    #[test]
+    fn return_error_on_error_response() {
+        let result: Result<SignalResult, PubNubError> =
+            PublishResponseBody::ErrorResponse(APIErrorBody::AsObjectWithService {
+                status: 400,
+                error: true,
+                service: "Signal".into(),
+                message: "Invalid Key".into(),
+            })
+            .try_into();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,364 @@
+//! # Blocking subscription module.
+//!
+//! This module contains the [`BlockingSubscription`] type, which can be used
+//! to receive real-time updates without `async` code (for example, in scripts
+//! or in applications built around blocking transport).
+
+use futures::{stream, StreamExt};
+use std::{
+    sync::{
+        mpsc::{self, Receiver},
+        OnceLock,
+    },
+    thread::{self, JoinHandle},
+};
+use tokio::runtime::Runtime;
+
+use crate::{
+    core::{Deserializer, PubNubError, Transport},
+    dx::pubnub_client::PubNubClientInstance,
+    lib::alloc::string::{String, ToString},
+    subscribe::{
+        EventEmitter, EventSubscriber, SubscribeStreamEvent, SubscriptionParams, SubscriptionSet,
+    },
+};
+
+/// Blocking subscription.
+///
+/// Subscription event engine driven by shared runtime from a separate thread,
+/// and received connection status changes and real-time updates are
+/// delivered through [`BlockingSubscription::next_message`] calls.
+///
+/// Subscription will be stopped and background thread terminated when
+/// [`BlockingSubscription`] instance is dropped. Shared runtime keeps running,
+/// so the client's subscription event engine can be used by other
+/// subscriptions.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use pubnub::{
+///     subscribe::{SubscribeStreamEvent, SubscriptionParams, Update},
+///     Keyset, PubNubClientBuilder,
+/// };
+///
+/// # fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let client = PubNubClientBuilder::with_reqwest_transport()
+///     .with_keyset(Keyset {
+///         subscribe_key: "demo",
+///         publish_key: Some("demo"),
+///         secret_key: None,
+///     })
+///     .with_user_id("user_id")
+///     .build()?;
+///
+/// let mut subscription = client.blocking_subscription(SubscriptionParams {
+///     channels: Some(&["my_channel"]),
+///     channel_groups: None,
+///     options: None,
+/// });
+///
+/// while let Some(event) = subscription.next_message()? {
+///     if let SubscribeStreamEvent::Update(Update::Message(message)) = event {
+///         println!("message: {:?}", String::from_utf8(message.data));
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct BlockingSubscription {
+    /// Events received by subscription on background thread.
+    receiver: Receiver<Result<SubscribeStreamEvent, PubNubError>>,
+
+    /// Background thread termination signal.
+    shutdown: async_channel::Sender<()>,
+
+    /// Background thread which drives subscription event engine.
+    worker: Option<JoinHandle<()>>,
+}
+
+impl BlockingSubscription {
+    /// Create blocking subscription.
+    ///
+    /// Subscription loop starts on background thread right away.
+    pub(crate) fn new<T, D>(
+        client: PubNubClientInstance<T, D>,
+        subscription: SubscriptionSet<T, D>,
+    ) -> Self
+    where
+        T: Transport + Send + Sync + 'static,
+        D: Deserializer + Send + Sync + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let (shutdown, shutdown_receiver) = async_channel::bounded::<()>(1);
+
+        let worker = thread::spawn(move || {
+            let runtime = match runtime() {
+                Ok(runtime) => runtime,
+                Err(details) => {
+                    let _ = sender.send(Err(PubNubError::SubscribeInitialization { details }));
+                    return;
+                }
+            };
+
+            runtime.block_on(async move {
+                let mut events = stream::select(
+                    stream::select(
+                        client.status_stream().map(SubscribeStreamEvent::Status),
+                        subscription.stream().map(SubscribeStreamEvent::Update),
+                    )
+                    .map(Some),
+                    shutdown_receiver.map(|_| None),
+                );
+                subscription.subscribe();
+
+                while let Some(Some(event)) = events.next().await {
+                    if sender.send(Ok(event)).is_err() {
+                        break;
+                    }
+                }
+
+                subscription.unsubscribe();
+            });
+        });
+
+        Self {
+            receiver,
+            shutdown,
+            worker: Some(worker),
+        }
+    }
+
+    /// Wait for next subscription event.
+    ///
+    /// Blocks the current thread until a connection status change or
+    /// real-time update is received.
+    ///
+    /// # Returns
+    ///
+    /// Next received [`SubscribeStreamEvent`] or `None` when subscription
+    /// loop has been stopped. Error returned if subscription loop can't be
+    /// started.
+    pub fn next_message(&mut self) -> Result<Option<SubscribeStreamEvent>, PubNubError> {
+        match self.receiver.recv() {
+            Ok(event) => event.map(Some),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+/// Runtime which drives blocking subscriptions.
+///
+/// First subscription creates the client's subscription event engine on this
+/// runtime. Runtime is shared and never shut down, because the event engine
+/// is used by the client for the rest of its life.
+fn runtime() -> Result<&'static Runtime, String> {
+    static RUNTIME: OnceLock<Result<Runtime, String>> = OnceLock::new();
+
+    RUNTIME
+        .get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .thread_name("pubnub-blocking-subscription")
+                .enable_all()
+                .build()
+                .map_err(|error| error.to_string())
+        })
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+impl Drop for BlockingSubscription {
+    fn drop(&mut self) {
+        let _ = self.shutdown.try_send(());
+
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl<T, D> PubNubClientInstance<T, D>
+where
+    T: Transport + Send + Sync + 'static,
+    D: Deserializer + Send + Sync + 'static,
+{
+    /// Creates a blocking subscription.
+    ///
+    /// Subscribe to real-time updates from channels and groups without
+    /// `async` code. Subscription loop driven from separate thread with
+    /// runtime shared by blocking subscriptions.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - A `SubscriptionParams` object containing the
+    ///   subscription parameters.
+    ///
+    /// # Returns
+    ///
+    /// The created [`BlockingSubscription`] object.
+    pub fn blocking_subscription<N>(
+        &self,
+        parameters: SubscriptionParams<N>,
+    ) -> BlockingSubscription
+    where
+        N: Into<String> + Clone,
+    {
+        BlockingSubscription::new(self.clone(), self.subscription(parameters))
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{
+        core::{blocking, TransportRequest, TransportResponse},
+        lib::alloc::{format, vec::Vec},
+        providers::deserialization_serde::DeserializerSerde,
+        subscribe::{ConnectionStatus, Message, Update},
+        Keyset, PubNubClientBuilder, PubNubGenericClient,
+    };
+    use percent_encoding::percent_decode_str;
+    use spin::Mutex;
+    use std::collections::VecDeque;
+
+    /// Transport which deliver published messages with subscribe response.
+    #[derive(Default)]
+    struct MockTransport {
+        published: Mutex<VecDeque<(String, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            let handshake = request
+                .query_parameters
+                .get("tt")
+                .map(|timetoken| timetoken.eq("0"))
+                .unwrap_or(true);
+
+            let messages = loop {
+                let published = self.published.lock().drain(..).collect::<Vec<_>>();
+                if handshake || !published.is_empty() {
+                    break published;
+                }
+
+                tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+            };
+
+            let messages = messages
+                .iter()
+                .map(|(channel, message)| {
+                    format!(
+                        r#"{{"a":"1","f":0,"i":"publisher","p":{{"t":"17000000000000001","r":1}},"k":"demo","c":"{channel}","d":{message},"b":"{channel}"}}"#
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(
+                    format!(r#"{{"t":{{"t":"17000000000000001","r":1}},"m":[{messages}]}}"#)
+                        .into_bytes(),
+                ),
+                ..Default::default()
+            })
+        }
+    }
+
+    impl blocking::Transport for MockTransport {
+        fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            // Path: /publish/{pub_key}/{sub_key}/0/{channel}/0/{message}
+            let components = request.path.split('/').collect::<Vec<_>>();
+            let message = percent_decode_str(components[7]).decode_utf8_lossy();
+            self.published
+                .lock()
+                .push_back((components[5].into(), message.into()));
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(b"[1,\"Sent\",\"17000000000000001\"]".to_vec()),
+                ..Default::default()
+            })
+        }
+    }
+
+    fn client() -> PubNubGenericClient<MockTransport, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(MockTransport::default())
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    /// Publish message once subscription connected and wait for it.
+    fn publish_and_receive(
+        client: &PubNubGenericClient<MockTransport, DeserializerSerde>,
+        subscription: &mut BlockingSubscription,
+        channel: &str,
+    ) -> Message {
+        loop {
+            if let Some(SubscribeStreamEvent::Status(ConnectionStatus::Connected)) =
+                subscription.next_message().unwrap()
+            {
+                break;
+            }
+        }
+
+        client
+            .publish_message("hello")
+            .channel(channel)
+            .execute_blocking()
+            .unwrap();
+
+        loop {
+            if let Some(SubscribeStreamEvent::Update(Update::Message(message))) =
+                subscription.next_message().unwrap()
+            {
+                break message;
+            }
+        }
+    }
+
+    #[test]
+    fn receive_published_message() {
+        let client = client();
+        let mut subscription = client.blocking_subscription(SubscriptionParams {
+            channels: Some(&["my-channel"]),
+            channel_groups: None,
+            options: None,
+        });
+
+        let message = publish_and_receive(&client, &mut subscription, "my-channel");
+
+        assert_eq!(message.channel, "my-channel");
+        assert_eq!(message.data, b"\"hello\"".to_vec());
+    }
+
+    #[test]
+    fn receive_on_next_subscription_after_first_dropped() {
+        let client = client();
+        let mut subscription = client.blocking_subscription(SubscriptionParams {
+            channels: Some(&["channel-1"]),
+            channel_groups: None,
+            options: None,
+        });
+        publish_and_receive(&client, &mut subscription, "channel-1");
+        drop(subscription);
+
+        let mut subscription = client.blocking_subscription(SubscriptionParams {
+            channels: Some(&["channel-2"]),
+            channel_groups: None,
+            options: None,
+        });
+        let message = publish_and_receive(&client, &mut subscription, "channel-2");
+
+        assert_eq!(message.channel, "channel-2");
+    }
+}
@@ -23,6 +23,9 @@ use crate::{
     lib::alloc::{collections::VecDeque, string::String, string::ToString, vec::Vec},
 };
 
+#[cfg(all(feature = "presence", feature = "std", feature = "serde"))]
+use crate::{core::ScalarValue, lib::collections::HashMap};
+
 /// Raw subscription that is responsible for getting messages from PubNub.
 ///
 /// In difference from [`Subscription`] this one is not responsible for
@@ -107,6 +110,19 @@ pub struct RawSubscription<T, D> {
         default = "None"
     )]
     pub(in crate::dx::subscribe) filter_expression: Option<String>,
+
+    /// A state that should be associated with the `user_id`.
+    ///
+    /// `state` object should be a `HashMap` with channel names as keys and
+    /// nested `HashMap` with values. State with subscribe can be set **only**
+    /// for channels.
+    #[cfg(all(feature = "presence", feature = "std", feature = "serde"))]
+    #[builder(
+        field(vis = "pub(in crate::dx::subscribe)"),
+        setter(strip_option),
+        default = "None"
+    )]
+    pub(in crate::dx::subscribe) state: Option<HashMap<String, HashMap<String, ScalarValue>>>,
 }
 
 impl<T, D> RawSubscriptionBuilder<T, D> {
@@ -206,6 +222,11 @@ where
                     request = request.filter_expression(filter_expr);
                 }
 
+                #[cfg(all(feature = "presence", feature = "std", feature = "serde"))]
+                if let Some(state) = ctx.subscription.state.clone() {
+                    request = request.scalar_state(state);
+                }
+
                 let response = request.execute().await;
 
                 if let Err(e) = response {
@@ -279,6 +300,11 @@ where
                 request = request.filter_expression(filter_expr);
             }
 
+            #[cfg(all(feature = "presence", feature = "std", feature = "serde"))]
+            if let Some(state) = ctx.subscription.state.clone() {
+                request = request.scalar_state(state);
+            }
+
             let response = request.execute_blocking();
 
             if let Err(e) = response {
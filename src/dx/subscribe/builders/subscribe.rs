@@ -13,10 +13,7 @@ use futures::{
 use crate::{
     core::{
         blocking,
-        utils::encoding::{
-            url_encode_extended, url_encoded_channel_groups, url_encoded_channels,
-            UrlEncodeExtension,
-        },
+        utils::encoding::{url_encoded_channel_groups, url_encoded_channels},
         Deserializer, PubNubError, Transport, {TransportMethod, TransportRequest},
     },
     dx::{
@@ -33,6 +30,8 @@ use crate::{
     },
 };
 
+#[cfg(all(feature = "presence", feature = "std", feature = "serde"))]
+use crate::core::{ScalarValue, Serialize};
 #[cfg(all(feature = "presence", feature = "std"))]
 use crate::lib::alloc::vec;
 #[cfg(feature = "std")]
@@ -168,6 +167,29 @@ impl<T, D> SubscribeRequestBuilder<T, D> {
         self
     }
 
+    /// A state with scalar values that should be associated with the
+    /// `user_id`.
+    ///
+    /// `state` object should be a `HashMap` with channel names as keys and
+    /// nested `HashMap` with [`ScalarValue`] values. Channels for which state
+    /// can't be serialized will be skipped.
+    #[cfg(all(feature = "presence", feature = "std", feature = "serde"))]
+    pub(in crate::dx::subscribe) fn scalar_state(
+        self,
+        state: HashMap<String, HashMap<String, ScalarValue>>,
+    ) -> Self {
+        let serialized_state = state
+            .iter()
+            .fold(HashMap::new(), |mut acc, (channel, state)| {
+                if let Ok(serialized_state) = state.serialize() {
+                    acc.insert(channel.clone(), serialized_state);
+                }
+                acc
+            });
+
+        self.state(serialized_state)
+    }
+
     /// Validate user-provided data for request builder.
     ///
     /// Validator ensure that list of provided data is enough to build valid
@@ -215,15 +237,12 @@ impl<T, D> SubscribeRequest<T, D> {
             query.insert("state".into(), state_json);
         }
 
+        // Expression will be URL-encoded by transport along with other query
+        // parameters.
         self.filter_expression
             .as_ref()
             .filter(|e| !e.is_empty())
-            .and_then(|e| {
-                query.insert(
-                    "filter-expr".into(),
-                    url_encode_extended(e.as_bytes(), UrlEncodeExtension::NonChannelPath),
-                )
-            });
+            .and_then(|e| query.insert("filter-expr".into(), e.clone()));
 
         query.insert("heartbeat".into(), self.heartbeat.to_string());
 
@@ -260,6 +279,8 @@ where
                 #[cfg(feature = "std")]
                 &client.config.transport.retry_configuration,
                 #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
                 &client.runtime,
             )
             .await
@@ -324,9 +345,90 @@ where
 #[cfg(test)]
 mod should {
     use super::*;
-    use crate::{core::TransportResponse, PubNubClientBuilder};
+    use crate::{
+        core::{utils::encoding::url_encode, TransportResponse},
+        PubNubClientBuilder, PubNubGenericClient,
+    };
     use futures::future::ready;
 
+    struct MockTransport;
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, _req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            Ok(TransportResponse::default())
+        }
+    }
+
+    fn client() -> PubNubGenericClient<
+        MockTransport,
+        crate::providers::deserialization_serde::DeserializerSerde,
+    > {
+        PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(crate::Keyset {
+                subscribe_key: "test",
+                publish_key: Some("test"),
+                secret_key: None,
+            })
+            .with_user_id("test")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn add_encoded_filter_expression_to_query() {
+        let request = client()
+            .subscribe_request()
+            .channels(vec!["test".into()])
+            .filter_expression("uuid != 'bob' && priority > 2".into())
+            .request()
+            .unwrap()
+            .transport_request()
+            .unwrap();
+
+        let expression = request.query_parameters.get("filter-expr").unwrap();
+        assert_eq!(expression, "uuid != 'bob' && priority > 2");
+        assert_eq!(
+            url_encode(expression.as_bytes()),
+            "uuid%20%21%3D%20'bob'%20%26%26%20priority%20%3E%202"
+        );
+    }
+
+    #[cfg(all(feature = "presence", feature = "serde"))]
+    #[test]
+    fn add_serialized_state_to_query() {
+        let request = client()
+            .subscribe_request()
+            .channels(vec!["test".into()])
+            .scalar_state(HashMap::from([(
+                "test".into(),
+                HashMap::from([("is_admin".into(), ScalarValue::Boolean(true))]),
+            )]))
+            .request()
+            .unwrap()
+            .transport_request()
+            .unwrap();
+
+        assert_eq!(
+            request.query_parameters.get("state"),
+            Some(&r#"{"test":{"is_admin":true}}"#.to_string())
+        );
+    }
+
+    #[test]
+    fn not_add_empty_filter_expression_to_query() {
+        let request = client()
+            .subscribe_request()
+            .channels(vec!["test".into()])
+            .filter_expression(String::new())
+            .request()
+            .unwrap()
+            .transport_request()
+            .unwrap();
+
+        assert!(!request.query_parameters.contains_key("filter-expr"));
+    }
+
     #[tokio::test]
     async fn be_able_to_cancel_subscribe_call() {
         struct MockTransport;
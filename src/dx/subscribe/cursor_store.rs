@@ -0,0 +1,258 @@
+//! # Subscription cursor store module.
+//!
+//! This module contains the [`SubscriptionCursorStore`] trait, which is used to
+//! persist subscription time cursor between client restarts, and
+//! [`InMemoryCursorStore`] and [`FileCursorStore`] implementations.
+
+use spin::{Mutex, RwLock};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Sender},
+    thread::{self, JoinHandle},
+};
+
+use crate::{
+    dx::subscribe::SubscriptionCursor,
+    lib::{
+        alloc::string::{String, ToString},
+        core::fmt::Debug,
+    },
+};
+
+/// Subscription cursor store trait.
+///
+/// Types that implement this trait can be used by the subscription module to
+/// restore subscription from the last received time cursor (instead of
+/// `handshake`) and to store new cursor each time when real-time updates
+/// received.
+pub trait SubscriptionCursorStore: Debug + Send + Sync {
+    /// Load previously stored time cursor.
+    ///
+    /// # Returns
+    ///
+    /// Stored [`SubscriptionCursor`] or `None` if there is no stored cursor.
+    fn load(&self) -> Option<SubscriptionCursor>;
+
+    /// Store time cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - Time cursor of the last received real-time updates.
+    fn save(&self, cursor: &SubscriptionCursor);
+}
+
+/// In-memory subscription cursor store.
+///
+/// Store keeps cursor only during application lifetime and can be shared
+/// between client instances.
+#[derive(Debug, Default)]
+pub struct InMemoryCursorStore {
+    /// Stored time cursor.
+    cursor: RwLock<Option<SubscriptionCursor>>,
+}
+
+impl InMemoryCursorStore {
+    /// Creates a new in-memory cursor store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SubscriptionCursorStore for InMemoryCursorStore {
+    fn load(&self) -> Option<SubscriptionCursor> {
+        self.cursor.read().clone()
+    }
+
+    fn save(&self, cursor: &SubscriptionCursor) {
+        *self.cursor.write() = Some(cursor.clone());
+    }
+}
+
+/// File-backed subscription cursor store.
+///
+/// Store keeps cursor in the file at specified `path` as `timetoken:region`
+/// string, so subscription can be restored after application restart.
+///
+/// Cursors are written by a background thread, so [`save`] doesn't block the
+/// subscription loop. When several cursors are saved in a row, only the latest
+/// one is written. The file is replaced atomically (new contents written to a
+/// temporary file first), and pending cursor is flushed when store is dropped.
+///
+/// [`save`]: SubscriptionCursorStore::save
+#[derive(Debug)]
+pub struct FileCursorStore {
+    /// Path to the file with stored time cursor.
+    path: PathBuf,
+
+    /// Latest saved time cursor.
+    ///
+    /// Cursor which has been passed to the writer but may not be written to
+    /// the file yet.
+    cursor: RwLock<Option<SubscriptionCursor>>,
+
+    /// Background cursor writer.
+    ///
+    /// Writer is spawned with the first saved cursor.
+    writer: Mutex<Option<(Sender<SubscriptionCursor>, JoinHandle<()>)>>,
+}
+
+impl FileCursorStore {
+    /// Creates a new file-backed cursor store.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the file which will be used to store time cursor.
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            path: path.into(),
+            cursor: Default::default(),
+            writer: Default::default(),
+        }
+    }
+
+    /// Spawn background thread which writes saved cursors to the file.
+    ///
+    /// # Returns
+    ///
+    /// Sender for cursors which should be written and writer thread handle or
+    /// `None` if it wasn't possible to spawn thread.
+    fn spawn_writer(&self) -> Option<(Sender<SubscriptionCursor>, JoinHandle<()>)> {
+        let (sender, receiver) = channel::<SubscriptionCursor>();
+        let path = self.path.clone();
+
+        let handle = thread::Builder::new()
+            .name("pubnub-cursor-store".into())
+            .spawn(move || {
+                while let Ok(cursor) = receiver.recv() {
+                    // Only the latest of queued cursors should be stored.
+                    let cursor = receiver.try_iter().last().unwrap_or(cursor);
+                    Self::write(&path, &cursor);
+                }
+            })
+            .map_err(|error| log::warn!("Unable to spawn subscription cursor writer: {error}"))
+            .ok()?;
+
+        Some((sender, handle))
+    }
+
+    /// Atomically replace contents of the file at `path` with `cursor`.
+    ///
+    /// Cursor is written to the temporary file next to the `path` and then
+    /// renamed, so the file never contains partially written cursor.
+    fn write(path: &Path, cursor: &SubscriptionCursor) {
+        let contents: String = [cursor.timetoken.as_str(), &cursor.region.to_string()].join(":");
+        let mut temporary_path = path.as_os_str().to_owned();
+        temporary_path.push(".tmp");
+
+        if let Err(error) =
+            fs::write(&temporary_path, contents).and_then(|_| fs::rename(&temporary_path, path))
+        {
+            log::warn!("Unable to store subscription cursor in {path:?}: {error}");
+        }
+    }
+}
+
+impl SubscriptionCursorStore for FileCursorStore {
+    fn load(&self) -> Option<SubscriptionCursor> {
+        // Saved cursor may not be written to the file yet.
+        if let Some(cursor) = self.cursor.read().clone() {
+            return Some(cursor);
+        }
+
+        let contents = fs::read_to_string(&self.path).ok()?;
+        let (timetoken, region) = contents.trim().split_once(':')?;
+
+        timetoken.parse::<u64>().ok()?;
+
+        Some(SubscriptionCursor {
+            timetoken: timetoken.to_string(),
+            region: region.parse().ok()?,
+        })
+    }
+
+    fn save(&self, cursor: &SubscriptionCursor) {
+        *self.cursor.write() = Some(cursor.clone());
+
+        let mut writer = self.writer.lock();
+        if writer.is_none() {
+            *writer = self.spawn_writer();
+        }
+
+        match writer.as_ref() {
+            Some((sender, _)) if sender.send(cursor.clone()).is_ok() => {}
+            // Fallback to the synchronous write.
+            _ => Self::write(&self.path, cursor),
+        }
+    }
+}
+
+impl Drop for FileCursorStore {
+    fn drop(&mut self) {
+        // Closed channel stops writer after pending cursor has been written.
+        if let Some((sender, handle)) = self.writer.lock().take() {
+            drop(sender);
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::lib::alloc::format;
+
+    fn cursor() -> SubscriptionCursor {
+        SubscriptionCursor {
+            timetoken: "17000000000000001".into(),
+            region: 4,
+        }
+    }
+
+    #[test]
+    fn store_cursor_in_memory() {
+        let store = InMemoryCursorStore::new();
+        assert!(store.load().is_none());
+
+        store.save(&cursor());
+
+        assert_eq!(store.load(), Some(cursor()));
+    }
+
+    #[test]
+    fn store_cursor_in_file() {
+        let path = std::env::temp_dir().join(format!("pubnub-cursor-{}", uuid::Uuid::new_v4()));
+        let store = FileCursorStore::new(&path);
+        assert!(store.load().is_none());
+
+        store.save(&cursor());
+        assert_eq!(store.load(), Some(cursor()));
+        drop(store);
+
+        assert_eq!(FileCursorStore::new(&path).load(), Some(cursor()));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn store_latest_cursor_in_file() {
+        let path = std::env::temp_dir().join(format!("pubnub-cursor-{}", uuid::Uuid::new_v4()));
+        let store = FileCursorStore::new(&path);
+        let latest = SubscriptionCursor {
+            timetoken: "17000000000000100".into(),
+            region: 4,
+        };
+
+        (0..100).for_each(|_| store.save(&cursor()));
+        store.save(&latest);
+        drop(store);
+
+        assert_eq!(FileCursorStore::new(&path).load(), Some(latest));
+        let mut temporary_path = path.as_os_str().to_owned();
+        temporary_path.push(".tmp");
+        assert!(!Path::new(&temporary_path).exists());
+        let _ = fs::remove_file(path);
+    }
+}
@@ -8,10 +8,11 @@ use spin::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use std::fmt::Debug;
 
 use crate::{
-    core::DataStream,
+    core::{DataStream, DataStreamOverflowPolicy},
+    dx::pubnub_client::SubscribeConfiguration,
     lib::{
         alloc::{collections::VecDeque, vec::Vec},
-        core::{default::Default, ops::Drop},
+        core::{default::Default, num::NonZeroUsize, ops::Drop},
     },
     subscribe::{
         AppContext, ConnectionStatus, EventEmitter, File, Message, MessageAction, Presence,
@@ -19,8 +20,17 @@ use crate::{
     },
 };
 
+/// Default maximum number of entries in listener data stream queue.
+const DEFAULT_STREAM_QUEUE_SIZE: usize = 100;
+
 #[derive(Debug)]
 pub(crate) struct EventDispatcher {
+    /// Maximum number of entries in listener data stream queue.
+    stream_queue_size: usize,
+
+    /// Policy which is used when listener data stream queue is full.
+    stream_overflow_policy: DataStreamOverflowPolicy,
+
     /// Whether listener streams has been created or not.
     has_streams: RwLock<bool>,
 
@@ -94,7 +104,38 @@ impl EventDispatcher {
     /// Returns [`EventDispatcher`] instance with pre-configured set of data
     /// streams.
     pub(crate) fn new() -> Self {
+        Self::with_stream_configuration(
+            DEFAULT_STREAM_QUEUE_SIZE,
+            DataStreamOverflowPolicy::default(),
+        )
+    }
+
+    /// Create event dispatcher instance with subscribe configuration.
+    ///
+    /// Listener data streams will use queue size and overflow policy from
+    /// client's subscribe `configuration`.
+    pub(crate) fn with_configuration(configuration: &SubscribeConfiguration) -> Self {
+        Self::with_stream_configuration(
+            configuration
+                .stream_queue_size
+                .map_or(DEFAULT_STREAM_QUEUE_SIZE, NonZeroUsize::get),
+            configuration.stream_overflow_policy,
+        )
+    }
+
+    /// Create empty event dispatcher instance with same listener data streams
+    /// configuration.
+    pub(crate) fn clone_empty(&self) -> Self {
+        Self::with_stream_configuration(self.stream_queue_size, self.stream_overflow_policy)
+    }
+
+    fn with_stream_configuration(
+        stream_queue_size: usize,
+        stream_overflow_policy: DataStreamOverflowPolicy,
+    ) -> Self {
         Self {
+            stream_queue_size,
+            stream_overflow_policy,
             has_streams: Default::default(),
             message_streams: Default::default(),
             signal_streams: Default::default(),
@@ -194,11 +235,11 @@ impl EventDispatcher {
     {
         let mut has_streams_slot = self.has_streams.write();
         *has_streams_slot = true;
-        let stream = if let Some(data) = data {
-            DataStream::with_queue_data(data, 100)
-        } else {
-            DataStream::new()
-        };
+        let stream = DataStream::with_configuration(
+            data.unwrap_or_default(),
+            self.stream_queue_size,
+            self.stream_overflow_policy,
+        );
 
         if let Some(streams) = streams.as_mut() {
             streams.push(stream.clone())
@@ -227,9 +268,11 @@ impl EventDispatcher {
             return;
         };
 
-        streams
-            .iter()
-            .for_each(|stream| stream.push_data(event.clone()))
+        streams.iter().for_each(|stream| {
+            if let Err(error) = stream.push_data(event.clone()) {
+                log::error!("Unable to deliver event: {error}");
+            }
+        })
     }
 
     /// Dequeues and returns a vector of matching events from the queue.
@@ -11,7 +11,10 @@ use crate::{
     },
     lib::{
         alloc::{string::String, sync::Arc},
-        core::fmt::{Debug, Formatter, Result},
+        core::{
+            fmt::{Debug, Formatter, Result},
+            num::NonZeroUsize,
+        },
     },
 };
 
@@ -32,6 +35,15 @@ pub(crate) struct SubscribeEffectHandler {
     /// Retry policy.
     retry_policy: RequestRetryConfiguration,
 
+    /// Retry policy override.
+    ///
+    /// Retry policy provided by active subscriptions which should be used
+    /// instead of `retry_policy`.
+    retry_policy_override: Arc<RwLock<Option<RequestRetryConfiguration>>>,
+
+    /// Maximum number of updates delivered by single receive call.
+    max_messages: Option<NonZeroUsize>,
+
     /// Cancellation channel.
     cancellation_channel: Sender<String>,
 }
@@ -43,6 +55,8 @@ impl SubscribeEffectHandler {
         emit_status: Arc<EmitStatusEffectExecutor>,
         emit_messages: Arc<EmitMessagesEffectExecutor>,
         retry_policy: RequestRetryConfiguration,
+        retry_policy_override: Arc<RwLock<Option<RequestRetryConfiguration>>>,
+        max_messages: Option<NonZeroUsize>,
         cancellation_channel: Sender<String>,
     ) -> Self {
         Self {
@@ -50,9 +64,19 @@ impl SubscribeEffectHandler {
             emit_status,
             emit_messages,
             retry_policy,
+            retry_policy_override,
+            max_messages,
             cancellation_channel,
         }
     }
+
+    /// Retry policy which should be used by reconnection effects.
+    fn retry_policy(&self) -> RequestRetryConfiguration {
+        self.retry_policy_override
+            .read()
+            .clone()
+            .unwrap_or_else(|| self.retry_policy.clone())
+    }
 }
 
 impl EffectHandler<SubscribeEffectInvocation, SubscribeEffect> for SubscribeEffectHandler {
@@ -80,7 +104,7 @@ impl EffectHandler<SubscribeEffectInvocation, SubscribeEffect> for SubscribeEffe
                 cursor: cursor.clone(),
                 attempts: *attempts,
                 reason: reason.clone(),
-                retry_policy: self.retry_policy.clone(),
+                retry_policy: self.retry_policy(),
                 executor: self.subscribe_call.clone(),
                 cancellation_channel: self.cancellation_channel.clone(),
             }),
@@ -90,6 +114,7 @@ impl EffectHandler<SubscribeEffectInvocation, SubscribeEffect> for SubscribeEffe
                     cancelled: RwLock::new(false),
                     input: input.clone(),
                     cursor: cursor.clone(),
+                    max_messages: self.max_messages,
                     executor: self.subscribe_call.clone(),
                     cancellation_channel: self.cancellation_channel.clone(),
                 })
@@ -106,7 +131,8 @@ impl EffectHandler<SubscribeEffectInvocation, SubscribeEffect> for SubscribeEffe
                 cursor: cursor.clone(),
                 attempts: *attempts,
                 reason: reason.clone(),
-                retry_policy: self.retry_policy.clone(),
+                retry_policy: self.retry_policy(),
+                max_messages: self.max_messages,
                 executor: self.subscribe_call.clone(),
                 cancellation_channel: self.cancellation_channel.clone(),
             }),
@@ -133,3 +159,76 @@ impl Debug for SubscribeEffectHandler {
         write!(f, "SubscribeEffectHandler {{}}")
     }
 }
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::{
+        core::{event_engine::Effect, PubNubError, TransportResponse},
+        dx::subscribe::{
+            event_engine::{SubscribeEvent, SubscriptionInput},
+            result::SubscribeResult,
+        },
+        lib::alloc::{boxed::Box, vec},
+    };
+    use futures::FutureExt;
+
+    fn handler(
+        retry_policy_override: Arc<RwLock<Option<RequestRetryConfiguration>>>,
+    ) -> SubscribeEffectHandler {
+        let (cancel_tx, _) = async_channel::bounded(1);
+
+        SubscribeEffectHandler::new(
+            Arc::new(|_| {
+                async move {
+                    Ok(SubscribeResult {
+                        cursor: Default::default(),
+                        messages: vec![],
+                    })
+                }
+                .boxed()
+            }),
+            Arc::new(|_| {}),
+            Arc::new(Box::new(|_, _| {})),
+            RequestRetryConfiguration::None,
+            retry_policy_override,
+            None,
+            cancel_tx,
+        )
+    }
+
+    fn handshake_reconnect() -> SubscribeEffectInvocation {
+        SubscribeEffectInvocation::HandshakeReconnect {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: None,
+            attempts: 1,
+            reason: PubNubError::Transport {
+                details: "test".into(),
+                status: Some(500),
+                response: Some(Box::new(TransportResponse {
+                    status: 500,
+                    ..Default::default()
+                })),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn use_retry_policy_override_in_reconnection_effect() {
+        let retry_policy_override = Arc::new(RwLock::new(None));
+        let handler = handler(retry_policy_override.clone());
+
+        let events = handler.create(&handshake_reconnect()).unwrap().run().await;
+        assert!(matches!(
+            events.as_slice(),
+            [SubscribeEvent::HandshakeReconnectGiveUp { .. }]
+        ));
+
+        *retry_policy_override.write() = Some(RequestRetryConfiguration::Fixed { max_retry: 1 });
+        let events = handler.create(&handshake_reconnect()).unwrap().run().await;
+        assert!(matches!(
+            events.as_slice(),
+            [SubscribeEvent::HandshakeReconnectSuccess { .. }]
+        ));
+    }
+}
@@ -25,6 +25,9 @@ pub(super) async fn execute(
 mod should {
     use super::*;
     use crate::dx::subscribe::types::Message;
+    use spin::RwLock;
+
+    type EmittedUpdates = Option<(Vec<Update>, SubscriptionCursor)>;
 
     #[tokio::test]
     async fn emit_expected_status() {
@@ -38,21 +41,33 @@ mod should {
             space_id: None,
             decryption_error: None,
         };
+        let cursor = SubscriptionCursor {
+            timetoken: "1234567890".into(),
+            region: 1,
+        };
+        let emitted: Arc<RwLock<EmittedUpdates>> = Default::default();
+        let emitted_clone = emitted.clone();
 
-        let emit_message_function: Arc<EmitMessagesEffectExecutor> = Arc::new(|updates, _| {
-            let emitted_update = updates.first().expect("update should be passed");
-            assert!(matches!(emitted_update, Update::Message(_)));
-
-            if let Update::Message(message) = emitted_update {
-                assert_eq!(*message, message.clone());
-            }
-        });
+        let emit_message_function: Arc<EmitMessagesEffectExecutor> =
+            Arc::new(move |updates, cursor| {
+                *emitted_clone.write() = Some((updates, cursor));
+            });
 
-        execute(
-            Default::default(),
-            vec![Update::Message(message.clone())],
+        let events = execute(
+            cursor.clone(),
+            vec![
+                Update::Message(message.clone()),
+                Update::Signal(message.clone()),
+            ],
             &emit_message_function,
         )
         .await;
+
+        assert!(events.is_empty());
+        let (updates, emitted_cursor) = emitted.write().take().expect("updates should be emitted");
+        assert_eq!(emitted_cursor, cursor);
+        assert_eq!(updates.len(), 2);
+        assert!(matches!(&updates[0], Update::Message(emitted) if *emitted == message));
+        assert!(matches!(&updates[1], Update::Signal(emitted) if *emitted == message));
     }
 }
@@ -21,13 +21,20 @@ pub(super) async fn execute(
 #[cfg(test)]
 mod should {
     use super::*;
+    use spin::RwLock;
 
     #[tokio::test]
     async fn emit_expected_status() {
-        let emit_status_function: Arc<EmitStatusEffectExecutor> = Arc::new(|status| {
-            assert!(matches!(status, ConnectionStatus::Connected));
+        let emitted: Arc<RwLock<Vec<ConnectionStatus>>> = Default::default();
+        let emitted_clone = emitted.clone();
+        let emit_status_function: Arc<EmitStatusEffectExecutor> = Arc::new(move |status| {
+            emitted_clone.write().push(status);
         });
 
-        execute(ConnectionStatus::Connected, &emit_status_function).await;
+        let events = execute(ConnectionStatus::Connected, &emit_status_function).await;
+
+        assert!(events.is_empty());
+        assert_eq!(emitted.read().len(), 1);
+        assert!(matches!(emitted.read()[0], ConnectionStatus::Connected));
     }
 }
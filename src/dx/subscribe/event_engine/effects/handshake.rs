@@ -108,6 +108,7 @@ mod should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: None,
                     response: None,
                 })
             }
@@ -87,6 +87,7 @@ mod should {
                 params.reason.unwrap(),
                 PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -113,6 +114,7 @@ mod should {
             1,
             PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -123,6 +125,7 @@ mod should {
                 delay: 0,
                 max_retry: 1,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mock_handshake_function,
         )
@@ -141,6 +144,7 @@ mod should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -159,6 +163,7 @@ mod should {
             11,
             PubNubError::Transport {
                 details: "test".into(),
+                status: None,
                 response: None,
             },
             "id",
@@ -166,6 +171,7 @@ mod should {
                 max_retry: 10,
                 delay: 0,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mock_handshake_function,
         )
@@ -192,6 +198,7 @@ mod should {
             1,
             PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
@@ -202,6 +209,7 @@ mod should {
                 delay: 0,
                 max_retry: 1,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mock_handshake_function,
         )
@@ -216,6 +224,7 @@ mod should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -234,6 +243,7 @@ mod should {
             1,
             PubNubError::Transport {
                 details: "test".into(),
+                status: None,
                 response: None,
             },
             "id",
@@ -16,7 +16,10 @@ use crate::{
     },
     lib::{
         alloc::{string::String, sync::Arc, vec::Vec},
-        core::fmt::{Debug, Formatter},
+        core::{
+            fmt::{Debug, Formatter},
+            num::NonZeroUsize,
+        },
     },
 };
 
@@ -154,6 +157,12 @@ pub(crate) enum SubscribeEffect {
         /// which updates will be delivered.
         cursor: SubscriptionCursor,
 
+        /// Maximum number of updates delivered by single receive call.
+        ///
+        /// Updates which exceed limit will be received with next receive
+        /// call.
+        max_messages: Option<NonZeroUsize>,
+
         /// Executor function.
         ///
         /// Function which will be used to execute receive updates.
@@ -196,6 +205,12 @@ pub(crate) enum SubscribeEffect {
         /// Retry policy.
         retry_policy: RequestRetryConfiguration,
 
+        /// Maximum number of updates delivered by single receive call.
+        ///
+        /// Updates which exceed limit will be received with next receive
+        /// call.
+        max_messages: Option<NonZeroUsize>,
+
         /// Executor function.
         ///
         /// Function which will be used to execute receive updates.
@@ -357,9 +372,10 @@ impl Effect for SubscribeEffect {
                 id,
                 input,
                 cursor,
+                max_messages,
                 executor,
                 ..
-            } => receive::execute(input, cursor, id, executor).await,
+            } => receive::execute(input, cursor, *max_messages, id, executor).await,
             Self::ReceiveReconnect {
                 id,
                 input,
@@ -367,6 +383,7 @@ impl Effect for SubscribeEffect {
                 attempts,
                 reason,
                 retry_policy,
+                max_messages,
                 executor,
                 ..
             } => {
@@ -376,6 +393,7 @@ impl Effect for SubscribeEffect {
                     *attempts,
                     reason.clone(), /* TODO: Does run function need to borrow self? Or we can
                                      * consume it? */
+                    *max_messages,
                     id,
                     retry_policy,
                     executor,
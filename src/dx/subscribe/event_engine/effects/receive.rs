@@ -8,14 +8,19 @@ use crate::{
             effects::SubscribeEffectExecutor, types::SubscriptionParams, SubscribeEvent,
             SubscriptionInput,
         },
+        result::Update,
         SubscriptionCursor,
     },
-    lib::alloc::{sync::Arc, vec, vec::Vec},
+    lib::{
+        alloc::{string::ToString, sync::Arc, vec, vec::Vec},
+        core::num::NonZeroUsize,
+    },
 };
 
 pub(crate) async fn execute(
     input: &SubscriptionInput,
     cursor: &SubscriptionCursor,
+    max_messages: Option<NonZeroUsize>,
     effect_id: &str,
     executor: &Arc<SubscribeEffectExecutor>,
 ) -> Vec<SubscribeEvent> {
@@ -47,19 +52,60 @@ pub(crate) async fn execute(
                 .unwrap_or(vec![])
         },
         |subscribe_result| {
-            vec![SubscribeEvent::ReceiveSuccess {
-                cursor: subscribe_result.cursor,
-                messages: subscribe_result.messages,
-            }]
+            let (cursor, messages) = limit_updates(
+                subscribe_result.cursor,
+                subscribe_result.messages,
+                max_messages,
+            );
+            vec![SubscribeEvent::ReceiveSuccess { cursor, messages }]
         },
     )
     .await
 }
 
+/// Limit number of received updates.
+///
+/// When the number of received updates exceeds `max_messages`, the list is
+/// truncated after the last update (within limit) which has timetoken and the
+/// cursor is moved to it, so the next receive call will continue from it.
+/// Updates are delivered at once if none of them can be used as a cursor.
+pub(super) fn limit_updates(
+    cursor: SubscriptionCursor,
+    mut messages: Vec<Update>,
+    max_messages: Option<NonZeroUsize>,
+) -> (SubscriptionCursor, Vec<Update>) {
+    let Some(max_messages) = max_messages.filter(|max| messages.len() > max.get()) else {
+        return (cursor, messages);
+    };
+
+    // Without timetoken it is impossible to continue from last delivered update.
+    let Some((index, timetoken)) = messages[..max_messages.get()]
+        .iter()
+        .map(|update| update.event_timestamp())
+        .enumerate()
+        .rfind(|(_, timetoken)| *timetoken > 0)
+    else {
+        return (cursor, messages);
+    };
+
+    messages.truncate(index + 1);
+
+    (
+        SubscriptionCursor {
+            timetoken: timetoken.to_string(),
+            region: cursor.region,
+        },
+        messages,
+    )
+}
+
 #[cfg(test)]
 mod should {
     use super::*;
-    use crate::{core::PubNubError, dx::subscribe::result::SubscribeResult};
+    use crate::{
+        core::PubNubError,
+        dx::subscribe::{result::SubscribeResult, types::Message},
+    };
     use futures::FutureExt;
 
     #[tokio::test]
@@ -87,6 +133,7 @@ mod should {
                 &Some(vec!["cg1".to_string()]),
             ),
             &Default::default(),
+            None,
             "id",
             &mock_receive_function,
         )
@@ -99,12 +146,184 @@ mod should {
         ));
     }
 
+    #[tokio::test]
+    async fn pass_received_cursor_and_messages() {
+        let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
+            async move {
+                Ok(SubscribeResult {
+                    cursor: SubscriptionCursor {
+                        timetoken: "20".into(),
+                        region: 2,
+                    },
+                    messages: vec![Update::Message(Message {
+                        channel: "ch1".into(),
+                        subscription: "ch1".into(),
+                        timestamp: 20,
+                        ..Default::default()
+                    })],
+                })
+            }
+            .boxed()
+        });
+
+        let result = execute(
+            &SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            &SubscriptionCursor {
+                timetoken: "10".into(),
+                region: 1,
+            },
+            None,
+            "id",
+            &mock_receive_function,
+        )
+        .await;
+
+        let Some(SubscribeEvent::ReceiveSuccess { cursor, messages }) = result.first() else {
+            panic!("Expected receive success event");
+        };
+        assert_eq!(cursor.timetoken, "20");
+        assert_eq!(cursor.region, 2);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].channel(), "ch1");
+    }
+
+    #[tokio::test]
+    async fn receive_backlog_in_batches() {
+        let backlog: Vec<Update> = (1..=500)
+            .map(|timetoken| {
+                Update::Message(Message {
+                    channel: "ch1".into(),
+                    subscription: "ch1".into(),
+                    timestamp: timetoken,
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |params| {
+            let timetoken = params
+                .cursor
+                .and_then(|cursor| cursor.timetoken.parse::<usize>().ok())
+                .unwrap_or(0);
+            let messages = backlog
+                .iter()
+                .filter(|update| update.event_timestamp() > timetoken)
+                .cloned()
+                .collect();
+
+            async move {
+                Ok(SubscribeResult {
+                    cursor: SubscriptionCursor {
+                        timetoken: "500".into(),
+                        region: 1,
+                    },
+                    messages,
+                })
+            }
+            .boxed()
+        });
+        let input = SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None);
+        let mut cursor = SubscriptionCursor {
+            timetoken: "0".into(),
+            region: 1,
+        };
+        let mut cycles = 0;
+
+        loop {
+            let result = execute(
+                &input,
+                &cursor,
+                NonZeroUsize::new(100),
+                "id",
+                &mock_receive_function,
+            )
+            .await;
+            let Some(SubscribeEvent::ReceiveSuccess {
+                cursor: next_cursor,
+                messages,
+            }) = result.first()
+            else {
+                panic!("Expected receive success event");
+            };
+
+            if messages.is_empty() {
+                break;
+            }
+
+            cycles += 1;
+            assert_eq!(messages.len(), 100);
+            assert_eq!(next_cursor.timetoken, (cycles * 100).to_string());
+            assert_eq!(
+                messages.last().map(|update| update.event_timestamp()),
+                Some(cycles * 100)
+            );
+            cursor = next_cursor.clone();
+        }
+
+        assert_eq!(cycles, 5);
+    }
+
+    #[test]
+    fn move_cursor_to_last_delivered_update_with_timetoken() {
+        let updates: Vec<Update> = [10, 0, 30]
+            .into_iter()
+            .map(|timestamp| {
+                Update::Message(Message {
+                    channel: "ch1".into(),
+                    subscription: "ch1".into(),
+                    timestamp,
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let server_cursor = SubscriptionCursor {
+            timetoken: "30".into(),
+            region: 1,
+        };
+
+        let (cursor, messages) =
+            limit_updates(server_cursor.clone(), updates.clone(), NonZeroUsize::new(2));
+        assert_eq!(cursor.timetoken, "10");
+        assert_eq!(messages.len(), 1);
+
+        // Updates without timetoken can't be used to continue, so everything
+        // delivered at once.
+        let (cursor, messages) = limit_updates(
+            server_cursor,
+            vec![updates[1].clone(); 3],
+            NonZeroUsize::new(2),
+        );
+        assert_eq!(cursor.timetoken, "30");
+        assert_eq!(messages.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn return_unsubscribe_all_event_on_empty_input() {
+        let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
+            panic!("Receive shouldn't be called for empty input");
+        });
+
+        let result = execute(
+            &SubscriptionInput::new(&None, &None),
+            &Default::default(),
+            None,
+            "id",
+            &mock_receive_function,
+        )
+        .await;
+
+        assert!(matches!(
+            result.first(),
+            Some(SubscribeEvent::UnsubscribeAll)
+        ));
+    }
+
     #[tokio::test]
     async fn return_receive_failure_event_on_err() {
         let mock_receive_function: Arc<SubscribeEffectExecutor> = Arc::new(move |_| {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: None,
                     response: None,
                 })
             }
@@ -117,6 +336,7 @@ mod should {
                 &Some(vec!["cg1".to_string()]),
             ),
             &Default::default(),
+            None,
             "id",
             &mock_receive_function,
         )
@@ -140,6 +360,7 @@ mod should {
                 &Some(vec!["cg1".to_string()]),
             ),
             &Default::default(),
+            None,
             "id",
             &mock_receive_function,
         )
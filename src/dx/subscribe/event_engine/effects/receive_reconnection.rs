@@ -5,12 +5,16 @@ use crate::{
     core::{PubNubError, RequestRetryConfiguration},
     dx::subscribe::{
         event_engine::{
-            effects::SubscribeEffectExecutor, types::SubscriptionParams, SubscribeEvent,
-            SubscriptionInput,
+            effects::{receive::limit_updates, SubscribeEffectExecutor},
+            types::SubscriptionParams,
+            SubscribeEvent, SubscriptionInput,
         },
         SubscriptionCursor,
     },
-    lib::alloc::{sync::Arc, vec, vec::Vec},
+    lib::{
+        alloc::{sync::Arc, vec, vec::Vec},
+        core::num::NonZeroUsize,
+    },
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -19,6 +23,7 @@ pub(crate) async fn execute(
     cursor: &SubscriptionCursor,
     attempt: u8,
     reason: PubNubError,
+    max_messages: Option<NonZeroUsize>,
     effect_id: &str,
     retry_policy: &RequestRetryConfiguration,
     executor: &Arc<SubscribeEffectExecutor>,
@@ -57,10 +62,12 @@ pub(crate) async fn execute(
                 .unwrap_or(vec![])
         },
         |subscribe_result| {
-            vec![SubscribeEvent::ReceiveReconnectSuccess {
-                cursor: subscribe_result.cursor,
-                messages: subscribe_result.messages,
-            }]
+            let (cursor, messages) = limit_updates(
+                subscribe_result.cursor,
+                subscribe_result.messages,
+                max_messages,
+            );
+            vec![SubscribeEvent::ReceiveReconnectSuccess { cursor, messages }]
         },
     )
     .await
@@ -86,6 +93,7 @@ mod should {
                 params.reason,
                 Some(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -113,16 +121,19 @@ mod should {
             10,
             PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
                 })),
             },
+            None,
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 20,
                 delay: 0,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mock_receive_function,
         )
@@ -141,6 +152,7 @@ mod should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -159,16 +171,19 @@ mod should {
             5,
             PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
                 })),
             },
+            None,
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 10,
                 delay: 0,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mock_receive_function,
         )
@@ -187,6 +202,7 @@ mod should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -205,16 +221,19 @@ mod should {
             10,
             PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
                 })),
             },
+            None,
             "id",
             &RequestRetryConfiguration::Linear {
                 delay: 0,
                 max_retry: 1,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mock_receive_function,
         )
@@ -241,16 +260,19 @@ mod should {
             10,
             PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
                 })),
             },
+            None,
             "id",
             &RequestRetryConfiguration::Linear {
                 max_retry: 20,
                 delay: 0,
                 excluded_endpoints: None,
+                max_elapsed: None,
             },
             &mock_receive_function,
         )
@@ -265,6 +287,7 @@ mod should {
             async move {
                 Err(PubNubError::Transport {
                     details: "test".into(),
+                    status: Some(500),
                     response: Some(Box::new(TransportResponse {
                         status: 500,
                         ..Default::default()
@@ -283,11 +306,13 @@ mod should {
             10,
             PubNubError::Transport {
                 details: "test".into(),
+                status: Some(500),
                 response: Some(Box::new(TransportResponse {
                     status: 500,
                     ..Default::default()
                 })),
             },
+            None,
             "id",
             &RequestRetryConfiguration::None,
             &mock_receive_function,
@@ -1,6 +1,6 @@
 //! Subscribe Event Engine module
 
-use crate::core::event_engine::EventEngine;
+use crate::{core::event_engine::EventEngine, dx::subscribe::SubscriptionCursor};
 
 #[doc(inline)]
 pub(crate) use effects::SubscribeEffect;
@@ -28,3 +28,15 @@ pub(in crate::dx::subscribe) mod types;
 
 pub(crate) type SubscribeEventEngine =
     EventEngine<SubscribeState, SubscribeEffectHandler, SubscribeEffect, SubscribeEffectInvocation>;
+
+impl SubscribeEventEngine {
+    /// Time cursor used by subscription loop.
+    ///
+    /// # Returns
+    ///
+    /// Last known time cursor or `None` if subscription loop not started yet
+    /// or doesn't use custom cursor for handshake.
+    pub(crate) fn current_cursor(&self) -> Option<SubscriptionCursor> {
+        self.current_state().current_cursor()
+    }
+}
@@ -195,12 +195,42 @@ pub(crate) enum SubscribeState {
 }
 
 impl SubscribeState {
+    /// Time cursor used by subscription loop.
+    ///
+    /// # Returns
+    ///
+    /// Time cursor stored in the state or `None` for the `Unsubscribed` state
+    /// and handshake states without custom cursor.
+    pub(crate) fn current_cursor(&self) -> Option<SubscriptionCursor> {
+        match self {
+            Self::Handshaking { cursor, .. }
+            | Self::HandshakeReconnecting { cursor, .. }
+            | Self::HandshakeStopped { cursor, .. }
+            | Self::HandshakeFailed { cursor, .. } => cursor.clone(),
+            Self::Receiving { cursor, .. }
+            | Self::ReceiveReconnecting { cursor, .. }
+            | Self::ReceiveStopped { cursor, .. }
+            | Self::ReceiveFailed { cursor, .. } => Some(cursor.clone()),
+            Self::Unsubscribed => None,
+        }
+    }
+
     /// Handle channels / groups list change event.
+    ///
+    /// State machine returns to `Unsubscribed` state if all channels and groups
+    /// has been removed.
     fn subscription_changed_transition(
         &self,
         channels: &Option<Vec<String>>,
         channel_groups: &Option<Vec<String>>,
     ) -> Option<Transition<Self, SubscribeEffectInvocation>> {
+        if SubscriptionInput::new(channels, channel_groups).is_empty {
+            return match self {
+                Self::Unsubscribed => None,
+                _ => self.unsubscribe_all_transition(),
+            };
+        }
+
         match self {
             Self::Unsubscribed => Some(self.transition_to(
                 Some(Self::Handshaking {
@@ -318,7 +348,8 @@ impl SubscribeState {
     /// Handle initial (reconnect) handshake success event.
     ///
     /// Event is sent when provided set of channels and groups has been used for
-    /// first time.
+    /// first time. `Reconnected` status emitted if handshake succeeded after
+    /// reconnection attempts and `Connected` otherwise.
     fn handshake_success_transition(
         &self,
         next_cursor: &SubscriptionCursor,
@@ -332,12 +363,18 @@ impl SubscribeState {
                     next_cursor.timetoken = cursor.timetoken.clone();
                 }
 
+                // Let listeners distinguish recovery from the initial connection.
+                let status = match self {
+                    Self::HandshakeReconnecting { .. } => ConnectionStatus::Reconnected,
+                    _ => ConnectionStatus::Connected,
+                };
+
                 Some(self.transition_to(
                     Some(Self::Receiving {
                         input: input.clone(),
                         cursor: next_cursor,
                     }),
-                    Some(vec![EmitStatus(ConnectionStatus::Connected)]),
+                    Some(vec![EmitStatus(status)]),
                 ))
             }
             _ => None,
@@ -436,12 +473,20 @@ impl SubscribeState {
     ) -> Option<Transition<Self, SubscribeEffectInvocation>> {
         match self {
             Self::Receiving { input, .. } | Self::ReceiveReconnecting { input, .. } => {
+                let mut invocations = vec![];
+
+                // Let listeners know that real-time updates receive recovered.
+                if matches!(self, Self::ReceiveReconnecting { .. }) {
+                    invocations.push(EmitStatus(ConnectionStatus::Reconnected));
+                }
+                invocations.push(EmitMessages(messages.to_vec(), cursor.clone()));
+
                 Some(self.transition_to(
                     Some(Self::Receiving {
                         input: input.clone(),
                         cursor: cursor.clone(),
                     }),
-                    Some(vec![EmitMessages(messages.to_vec(), cursor.clone())]),
+                    Some(invocations),
                 ))
             }
             _ => None,
@@ -721,7 +766,6 @@ impl State for SubscribeState {
 
 #[cfg(test)]
 mod should {
-    // TODO: EE process tests should be async!
     use futures::FutureExt;
     use test_case::test_case;
 
@@ -772,6 +816,8 @@ mod should {
                 emit_status,
                 emit_message,
                 RequestRetryConfiguration::None,
+                Default::default(),
+                None,
                 tx,
             ),
             start_state,
@@ -779,6 +825,71 @@ mod should {
         )
     }
 
+    #[test_case(
+        SubscribeState::Receiving {
+            input: SubscriptionInput::new(
+                &Some(vec!["ch1".to_string(), "ch2".to_string()]),
+                &Some(vec!["gr1".to_string()])
+            ),
+            cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
+        },
+        SubscribeEvent::SubscriptionChanged {
+            channels: Some(vec!["ch1".to_string()]),
+            channel_groups: None,
+        },
+        SubscribeState::Receiving {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
+        };
+        "to receiving with remaining channels on partial unsubscribe"
+    )]
+    #[test_case(
+        SubscribeState::Receiving {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
+        },
+        SubscribeEvent::SubscriptionChanged {
+            channels: None,
+            channel_groups: None,
+        },
+        SubscribeState::Unsubscribed;
+        "to unsubscribed when all channels removed while receiving"
+    )]
+    #[test_case(
+        SubscribeState::Handshaking {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: None,
+        },
+        SubscribeEvent::SubscriptionChanged {
+            channels: Some(vec![]),
+            channel_groups: None,
+        },
+        SubscribeState::Unsubscribed;
+        "to unsubscribed when all channels removed while handshaking"
+    )]
+    #[test_case(
+        SubscribeState::Unsubscribed,
+        SubscribeEvent::SubscriptionChanged {
+            channels: None,
+            channel_groups: None,
+        },
+        SubscribeState::Unsubscribed;
+        "to not change on empty subscription change"
+    )]
+    #[tokio::test]
+    async fn transition_on_partial_unsubscribe(
+        init_state: SubscribeState,
+        event: SubscribeEvent,
+        target_state: SubscribeState,
+    ) {
+        let engine = event_engine(init_state.clone());
+        assert_eq!(engine.current_state(), init_state);
+
+        engine.process_async(&event).await;
+
+        assert_eq!(engine.current_state(), target_state);
+    }
+
     #[test_case(
         SubscribeState::Unsubscribed,
         SubscribeEvent::SubscriptionChanged {
@@ -813,7 +924,7 @@ mod should {
     #[test_case(
         SubscribeState::Unsubscribed,
         SubscribeEvent::ReceiveFailure {
-            reason: PubNubError::Transport { details: "Test".to_string(), response: None }
+            reason: PubNubError::Transport { details: "Test".to_string(), status: None, response: None }
         },
         SubscribeState::Unsubscribed;
         "to not change on unexpected event"
@@ -828,7 +939,7 @@ mod should {
         assert_eq!(engine.current_state(), init_state);
 
         // Process event.
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -884,7 +995,7 @@ mod should {
             cursor: None,
         },
         SubscribeEvent::HandshakeFailure {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -893,7 +1004,7 @@ mod should {
             ),
             cursor: None,
             attempts:  1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to handshake reconnect on handshake failure"
     )]
@@ -906,7 +1017,7 @@ mod should {
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
         },
         SubscribeEvent::HandshakeFailure {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -915,7 +1026,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts:  1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to handshake reconnect with custom cursor on handshake failure"
     )]
@@ -1048,7 +1159,7 @@ mod should {
             cursor: None,
         },
         SubscribeEvent::HandshakeReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, }
         },
         SubscribeState::Handshaking {
             input: SubscriptionInput::new(
@@ -1068,7 +1179,7 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -1081,10 +1192,10 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::HandshakeReconnectFailure {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -1093,7 +1204,7 @@ mod should {
             ),
             cursor: None,
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         };
         "to handshake reconnecting on reconnect failure"
     )]
@@ -1105,10 +1216,10 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::HandshakeReconnectFailure {
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         },
         SubscribeState::HandshakeReconnecting {
             input: SubscriptionInput::new(
@@ -1117,7 +1228,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reason on error".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason on error".to_string(), status: None, response: None, },
         };
         "to handshake reconnecting with custom cursor on reconnect failure"
     )]
@@ -1129,7 +1240,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1152,7 +1263,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1175,7 +1286,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::Disconnect,
         SubscribeState::HandshakeStopped {
@@ -1195,7 +1306,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::Disconnect,
         SubscribeState::HandshakeStopped {
@@ -1215,10 +1326,10 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::HandshakeReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test give up reason".to_string(), status: None, response: None, }
         },
         SubscribeState::HandshakeFailed {
             input: SubscriptionInput::new(
@@ -1226,7 +1337,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test give up reason".to_string(), status: None, response: None, }
         };
         "to handshake failed on give up"
     )]
@@ -1238,10 +1349,10 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::HandshakeReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test give up reason".to_string(), status: None, response: None, }
         },
         SubscribeState::HandshakeFailed {
             input: SubscriptionInput::new(
@@ -1249,7 +1360,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test give up reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test give up reason".to_string(), status: None, response: None, }
         };
         "to handshake failed with custom cursor on give up"
     )]
@@ -1261,7 +1372,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::HandshakeReconnectSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 }
@@ -1283,7 +1394,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::HandshakeReconnectSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 2 }
@@ -1305,7 +1416,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1329,7 +1440,7 @@ mod should {
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1353,7 +1464,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::ReceiveSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
@@ -1366,7 +1477,7 @@ mod should {
             ),
             cursor: None,
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on unexpected event"
     )]
@@ -1379,11 +1490,300 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
 
+    #[test_case(
+        SubscribeState::Unsubscribed,
+        None;
+        "no cursor for unsubscribed state"
+    )]
+    #[test_case(
+        SubscribeState::Handshaking {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: None,
+        },
+        None;
+        "no cursor for handshaking state without custom cursor"
+    )]
+    #[test_case(
+        SubscribeState::HandshakeStopped {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: Some(SubscriptionCursor { timetoken: "10".into(), region: 1 }),
+        },
+        Some(SubscriptionCursor { timetoken: "10".into(), region: 1 });
+        "custom cursor for handshake stopped state"
+    )]
+    #[test_case(
+        SubscribeState::ReceiveFailed {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: SubscriptionCursor { timetoken: "20".into(), region: 1 },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        Some(SubscriptionCursor { timetoken: "20".into(), region: 1 });
+        "cursor for receive failed state"
+    )]
+    fn provide_current_cursor(state: SubscribeState, expected_cursor: Option<SubscriptionCursor>) {
+        assert_eq!(state.current_cursor(), expected_cursor);
+    }
+
+    #[tokio::test]
+    async fn provide_current_cursor_when_receiving() {
+        let engine = event_engine(SubscribeState::Handshaking {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: None,
+        });
+        assert!(engine.current_cursor().is_none());
+
+        engine
+            .process_async(&SubscribeEvent::HandshakeSuccess {
+                cursor: SubscriptionCursor {
+                    timetoken: "100".into(),
+                    region: 1,
+                },
+            })
+            .await;
+
+        assert!(matches!(
+            engine.current_state(),
+            SubscribeState::Receiving { .. }
+        ));
+        assert_eq!(
+            engine.current_cursor(),
+            Some(SubscriptionCursor {
+                timetoken: "100".into(),
+                region: 1
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn run_effects_in_invocation_order() {
+        let executed = Arc::new(spin::Mutex::new(Vec::<String>::new()));
+        let call_executed = executed.clone();
+        let call: Arc<SubscribeEffectExecutor> = Arc::new(move |params| {
+            call_executed.lock().push(
+                if params.cursor.is_some() {
+                    "receive"
+                } else {
+                    "handshake"
+                }
+                .to_string(),
+            );
+
+            async move {
+                Ok(SubscribeResult {
+                    cursor: SubscriptionCursor {
+                        timetoken: "200".into(),
+                        region: 1,
+                    },
+                    messages: vec![],
+                })
+            }
+            .boxed()
+        });
+        let status_executed = executed.clone();
+        let emit_status: Arc<EmitStatusEffectExecutor> =
+            Arc::new(move |status| status_executed.lock().push(format!("status:{status:?}")));
+        let messages_executed = executed.clone();
+        let emit_message: Arc<EmitMessagesEffectExecutor> =
+            Arc::new(move |_, _| messages_executed.lock().push("messages".to_string()));
+        let (tx, _) = async_channel::bounded(1);
+        let engine = EventEngine::new(
+            SubscribeEffectHandler::new(
+                call,
+                emit_status,
+                emit_message,
+                RequestRetryConfiguration::None,
+                Default::default(),
+                None,
+                tx,
+            ),
+            SubscribeState::ReceiveReconnecting {
+                input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+                cursor: SubscriptionCursor {
+                    timetoken: "10".into(),
+                    region: 1,
+                },
+                attempts: 1,
+                reason: PubNubError::Transport {
+                    details: "Test reason".to_string(),
+                    status: None,
+                    response: None,
+                },
+            },
+            RuntimeTokio,
+        );
+
+        let events = engine
+            .process_async(&SubscribeEvent::ReceiveReconnectSuccess {
+                cursor: SubscriptionCursor {
+                    timetoken: "100".into(),
+                    region: 1,
+                },
+                messages: vec![],
+            })
+            .await;
+
+        assert!(matches!(
+            engine.current_state(),
+            SubscribeState::Receiving { .. }
+        ));
+        assert_eq!(
+            *executed.lock(),
+            vec!["status:Reconnected", "messages", "receive"]
+        );
+        assert!(matches!(
+            events.as_slice(),
+            [SubscribeEvent::ReceiveSuccess { .. }]
+        ));
+    }
+
+    #[tokio::test]
+    async fn emit_connection_error_status_on_handshake_reconnect_give_up() {
+        let reason = PubNubError::Transport {
+            details: "Test give up reason".to_string(),
+            status: Some(500),
+            response: None,
+        };
+        let (status_tx, status_rx) = async_channel::unbounded::<ConnectionStatus>();
+        let call: Arc<SubscribeEffectExecutor> = Arc::new(|_| {
+            async move {
+                Ok(SubscribeResult {
+                    cursor: Default::default(),
+                    messages: vec![],
+                })
+            }
+            .boxed()
+        });
+        let emit_status: Arc<EmitStatusEffectExecutor> = Arc::new(move |status| {
+            status_tx
+                .send_blocking(status)
+                .expect("Status receiver dropped");
+        });
+        let emit_message: Arc<EmitMessagesEffectExecutor> = Arc::new(|_, _| {});
+        let (tx, _) = async_channel::bounded(1);
+        let engine = EventEngine::new(
+            SubscribeEffectHandler::new(
+                call,
+                emit_status,
+                emit_message,
+                RequestRetryConfiguration::None,
+                Default::default(),
+                None,
+                tx,
+            ),
+            SubscribeState::HandshakeReconnecting {
+                input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+                cursor: None,
+                attempts: 1,
+                reason: PubNubError::Transport {
+                    details: "Test reason".to_string(),
+                    status: None,
+                    response: None,
+                },
+            },
+            RuntimeTokio,
+        );
+
+        engine.process(&SubscribeEvent::HandshakeReconnectGiveUp {
+            reason: reason.clone(),
+        });
+
+        let status = tokio::time::timeout(core::time::Duration::from_secs(1), status_rx.recv())
+            .await
+            .expect("Status hasn't been emitted")
+            .unwrap();
+        assert_eq!(status, ConnectionStatus::ConnectionError(reason));
+    }
+
+    #[test_case(
+        SubscribeState::Handshaking {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: None,
+        },
+        SubscribeEvent::HandshakeSuccess {
+            cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 }
+        },
+        ConnectionStatus::Connected;
+        "connected on initial handshake success"
+    )]
+    #[test_case(
+        SubscribeState::HandshakeReconnecting {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: None,
+            attempts: 1,
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        SubscribeEvent::HandshakeReconnectSuccess {
+            cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 }
+        },
+        ConnectionStatus::Reconnected;
+        "reconnected on handshake reconnect success"
+    )]
+    #[tokio::test]
+    async fn emit_status_on_handshake_success(
+        init_state: SubscribeState,
+        event: SubscribeEvent,
+        expected_status: ConnectionStatus,
+    ) {
+        let transition = init_state.transition(&event).unwrap();
+
+        assert!(transition.invocations.iter().any(
+            |invocation| matches!(invocation, EmitStatus(status) if *status == expected_status)
+        ));
+    }
+
+    #[test_case(
+        SubscribeState::Receiving {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
+        },
+        SubscribeEvent::ReceiveSuccess {
+            cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 },
+            messages: vec![]
+        },
+        None;
+        "only messages on receive success"
+    )]
+    #[test_case(
+        SubscribeState::ReceiveReconnecting {
+            input: SubscriptionInput::new(&Some(vec!["ch1".to_string()]), &None),
+            cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
+            attempts: 1,
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
+        },
+        SubscribeEvent::ReceiveReconnectSuccess {
+            cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 },
+            messages: vec![]
+        },
+        Some(ConnectionStatus::Reconnected);
+        "reconnected status and messages on receive reconnect success"
+    )]
+    #[tokio::test]
+    async fn emit_status_and_messages_on_receive_success(
+        init_state: SubscribeState,
+        event: SubscribeEvent,
+        expected_status: Option<ConnectionStatus>,
+    ) {
+        let transition = init_state.transition(&event).unwrap();
+        let mut invocations = transition
+            .invocations
+            .iter()
+            .filter(|invocation| matches!(invocation, EmitStatus(_) | EmitMessages(..)));
+
+        if let Some(expected_status) = expected_status {
+            assert!(matches!(
+                invocations.next(),
+                Some(EmitStatus(status)) if *status == expected_status
+            ));
+        }
+        assert!(matches!(invocations.next(), Some(EmitMessages(..))));
+        assert!(invocations.next().is_none());
+    }
+
     #[test_case(
         SubscribeState::HandshakeFailed {
             input: SubscriptionInput::new(
@@ -1391,7 +1791,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1413,7 +1813,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -1435,7 +1835,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::Reconnect { cursor: None },
         SubscribeState::Handshaking {
@@ -1454,7 +1854,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::Reconnect {
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 })
@@ -1475,7 +1875,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::Reconnect { cursor: None },
         SubscribeState::Handshaking {
@@ -1494,7 +1894,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::Reconnect {
             cursor: Some(SubscriptionCursor { timetoken: "10".into(), region: 2 })
@@ -1515,7 +1915,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1538,7 +1938,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 1 }),
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -1561,7 +1961,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::UnsubscribeAll,
         SubscribeState::Unsubscribed;
@@ -1574,7 +1974,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         },
         SubscribeEvent::ReceiveSuccess {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
@@ -1586,7 +1986,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: None,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, },
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, },
         };
         "to not change on unexpected event"
     )]
@@ -1599,7 +1999,7 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -1808,7 +2208,7 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -1886,7 +2286,7 @@ mod should {
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
         },
         SubscribeEvent::ReceiveFailure {
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, }
         },
         SubscribeState::ReceiveReconnecting {
             input: SubscriptionInput::new(
@@ -1895,7 +2295,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test reason".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test reason".to_string(), status: None, response: None, }
         };
         "to receive reconnecting on receive failure"
     )]
@@ -1958,7 +2358,7 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -1971,10 +2371,10 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::ReceiveReconnectFailure {
-            reason: PubNubError::Transport { details: "Test reconnect error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test reconnect error".to_string(), status: None, response: None, }
         },
         SubscribeState::ReceiveReconnecting {
             input: SubscriptionInput::new(
@@ -1983,7 +2383,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 2,
-            reason: PubNubError::Transport { details: "Test reconnect error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test reconnect error".to_string(), status: None, response: None, }
         };
         "to receive reconnecting on reconnect failure"
     )]
@@ -1995,7 +2395,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::ReceiveReconnectSuccess {
             cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 },
@@ -2018,7 +2418,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -2041,7 +2441,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -2065,7 +2465,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::Disconnect,
         SubscribeState::ReceiveStopped {
@@ -2085,10 +2485,10 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::ReceiveReconnectGiveUp {
-            reason: PubNubError::Transport { details: "Test give up error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test give up error".to_string(), status: None, response: None, }
         },
         SubscribeState::ReceiveFailed {
             input: SubscriptionInput::new(
@@ -2096,7 +2496,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test give up error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test give up error".to_string(), status: None, response: None, }
         };
         "to receive failed on give up"
     )]
@@ -2108,7 +2508,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::UnsubscribeAll,
         SubscribeState::Unsubscribed;
@@ -2122,7 +2522,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::HandshakeSuccess {
             cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 },
@@ -2134,7 +2534,7 @@ mod should {
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
             attempts: 1,
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         };
         "to not change on unexpected event"
     )]
@@ -2147,7 +2547,7 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -2159,7 +2559,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::SubscriptionChanged {
             channels: Some(vec!["ch2".to_string()]),
@@ -2181,7 +2581,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::SubscriptionRestored {
             channels: Some(vec!["ch2".to_string()]),
@@ -2204,7 +2604,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::Reconnect { cursor: None },
         SubscribeState::Handshaking {
@@ -2223,7 +2623,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::Reconnect {
             cursor: Some(SubscriptionCursor { timetoken: "20".into(), region: 3 })
@@ -2244,7 +2644,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::UnsubscribeAll,
         SubscribeState::Unsubscribed;
@@ -2257,7 +2657,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         },
         SubscribeEvent::HandshakeSuccess {
             cursor: SubscriptionCursor { timetoken: "100".into(), region: 1 }
@@ -2268,7 +2668,7 @@ mod should {
                 &Some(vec!["gr1".to_string()])
             ),
             cursor: SubscriptionCursor { timetoken: "10".into(), region: 1 },
-            reason: PubNubError::Transport { details: "Test error".to_string(), response: None, }
+            reason: PubNubError::Transport { details: "Test error".to_string(), status: None, response: None, }
         };
         "to not change on unexpected event"
     )]
@@ -2281,7 +2681,7 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -2408,7 +2808,7 @@ mod should {
         let engine = event_engine(init_state.clone());
         assert_eq!(engine.current_state(), init_state);
 
-        engine.process(&event);
+        engine.process_async(&event).await;
 
         assert_eq!(engine.current_state(), target_state);
     }
@@ -9,7 +9,7 @@
 use crate::{
     core::PubNubError,
     lib::{
-        alloc::collections::HashSet,
+        alloc::{collections::HashSet, string::ToString},
         core::{
             iter::Sum,
             ops::{Add, AddAssign, Sub, SubAssign},
@@ -49,19 +49,15 @@ pub struct SubscriptionInput {
 }
 
 impl SubscriptionInput {
+    /// Create subscription input.
+    ///
+    /// Names normalized: leading / trailing whitespaces trimmed and empty names
+    /// dropped.
     pub fn new(channels: &Option<Vec<String>>, channel_groups: &Option<Vec<String>>) -> Self {
-        let channels = channels.as_ref().map(|channels| {
-            channels.iter().fold(HashSet::new(), |mut acc, channel| {
-                acc.insert(channel.clone());
-                acc
-            })
-        });
-        let channel_groups = channel_groups.as_ref().map(|groups| {
-            groups.iter().fold(HashSet::new(), |mut acc, group| {
-                acc.insert(group.clone());
-                acc
-            })
-        });
+        let channels = channels.as_ref().map(|channels| Self::normalized(channels));
+        let channel_groups = channel_groups
+            .as_ref()
+            .map(|groups| Self::normalized(groups));
 
         let channel_groups_is_empty = channel_groups.as_ref().map_or(true, |set| set.is_empty());
         let channels_is_empty = channels.as_ref().map_or(true, |set| set.is_empty());
@@ -97,6 +93,23 @@ impl SubscriptionInput {
             .map_or(false, |channels| channels.contains(channel))
     }
 
+    /// Check whether `channel` matches any of subscribed channels.
+    ///
+    /// Besides exact match, channel which ends with `.*` treated as wildcard
+    /// and matches any channel with same prefix (`news.*` matches
+    /// `news.sport`).
+    pub fn contains_matching(&self, channel: &str) -> bool {
+        self.contains_channel(channel)
+            || self.channels.iter().flatten().any(|name| {
+                matches!(
+                    name.strip_suffix('*'),
+                    Some(prefix) if prefix.ends_with('.')
+                        && channel.len() > prefix.len()
+                        && channel.starts_with(prefix)
+                )
+            })
+    }
+
     pub fn channel_groups(&self) -> Option<Vec<String>> {
         self.channel_groups
             .clone()
@@ -111,6 +124,43 @@ impl SubscriptionInput {
             })
     }
 
+    /// Channels and groups which are present in both inputs.
+    ///
+    /// # Returns
+    ///
+    /// Returns [`SubscriptionInput`] with common channels and groups. Input is
+    /// empty if there is nothing in common.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let channel_groups = self.intersect_sets(&self.channel_groups, &other.channel_groups);
+        let channels = self.intersect_sets(&self.channels, &other.channels);
+        let channel_groups_is_empty = channel_groups.as_ref().map_or(true, |set| set.is_empty());
+        let channels_is_empty = channels.as_ref().map_or(true, |set| set.is_empty());
+
+        Self {
+            channels,
+            channel_groups,
+            is_empty: channel_groups_is_empty && channels_is_empty,
+        }
+    }
+
+    /// Check whether all channels and groups are present in `other` input.
+    ///
+    /// Missing list (`None`) treated same as empty list, so empty input is
+    /// subset of any other input.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.is_subset_of_set(&self.channels, &other.channels)
+            && self.is_subset_of_set(&self.channel_groups, &other.channel_groups)
+    }
+
+    fn normalized(names: &[String]) -> HashSet<String> {
+        names
+            .iter()
+            .map(|name| name.trim())
+            .filter(|name| !name.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
+
     fn join_sets(
         &self,
         lhs: &Option<HashSet<String>>,
@@ -135,6 +185,31 @@ impl SubscriptionInput {
             _ => None,
         }
     }
+
+    fn intersect_sets(
+        &self,
+        lhs: &Option<HashSet<String>>,
+        rhs: &Option<HashSet<String>>,
+    ) -> Option<HashSet<String>> {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => {
+                Some(lhs & rhs).filter(|intersection: &HashSet<String>| !intersection.is_empty())
+            }
+            _ => None,
+        }
+    }
+
+    fn is_subset_of_set(
+        &self,
+        lhs: &Option<HashSet<String>>,
+        rhs: &Option<HashSet<String>>,
+    ) -> bool {
+        match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => lhs.is_subset(rhs),
+            (Some(lhs), None) => lhs.is_empty(),
+            (None, _) => true,
+        }
+    }
 }
 
 impl Add for SubscriptionInput {
@@ -752,4 +827,134 @@ mod it_should {
         assert!(diff_input.channels().is_none());
         assert!(diff_input.channel_groups().is_none());
     }
+
+    #[test]
+    fn intersect_disjoint_inputs() {
+        let input = SubscriptionInput::new(
+            &Some(vec!["channel-1".into()]),
+            &Some(vec!["channel-group-1".into()]),
+        );
+        let other_input = SubscriptionInput::new(
+            &Some(vec!["channel-2".into()]),
+            &Some(vec!["channel-group-2".into()]),
+        );
+
+        let intersection = input.intersection(&other_input);
+
+        assert!(intersection.is_empty);
+        assert!(intersection.channels().is_none());
+        assert!(intersection.channel_groups().is_none());
+    }
+
+    #[test]
+    fn intersect_overlapping_inputs() {
+        let input = SubscriptionInput::new(
+            &Some(vec!["channel-1".into(), "channel-2".into()]),
+            &Some(vec!["channel-group-1".into()]),
+        );
+        let other_input = SubscriptionInput::new(
+            &Some(vec!["channel-2".into(), "channel-3".into()]),
+            &Some(vec!["channel-group-2".into()]),
+        );
+
+        let intersection = input.intersection(&other_input);
+
+        assert!(!intersection.is_empty);
+        assert_eq!(intersection.channels(), Some(vec!["channel-2".into()]));
+        assert!(intersection.channel_groups().is_none());
+    }
+
+    #[test]
+    fn intersect_with_missing_and_empty_lists() {
+        let input = SubscriptionInput::new(&Some(vec!["channel-1".into()]), &Some(vec![]));
+        let other_input = SubscriptionInput::new(&None, &Some(vec!["channel-group-1".into()]));
+
+        let intersection = input.intersection(&other_input);
+
+        assert!(intersection.is_empty);
+        assert!(intersection.channels().is_none());
+        assert!(intersection.channel_groups().is_none());
+    }
+
+    #[test]
+    fn check_subset_input() {
+        let input = SubscriptionInput::new(
+            &Some(vec!["channel-1".into()]),
+            &Some(vec!["channel-group-1".into()]),
+        );
+        let other_input = SubscriptionInput::new(
+            &Some(vec!["channel-1".into(), "channel-2".into()]),
+            &Some(vec!["channel-group-1".into()]),
+        );
+
+        assert!(input.is_subset_of(&other_input));
+        assert!(!other_input.is_subset_of(&input));
+        assert!(input.is_subset_of(&input));
+    }
+
+    #[test]
+    fn check_subset_for_disjoint_inputs() {
+        let input = SubscriptionInput::new(&Some(vec!["channel-1".into()]), &None);
+        let other_input = SubscriptionInput::new(&None, &Some(vec!["channel-group-1".into()]));
+
+        assert!(!input.is_subset_of(&other_input));
+        assert!(!other_input.is_subset_of(&input));
+    }
+
+    #[test]
+    fn check_subset_with_missing_and_empty_lists() {
+        let empty_input = SubscriptionInput::new(&None, &None);
+        let input_with_empty_lists = SubscriptionInput::new(&Some(vec![]), &Some(vec![]));
+        let input = SubscriptionInput::new(&Some(vec!["channel-1".into()]), &None);
+
+        assert!(empty_input.is_subset_of(&input));
+        assert!(input_with_empty_lists.is_subset_of(&empty_input));
+        assert!(empty_input.is_subset_of(&input_with_empty_lists));
+        assert!(!input.is_subset_of(&empty_input));
+    }
+
+    #[test]
+    fn trim_names_and_drop_empty() {
+        let input = SubscriptionInput::new(
+            &Some(vec![
+                " channel-1".into(),
+                "channel-2 ".into(),
+                "  ".into(),
+                "".into(),
+            ]),
+            &Some(vec![" channel-group-1 ".into(), "".into()]),
+        );
+
+        assert!(!input.is_empty);
+        assert!(input.contains_channel("channel-1"));
+        assert!(input.contains_channel("channel-2"));
+        assert_eq!(input.channels().map(|channels| channels.len()), Some(2));
+        assert_eq!(
+            input.channel_groups(),
+            Some(vec!["channel-group-1".to_string()])
+        );
+    }
+
+    #[test]
+    fn be_empty_when_only_blank_names_provided() {
+        let input = SubscriptionInput::new(&Some(vec![" ".into(), "".into()]), &None);
+
+        assert!(input.is_empty);
+    }
+
+    #[test]
+    fn match_wildcard_channels() {
+        let input = SubscriptionInput::new(
+            &Some(vec!["news.*".into(), "channel-1".into()]),
+            &Some(vec!["sport.*".into()]),
+        );
+
+        assert!(input.contains_matching("news.sport"));
+        assert!(input.contains_matching("news.sport.football"));
+        assert!(input.contains_matching("news.*"));
+        assert!(input.contains_matching("channel-1"));
+        assert!(!input.contains_matching("news."));
+        assert!(!input.contains_matching("newsroom"));
+        assert!(!input.contains_matching("sport.football"));
+    }
 }
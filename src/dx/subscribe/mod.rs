@@ -22,7 +22,7 @@ use crate::{
     core::{
         event_engine::{CancellationTask, EventEngine},
         runtime::Runtime,
-        DataStream, PubNubEntity,
+        DataStream, PubNubEntity, RequestRetryConfiguration,
     },
     lib::alloc::string::ToString,
 };
@@ -32,7 +32,7 @@ use crate::{
     subscribe::raw::RawSubscriptionBuilder,
 };
 
-#[cfg(all(feature = "presence", feature = "std"))]
+#[cfg(feature = "std")]
 use event_engine::SubscriptionInput;
 #[cfg(feature = "std")]
 use event_engine::{SubscribeEffectHandler, SubscribeEventEngine, SubscribeState};
@@ -41,7 +41,7 @@ use event_engine::{SubscribeEffectHandler, SubscribeEventEngine, SubscribeState}
 pub(crate) mod event_engine;
 
 #[cfg(feature = "std")]
-pub(crate) use subscription_manager::SubscriptionManager;
+pub(crate) use subscription_manager::{SubscriptionManager, DEFAULT_DEDUPLICATION_CACHE_SIZE};
 #[cfg(feature = "std")]
 pub(crate) mod subscription_manager;
 
@@ -65,7 +65,7 @@ pub mod result;
 
 #[cfg(feature = "std")]
 #[doc(inline)]
-pub use subscription::Subscription;
+pub use subscription::{Subscription, SubscriptionFilter};
 #[cfg(feature = "std")]
 mod subscription;
 
@@ -75,6 +75,18 @@ pub use subscription_set::SubscriptionSet;
 #[cfg(feature = "std")]
 mod subscription_set;
 
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use cursor_store::{FileCursorStore, InMemoryCursorStore, SubscriptionCursorStore};
+#[cfg(feature = "std")]
+mod cursor_store;
+
+#[cfg(all(feature = "std", feature = "blocking", feature = "tokio"))]
+#[doc(inline)]
+pub use blocking_subscription::BlockingSubscription;
+#[cfg(all(feature = "std", feature = "blocking", feature = "tokio"))]
+mod blocking_subscription;
+
 #[cfg(feature = "std")]
 #[doc(inline)]
 pub use traits::{EventEmitter, EventSubscriber, Subscribable, SubscribableType, Subscriber};
@@ -406,6 +418,99 @@ where
         }
     }
 
+    /// Current subscription time cursor.
+    ///
+    /// Time cursor of the last received real-time updates batch. It can be
+    /// stored and passed to [`reconnect`] (or `subscribe_with_timetoken`) to
+    /// catch up on missed updates after application restart.
+    ///
+    /// ```no_run
+    /// use pubnub::{
+    ///     subscribe::{EventSubscriber, SubscriptionParams},
+    ///     Keyset, PubNubClient, PubNubClientBuilder,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let pubnub = PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #             subscribe_key: "demo",
+    /// #             publish_key: Some("demo"),
+    /// #             secret_key: None,
+    /// #         })
+    /// #         .with_user_id("user_id")
+    /// #         .build()?;
+    /// # let subscription = pubnub.subscription(SubscriptionParams {
+    /// #     channels: Some(&["channel"]),
+    /// #     channel_groups: None,
+    /// #     options: None
+    /// # });
+    /// # subscription.subscribe();
+    /// // .....
+    /// if let Some(cursor) = pubnub.current_cursor() {
+    ///     println!("Last received timetoken: {}", cursor.timetoken);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`reconnect`]: PubNubClientInstance::reconnect
+    pub fn current_cursor(&self) -> Option<SubscriptionCursor> {
+        self.subscription_manager(false)
+            .read()
+            .as_ref()
+            .and_then(|manager| manager.current_cursor())
+            .or_else(|| self.cursor.read().clone())
+    }
+
+    /// Unsubscribes from specific channels and groups.
+    ///
+    /// Stop receiving real-time events for `channels` and `channel_groups`
+    /// while keeping the rest of subscription active. [`Subscription`] and
+    /// [`SubscriptionSet`] created only for removed channels and groups will
+    /// be invalidated.
+    ///
+    /// ```no_run
+    /// use pubnub::{
+    ///     subscribe::{EventSubscriber, SubscriptionParams},
+    ///     Keyset, PubNubClient, PubNubClientBuilder,
+    /// };
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let pubnub = PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #             subscribe_key: "demo",
+    /// #             publish_key: Some("demo"),
+    /// #             secret_key: None,
+    /// #         })
+    /// #         .with_user_id("user_id")
+    /// #         .build()?;
+    /// let subscription = pubnub.subscription(SubscriptionParams {
+    ///     channels: Some(&["channel-1", "channel-2"]),
+    ///     channel_groups: None,
+    ///     options: None
+    /// });
+    /// subscription.subscribe();
+    /// // .....
+    /// pubnub.unsubscribe(&["channel-2"], &[]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unsubscribe<N>(&self, channels: &[N], channel_groups: &[N])
+    where
+        N: Into<String> + Clone,
+    {
+        let names = |names: &[N]| {
+            (!names.is_empty()).then(|| names.iter().cloned().map(Into::into).collect())
+        };
+        let removed = SubscriptionInput::new(&names(channels), &names(channel_groups));
+
+        if let Some(manager) = self.subscription_manager(false).write().as_mut() {
+            manager.unsubscribe(removed)
+        }
+    }
+
     /// Unsubscribes from all real-time events.
     ///
     /// Stop any actions for receiving real-time events processing for all
@@ -450,9 +555,13 @@ where
                 let heartbeat_self = self.clone();
                 #[cfg(feature = "presence")]
                 let leave_self = self.clone();
+                let retry_configuration = Arc::new(RwLock::new(None));
 
                 *slot = Some(SubscriptionManager::new(
-                    self.subscribe_event_engine(),
+                    self.subscribe_event_engine(retry_configuration.clone()),
+                    DEFAULT_DEDUPLICATION_CACHE_SIZE,
+                    self.cursor_store.clone(),
+                    retry_configuration,
                     #[cfg(feature = "presence")]
                     Arc::new(move |channels, groups, _all| {
                         Self::subscribe_heartbeat_call(heartbeat_self.clone(), channels, groups);
@@ -468,13 +577,17 @@ where
         self.subscription.clone()
     }
 
-    fn subscribe_event_engine(&self) -> Arc<SubscribeEventEngine> {
+    fn subscribe_event_engine(
+        &self,
+        retry_configuration: Arc<RwLock<Option<RequestRetryConfiguration>>>,
+    ) -> Arc<SubscribeEventEngine> {
         let channel_bound = 10; // TODO: Think about this value
         let emit_messages_client = self.clone();
         let emit_status_client = self.clone();
         let subscribe_client = self.clone();
         let request_retry = self.config.transport.retry_configuration.clone();
         let request_subscribe_retry = request_retry.clone();
+        let request_subscribe_retry_override = retry_configuration.clone();
         let runtime = self.runtime.clone();
         let runtime_sleep = runtime.clone();
         let (cancel_tx, cancel_rx) = async_channel::bounded::<String>(channel_bound);
@@ -482,11 +595,16 @@ where
         EventEngine::new(
             SubscribeEffectHandler::new(
                 Arc::new(move |params| {
-                    let delay_in_microseconds = request_subscribe_retry.retry_delay(
-                        Some("/v2/subscribe".to_string()),
-                        &params.attempt,
-                        params.reason.as_ref(),
-                    );
+                    // Subscriptions may override client-wide retry policy.
+                    let delay_in_microseconds = request_subscribe_retry_override
+                        .read()
+                        .as_ref()
+                        .unwrap_or(&request_subscribe_retry)
+                        .retry_delay(
+                            Some("/v2/subscribe".to_string()),
+                            &params.attempt,
+                            params.reason.as_ref(),
+                        );
                     let inner_runtime_sleep = runtime_sleep.clone();
 
                     Self::subscribe_call(
@@ -510,6 +628,8 @@ where
                     Self::emit_messages(emit_messages_client.clone(), updates, cursor)
                 })),
                 request_retry,
+                retry_configuration,
+                self.config.subscribe.max_messages_per_receive,
                 cancel_tx,
             ),
             SubscribeState::Unsubscribed,
@@ -538,6 +658,10 @@ where
             request = request.channel_groups(channel_groups);
         }
 
+        if let Some(filter_expression) = client.get_filter_expression::<String>() {
+            request = request.filter_expression(filter_expression);
+        }
+
         #[cfg(feature = "presence")]
         {
             let state = client.state.read();
@@ -618,7 +742,7 @@ where
 
     /// Filter out `-pnpres` entries from the list.
     #[cfg(feature = "presence")]
-    fn presence_filtered_entries(entries: Option<Vec<String>>) -> Option<Vec<String>> {
+    pub(crate) fn presence_filtered_entries(entries: Option<Vec<String>>) -> Option<Vec<String>> {
         entries.map(|channels| {
             channels
                 .into_iter()
@@ -758,8 +882,11 @@ mod should {
 
     use super::*;
     use crate::{
-        core::{blocking, PubNubError, TransportRequest, TransportResponse},
+        core::{
+            blocking, PubNubError, RequestRetryConfiguration, TransportRequest, TransportResponse,
+        },
         providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
         Keyset, PubNubClientBuilder, PubNubGenericClient,
     };
 
@@ -898,6 +1025,123 @@ mod should {
             .unwrap()
     }
 
+    #[tokio::test]
+    async fn pass_filter_expression_to_subscribe_request() {
+        struct CapturingTransport(async_channel::Sender<TransportRequest>);
+
+        #[async_trait::async_trait]
+        impl Transport for CapturingTransport {
+            async fn send(
+                &self,
+                request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                self.0.send(request).await.unwrap();
+
+                Ok(TransportResponse {
+                    status: 200,
+                    body: generate_body(0),
+                    ..Default::default()
+                })
+            }
+        }
+
+        let (tx, rx) = async_channel::bounded(1);
+        let (_cancel_tx, cancel_rx) = async_channel::bounded(1);
+        let client = PubNubClientBuilder::with_transport(CapturingTransport(tx))
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap();
+        client.set_filter_expression("priority > 2");
+
+        let _ = PubNubClientInstance::subscribe_call(
+            client.clone(),
+            event_engine::SubscriptionParams {
+                channels: &Some(vec!["channel".into()]),
+                channel_groups: &None,
+                cursor: None,
+                attempt: 0,
+                reason: None,
+                effect_id: "id",
+            },
+            Arc::new(|| futures::future::ready(()).boxed()),
+            cancel_rx,
+        )
+        .await;
+
+        let request = rx.recv().await.unwrap();
+        assert_eq!(
+            request.query_parameters.get("filter-expr"),
+            Some(&"priority > 2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn use_subscribe_retry_configuration_override() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        struct FailingTransport(Arc<AtomicUsize>);
+
+        #[async_trait::async_trait]
+        impl Transport for FailingTransport {
+            async fn send(
+                &self,
+                _request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(TransportResponse {
+                    status: 500,
+                    ..Default::default()
+                })
+            }
+        }
+
+        async fn handshake_attempts(
+            subscribe_retry_configuration: Option<RequestRetryConfiguration>,
+        ) -> usize {
+            let attempts = Arc::new(AtomicUsize::new(0));
+            let client = PubNubClientBuilder::with_transport(FailingTransport(attempts.clone()))
+                .with_keyset(Keyset {
+                    subscribe_key: "demo",
+                    publish_key: None,
+                    secret_key: None,
+                })
+                .with_user_id("user")
+                .build()
+                .unwrap();
+
+            let subscription = client.subscription(SubscriptionParams {
+                channels: Some(&["channel"]),
+                channel_groups: None,
+                options: subscribe_retry_configuration.map(|configuration| {
+                    vec![SubscriptionOptions::RetryConfiguration(configuration)]
+                }),
+            });
+            let mut status_stream = client.status_stream();
+            subscription.subscribe();
+
+            // Wait for reconnection give up.
+            loop {
+                if let Some(ConnectionStatus::ConnectionError(_)) = status_stream.next().await {
+                    break;
+                }
+            }
+            client.unsubscribe_all();
+
+            attempts.load(Ordering::SeqCst)
+        }
+
+        assert_eq!(handshake_attempts(None).await, 1);
+        assert_eq!(
+            handshake_attempts(Some(RequestRetryConfiguration::Fixed { max_retry: 1 })).await,
+            2
+        );
+    }
+
     #[tokio::test]
     async fn create_subscription_set() {
         let _ = client().subscription(SubscriptionParams {
@@ -943,6 +1187,157 @@ mod should {
         client.unsubscribe_all();
     }
 
+    #[tokio::test]
+    async fn share_real_time_updates_with_clones() {
+        let client = client();
+        let cloned_client = client.clone();
+        let mut cloned_status_stream = cloned_client.status_stream();
+        let mut cloned_messages_stream = cloned_client.messages_stream();
+        let subscription = client.subscription(SubscriptionParams {
+            channels: Some(&["my-channel"]),
+            channel_groups: None,
+            options: None,
+        });
+        subscription.subscribe();
+
+        let status = cloned_status_stream.next().await.unwrap();
+        let message = cloned_messages_stream.next().await.unwrap();
+
+        assert!(matches!(status, ConnectionStatus::Connected));
+        assert_eq!(message.channel, "my-channel");
+        assert!(Arc::ptr_eq(
+            &client.subscription,
+            &cloned_client.subscription
+        ));
+        assert_eq!(
+            client
+                .cursor
+                .read()
+                .as_ref()
+                .map(|cursor| cursor.timetoken.clone()),
+            Some("15628652479932717".into())
+        );
+
+        cloned_client.unsubscribe_all();
+        assert!(client.cursor.read().is_none());
+    }
+
+    /// Transport which emulates subscribe and presence services.
+    #[derive(Default)]
+    struct RoutingTransport {
+        subscribe_count: RwLock<u16>,
+        leave_paths: RwLock<Vec<String>>,
+
+        /// Whether `leave` request should fail or not.
+        fail_leave: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for RoutingTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            let body = if request.path.ends_with("/leave") {
+                self.leave_paths.write().push(request.path.clone());
+
+                if self.fail_leave {
+                    return Err(PubNubError::Transport {
+                        details: "Connection reset".into(),
+                        status: None,
+                        response: None,
+                    });
+                }
+
+                Some(
+                    r#"{"status": 200, "message": "OK", "action": "leave", "service": "Presence"}"#
+                        .into(),
+                )
+            } else if request.path.contains("/heartbeat") {
+                Some(r#"{"status": 200, "message": "OK", "service": "Presence"}"#.into())
+            } else {
+                let mut count_slot = self.subscribe_count.write();
+                let body = generate_body(*count_slot);
+                *count_slot = 2;
+                body
+            };
+
+            if body.is_none() {
+                tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
+            }
+
+            Ok(TransportResponse {
+                status: 200,
+                body,
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Create client with active subscription to `my-channel`.
+    ///
+    /// Returned subscription should be kept alive to stay subscribed.
+    async fn subscribed_client(
+        transport: RoutingTransport,
+    ) -> (
+        PubNubGenericClient<RoutingTransport, DeserializerSerde>,
+        SubscriptionSet<PubNubMiddleware<RoutingTransport>, DeserializerSerde>,
+    ) {
+        let client = PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: Some("demo"),
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap();
+        let mut status_stream = client.status_stream();
+        let subscription = client.subscription(SubscriptionParams {
+            channels: Some(&["my-channel"]),
+            channel_groups: None,
+            options: None,
+        });
+        subscription.subscribe();
+
+        let status = status_stream.next().await.unwrap();
+        assert!(matches!(status, ConnectionStatus::Connected));
+
+        (client, subscription)
+    }
+
+    #[tokio::test]
+    async fn leave_and_unsubscribe_on_shutdown() {
+        let (client, _subscription) = subscribed_client(RoutingTransport::default()).await;
+        let event_engine = client
+            .subscription_manager(false)
+            .read()
+            .as_ref()
+            .map(|manager| manager.event_engine.clone())
+            .unwrap();
+        client.clone().shutdown().await.unwrap();
+
+        assert!(matches!(
+            event_engine.current_state(),
+            SubscribeState::Unsubscribed
+        ));
+        assert!(client.subscription.read().is_none());
+        assert_eq!(
+            *client.transport.transport.leave_paths.read(),
+            vec!["/v2/presence/sub_key/demo/channel/my-channel/leave".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn terminate_on_shutdown_when_leave_failed() {
+        let (client, _subscription) = subscribed_client(RoutingTransport {
+            fail_leave: true,
+            ..Default::default()
+        })
+        .await;
+
+        assert!(client.clone().shutdown().await.is_err());
+        assert!(client.subscription_manager(false).read().is_none());
+        assert_eq!(client.transport.transport.leave_paths.read().len(), 1);
+    }
+
     #[tokio::test]
     async fn subscribe_raw() {
         let subscription = client()
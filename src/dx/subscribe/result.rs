@@ -209,8 +209,9 @@ pub struct Envelope {
     /// PubNub defined event type.
     #[cfg_attr(
         feature = "serde",
-        serde(rename = "f"),
-        serde(default = "Envelope::default_message_type")
+        serde(rename = "e"),
+        serde(default = "Envelope::default_message_type"),
+        serde(deserialize_with = "Envelope::deserialize_message_type")
     )]
     pub message_type: SubscribeMessageType,
 
@@ -538,21 +539,51 @@ impl Envelope {
     fn default_message_type() -> SubscribeMessageType {
         SubscribeMessageType::Message
     }
+
+    /// Message type from its numeric representation.
+    ///
+    /// Unknown message types handled as regular messages.
+    fn deserialize_message_type<'de, D>(deserializer: D) -> Result<SubscribeMessageType, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let discriminant = <Option<u8> as serde::Deserialize>::deserialize(deserializer)?;
+
+        Ok(discriminant
+            .and_then(SubscribeMessageType::from_discriminant)
+            .unwrap_or_else(Self::default_message_type))
+    }
 }
 
-#[cfg(feature = "std")]
 impl Update {
     /// Name of subscription.
     ///
     /// Name of channel or channel group on which client subscribed and through
     /// which real-time update has been delivered.
-    pub(crate) fn subscription(&self) -> String {
+    pub fn subscription(&self) -> &str {
         match self {
             Self::Presence(presence) => presence.subscription(),
             Self::AppContext(object) => object.subscription(),
-            Self::MessageAction(reaction) => reaction.subscription.clone(),
-            Self::File(file) => file.subscription.clone(),
-            Self::Message(message) | Self::Signal(message) => message.subscription.clone(),
+            Self::MessageAction(reaction) => &reaction.subscription,
+            Self::File(file) => &file.subscription,
+            Self::Message(message) | Self::Signal(message) => &message.subscription,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Update {
+    /// Name of channel.
+    ///
+    /// Name of channel on which real-time update has been triggered. `AppContext`
+    /// updates don't carry channel name, so subscription name is used instead.
+    pub(crate) fn channel(&self) -> String {
+        match self {
+            Self::Presence(presence) => presence.channel().to_string(),
+            Self::AppContext(object) => object.subscription().to_string(),
+            Self::MessageAction(reaction) => reaction.channel.clone(),
+            Self::File(file) => file.channel.clone(),
+            Self::Message(message) | Self::Signal(message) => message.channel.clone(),
         }
     }
 
@@ -4,12 +4,13 @@
 //! subscription to the specific entity and attach listeners to process
 //! real-time events triggered for the `entity`.
 
+use futures::Stream;
 use spin::RwLock;
 use uuid::Uuid;
 
 use crate::core::{Deserializer, Transport};
 use crate::{
-    core::{DataStream, PubNubEntity},
+    core::{DataStream, PubNubEntity, RequestRetryConfiguration},
     dx::pubnub_client::PubNubClientInstance,
     lib::{
         alloc::{
@@ -23,12 +24,14 @@ use crate::{
             cmp::PartialEq,
             fmt::{Debug, Formatter, Result},
             ops::{Add, Deref, DerefMut, Drop},
+            pin::Pin,
+            task::{Context, Poll},
         },
     },
     subscribe::{
         event_engine::SubscriptionInput, traits::EventHandler, AppContext, EventDispatcher,
         EventEmitter, EventSubscriber, File, Message, MessageAction, Presence, SubscribableType,
-        SubscriptionCursor, SubscriptionOptions, SubscriptionSet, Update,
+        SubscribeStreamEvent, SubscriptionCursor, SubscriptionOptions, SubscriptionSet, Update,
     },
 };
 
@@ -89,6 +92,34 @@ use crate::{
 /// #     Ok(())
 /// # }
 /// ```
+///
+/// ### Subscription as stream
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use pubnub::{
+///     subscribe::{EventSubscriber, Subscriber},
+///     Keyset, PubNubClient, PubNubClientBuilder,
+/// };
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let pubnub = // PubNubClient
+/// #     PubNubClientBuilder::with_reqwest_transport()
+/// #         .with_keyset(Keyset {
+/// #              subscribe_key: "demo",
+/// #              publish_key: Some("demo"),
+/// #              secret_key: Some("demo")
+/// #          })
+/// #         .with_user_id("uuid")
+/// #         .build()?;
+/// let subscription = pubnub.channel("my_channel").subscription(None);
+/// subscription.subscribe();
+/// // `Subscription` itself is a stream of real-time updates.
+/// let events = subscription.take(10).collect::<Vec<_>>().await;
+/// #     Ok(())
+/// # }
+/// ```
 pub struct Subscription<
     T: Transport + Send + Sync + 'static,
     D: Deserializer + Send + Sync + 'static,
@@ -98,8 +129,20 @@ pub struct Subscription<
 
     /// Whether subscription is `Clone::clone()` method call result or not.
     is_clone: bool,
+
+    /// Real-time updates stream.
+    ///
+    /// Stream is created on first [`Stream::poll_next`] call and is used to
+    /// deliver updates when [`Subscription`] used as [`Stream`].
+    updates: Option<DataStream<Update>>,
 }
 
+/// Real-time updates filter.
+///
+/// Predicate which is used by [`Subscription`] to decide whether update should
+/// be delivered to the listeners or not.
+pub type SubscriptionFilter = dyn Fn(&Update) -> bool + Send + Sync;
+
 /// Subscription reference
 ///
 /// This struct contains the actual subscription state.
@@ -128,7 +171,6 @@ pub struct SubscriptionRef<T: Send + Sync, D: Send + Sync> {
 /// for its internal state.
 ///
 /// Not intended to be used directly. Use [`Subscription`] instead.
-#[derive(Debug)]
 pub struct SubscriptionState<T: Send + Sync, D: Send + Sync> {
     /// Unique event handler identifier.
     pub(super) id: String,
@@ -158,6 +200,12 @@ pub struct SubscriptionState<T: Send + Sync, D: Send + Sync> {
     /// processing.
     options: Option<Vec<SubscriptionOptions>>,
 
+    /// Real-time updates filter.
+    ///
+    /// Updates for which filter returns `false` won't be delivered to the
+    /// listeners.
+    filter: RwLock<Option<Arc<SubscriptionFilter>>>,
+
     /// The list of weak references to all [`SubscriptionRef`] clones created
     /// for this reference.
     clones: RwLock<HashMap<String, Weak<SubscriptionRef<T, D>>>>,
@@ -189,6 +237,7 @@ where
         Self {
             inner: SubscriptionRef::new(client, entity, options),
             is_clone: false,
+            updates: None,
         }
     }
 
@@ -236,8 +285,48 @@ where
         Self {
             inner: self.inner.clone_empty(),
             is_clone: false,
+            updates: None,
         }
     }
+
+    /// Filter real-time updates client-side.
+    ///
+    /// Updates for which `filter` returns `false` won't be delivered to the
+    /// subscription listeners. Filter is shared between subscription clones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::{
+    ///     subscribe::{Subscriber, Update},
+    ///     Keyset, PubNubClient, PubNubClientBuilder,
+    /// };
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: Some("demo")
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// // Receive only messages and ignore signals and other updates.
+    /// let subscription = pubnub
+    ///     .channel("my_channel")
+    ///     .subscription(None)
+    ///     .with_filter(|update| matches!(update, Update::Message(_)));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn with_filter<F>(self, filter: F) -> Self
+    where
+        F: Fn(&Update) -> bool + Send + Sync + 'static,
+    {
+        *self.filter.write() = Some(Arc::new(filter));
+        self
+    }
 }
 
 impl<T, D> Deref for Subscription<T, D>
@@ -272,6 +361,7 @@ where
         Self {
             inner: self.inner.clone(),
             is_clone: true,
+            updates: None,
         }
     }
 }
@@ -305,6 +395,28 @@ where
     }
 }
 
+impl<T, D> Stream for Subscription<T, D>
+where
+    T: Transport + Send + Sync + 'static,
+    D: Deserializer + Send + Sync + 'static,
+{
+    type Item = SubscribeStreamEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.updates.is_none() {
+            self.updates = Some(self.event_dispatcher.stream());
+        }
+
+        let Some(updates) = self.updates.as_mut() else {
+            return Poll::Ready(None);
+        };
+
+        Pin::new(updates)
+            .poll_next(ctx)
+            .map(|update| update.map(SubscribeStreamEvent::Update))
+    }
+}
+
 impl<T, D> Add for Subscription<T, D>
 where
     T: Transport + Send + Sync + 'static,
@@ -374,12 +486,16 @@ where
         entity: PubNubEntity<T, D>,
         options: Option<Vec<SubscriptionOptions>>,
     ) -> Arc<Self> {
+        let event_dispatcher = client
+            .upgrade()
+            .map(|client| EventDispatcher::with_configuration(&client.config.subscribe))
+            .unwrap_or_default();
         let subscription_ref = SubscriptionState::new(client, entity, options);
         let subscription_id = Uuid::new_v4().to_string();
         let subscription = Arc::new(Self {
             instance_id: subscription_id.clone(),
             state: Arc::new(subscription_ref),
-            event_dispatcher: Default::default(),
+            event_dispatcher: Arc::new(event_dispatcher),
         });
         subscription.store_clone(subscription_id, Arc::downgrade(&subscription));
         subscription
@@ -396,7 +512,7 @@ where
         let instance = Arc::new(Self {
             instance_id: instance_id.clone(),
             state: Arc::clone(&self.state),
-            event_dispatcher: Default::default(),
+            event_dispatcher: Arc::new(self.event_dispatcher.clone_empty()),
         });
         self.store_clone(instance_id, Arc::downgrade(&instance));
         instance
@@ -462,15 +578,20 @@ where
     /// 1. The event's subscription is present in the subscription input.
     /// 2. The event's timestamp is greater than or equal to the current
     ///    timetoken.
+    /// 3. The event is accepted by subscription filter (if set).
     fn filtered_events(&self, events: &[Update]) -> Vec<Update> {
         let subscription_input = self.subscription_input(true);
         let current_timetoken = self.current_timetoken();
+        let filter = self.filter.read().clone();
 
         events
             .iter()
             .filter(|event| {
-                subscription_input.contains(&event.subscription())
+                let subscription = event.subscription();
+                (subscription_input.contains_matching(subscription)
+                    || subscription_input.contains_channel_group(subscription))
                     && event.event_timestamp().ge(&current_timetoken)
+                    && filter.as_ref().map(|filter| filter(event)).unwrap_or(true)
             })
             .cloned()
             .collect::<Vec<Update>>()
@@ -639,6 +760,10 @@ where
     fn client(&self) -> Weak<PubNubClientInstance<T, D>> {
         self.client.clone()
     }
+
+    fn retry_configuration(&self) -> Option<RequestRetryConfiguration> {
+        SubscriptionOptions::retry_configuration(&self.options)
+    }
 }
 
 impl<T, D> EventEmitter for SubscriptionRef<T, D>
@@ -708,6 +833,7 @@ where
             subscription_input: input,
             cursor: Default::default(),
             options,
+            filter: Default::default(),
             clones: Default::default(),
         }
     }
@@ -726,10 +852,31 @@ where
     }
 }
 
+impl<T, D> Debug for SubscriptionState<T, D>
+where
+    T: Send + Sync,
+    D: Send + Sync,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "SubscriptionState {{ id: {}, subscription_input: {:?}, is_subscribed: {}, \
+            cursor: {:?}, options: {:?}, with_filter: {}}}",
+            self.id,
+            self.subscription_input,
+            *self.is_subscribed.read(),
+            self.cursor.read().clone(),
+            self.options,
+            self.filter.read().is_some()
+        )
+    }
+}
+
 #[cfg(test)]
 mod it_should {
     use super::*;
     use crate::{Channel, ChannelGroup, Keyset, PubNubClient, PubNubClientBuilder};
+    use futures::{FutureExt, StreamExt};
 
     fn client() -> PubNubClient {
         PubNubClientBuilder::with_reqwest_transport()
@@ -876,4 +1023,84 @@ mod it_should {
             .as_ref()
             .is_none());
     }
+
+    #[tokio::test]
+    async fn drop_updates_rejected_by_filter() {
+        let client = Arc::new(client());
+        let channel = Channel::new(&client, "channel");
+        let subscription = Subscription::new(
+            Arc::downgrade(&client),
+            PubNubEntity::Channel(channel),
+            None,
+        )
+        .with_filter(|update| matches!(update, Update::Message(_)));
+        let message = Message {
+            channel: "channel".into(),
+            subscription: "channel".into(),
+            timestamp: 15800701771129796,
+            ..Default::default()
+        };
+
+        // Simulate `.subscribe()` call.
+        {
+            let mut is_subscribed = subscription.is_subscribed.write();
+            *is_subscribed = true;
+        }
+        subscription.handle_events(
+            "15800701771129796".to_string().into(),
+            &[
+                Update::Signal(message.clone()),
+                Update::Message(message.clone()),
+            ],
+        );
+
+        let mut stream = subscription.stream();
+        assert!(matches!(stream.next().await, Some(Update::Message(_))));
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn collect_updates_through_stream() {
+        let client = Arc::new(client());
+        let channel = Channel::new(&client, "channel");
+        let subscription = Subscription::new(
+            Arc::downgrade(&client),
+            PubNubEntity::Channel(channel),
+            None,
+        );
+        let message = Message {
+            channel: "channel".into(),
+            subscription: "channel".into(),
+            timestamp: 15800701771129796,
+            ..Default::default()
+        };
+
+        // Simulate `.subscribe()` call.
+        {
+            let mut is_subscribed = subscription.is_subscribed.write();
+            *is_subscribed = true;
+        }
+        subscription.handle_events(
+            "15800701771129796".to_string().into(),
+            &[
+                Update::Message(message.clone()),
+                Update::Signal(message.clone()),
+                Update::Message(message.clone()),
+            ],
+        );
+
+        let events = subscription
+            .take(2)
+            .collect::<Vec<SubscribeStreamEvent>>()
+            .await;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0],
+            SubscribeStreamEvent::Update(Update::Message(_))
+        ));
+        assert!(matches!(
+            events[1],
+            SubscribeStreamEvent::Update(Update::Signal(_))
+        ));
+    }
 }
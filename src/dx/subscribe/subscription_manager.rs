@@ -5,19 +5,22 @@
 
 use spin::RwLock;
 
-use crate::core::{Deserializer, Transport};
+use crate::core::{Deserializer, RequestRetryConfiguration, Transport};
 use crate::subscribe::traits::EventHandler;
 use crate::{
     dx::subscribe::{
         event_engine::{
-            event::SubscribeEvent, SubscribeEffectInvocation, SubscribeEventEngine,
+            event::SubscribeEvent, SubscribeEffectInvocation, SubscribeEventEngine, SubscribeState,
             SubscriptionInput,
         },
         result::Update,
         ConnectionStatus, PubNubClientInstance, Subscription, SubscriptionCursor,
+        SubscriptionCursorStore,
     },
     lib::{
         alloc::{
+            collections::VecDeque,
+            string::String,
             sync::{Arc, Weak},
             vec::Vec,
         },
@@ -33,6 +36,9 @@ use crate::{
 pub(in crate::dx::subscribe) type PresenceCall =
     dyn Fn(Option<Vec<String>>, Option<Vec<String>>, bool) + Send + Sync;
 
+/// Default number of recently delivered updates used for de-duplication.
+pub(crate) const DEFAULT_DEDUPLICATION_CACHE_SIZE: usize = 100;
+
 /// Active subscriptions' manager.
 ///
 /// [`PubNubClient`] allows to have multiple [`subscription`] objects which will
@@ -49,6 +55,9 @@ pub(crate) struct SubscriptionManager<T, D> {
 impl<T, D> SubscriptionManager<T, D> {
     pub fn new(
         event_engine: Arc<SubscribeEventEngine>,
+        deduplication_cache_size: usize,
+        cursor_store: Option<Arc<dyn SubscriptionCursorStore>>,
+        retry_configuration: Arc<RwLock<Option<RequestRetryConfiguration>>>,
         #[cfg(feature = "presence")] heartbeat_call: Arc<PresenceCall>,
         #[cfg(feature = "presence")] leave_call: Arc<PresenceCall>,
     ) -> Self {
@@ -56,6 +65,11 @@ impl<T, D> SubscriptionManager<T, D> {
             inner: Arc::new(SubscriptionManagerRef {
                 event_engine,
                 event_handlers: Default::default(),
+                delivered_updates: RwLock::new(DeliveredUpdates::new(deduplication_cache_size)),
+                unsubscribed: Default::default(),
+                cursor_store,
+                retry_configuration,
+                retry_configuration_owner: Default::default(),
                 #[cfg(feature = "presence")]
                 heartbeat_call,
                 #[cfg(feature = "presence")]
@@ -98,7 +112,7 @@ pub(crate) struct SubscriptionManagerRef<T, D> {
     /// Subscription event engine.
     ///
     /// State machine which is responsible for subscription loop maintenance.
-    event_engine: Arc<SubscribeEventEngine>,
+    pub(in crate::dx::subscribe) event_engine: Arc<SubscribeEventEngine>,
 
     /// List of registered event handlers.
     ///
@@ -106,6 +120,33 @@ pub(crate) struct SubscriptionManagerRef<T, D> {
     /// to the listeners.
     event_handlers: RwLock<HashMap<String, Weak<dyn EventHandler<T, D> + Send + Sync>>>,
 
+    /// Recently delivered updates.
+    ///
+    /// Used to skip updates which has been received more than once (for
+    /// example during reconnection catch-up).
+    delivered_updates: RwLock<DeliveredUpdates>,
+
+    /// Channels and groups removed with partial unsubscribe.
+    ///
+    /// Registered event handlers still may list them, so they are excluded
+    /// from subscription input until explicitly subscribed again.
+    unsubscribed: RwLock<SubscriptionInput>,
+
+    /// Subscription time cursor store.
+    ///
+    /// Store used to restore subscription from previously received time
+    /// cursor and keep the latest one.
+    cursor_store: Option<Arc<dyn SubscriptionCursorStore>>,
+
+    /// Subscription loop retry configuration override.
+    ///
+    /// Retry configuration provided by one of registered event handlers which
+    /// is shared with subscription event engine.
+    retry_configuration: Arc<RwLock<Option<RequestRetryConfiguration>>>,
+
+    /// Identifier of event handler which provided `retry_configuration`.
+    retry_configuration_owner: RwLock<Option<String>>,
+
     /// Presence `join` announcement.
     ///
     /// Announces `user_id` presence on specified channels and groups.
@@ -131,6 +172,18 @@ where
     }
 
     pub fn notify_new_messages(&self, cursor: SubscriptionCursor, events: Vec<Update>) {
+        let events = {
+            let mut delivered_updates = self.delivered_updates.write();
+            events
+                .into_iter()
+                .filter(|update| delivered_updates.insert(update))
+                .collect::<Vec<_>>()
+        };
+
+        if let Some(store) = &self.cursor_store {
+            store.save(&cursor);
+        }
+
         if let Some(client) = self.client() {
             client.handle_events(cursor.clone(), &events)
         }
@@ -159,11 +212,25 @@ where
             return;
         }
 
+        // Restore subscription from stored cursor when subscription loop
+        // starts.
+        let cursor = cursor.or_else(|| {
+            let store = self.cursor_store.as_ref()?;
+            matches!(
+                self.event_engine.current_state(),
+                SubscribeState::Unsubscribed
+            )
+            .then(|| store.load())
+            .flatten()
+        });
+
         {
             self.event_handlers
                 .write()
                 .insert(event_handler_id.clone(), event_handler.clone());
+            *self.unsubscribed.write() -= upgraded_event_handler.subscription_input(false);
         }
+        self.update_retry_configuration(Some(&upgraded_event_handler));
 
         if let Some(cursor) = cursor {
             self.restore_subscription(cursor);
@@ -175,6 +242,7 @@ where
     pub fn update(
         &self,
         event_handler: &Weak<dyn EventHandler<T, D> + Send + Sync>,
+        added: Option<&SubscriptionInput>,
         removed: Option<&[Subscription<T, D>]>,
     ) {
         let Some(upgraded_event_handler) = event_handler.upgrade().clone() else {
@@ -189,6 +257,10 @@ where
             return;
         }
 
+        if let Some(added) = added {
+            *self.unsubscribed.write() -= added.clone();
+        }
+
         // Handle subscriptions' set subscriptions subset which has been removed from
         // it.
         let removed = removed.map(|removed| {
@@ -234,11 +306,45 @@ where
                 }
             });
             handlers.clear();
+            *self.unsubscribed.write() = Default::default();
         }
 
         self.change_subscription(Some(&inputs));
     }
 
+    /// Unsubscribe from subset of channels and groups.
+    ///
+    /// Event handlers which has been created only for `removed` channels and
+    /// groups will be invalidated and unregistered. The rest of handlers keep
+    /// their input, but `removed` entries will be excluded from subscription
+    /// until re-subscribed.
+    pub fn unsubscribe(&mut self, removed: SubscriptionInput) {
+        // Only channels and groups which are currently subscribed can be
+        // removed.
+        let removed = self.current_input().intersection(&removed);
+        if removed.is_empty {
+            return;
+        }
+
+        {
+            self.event_handlers.write().retain(|_, weak_handler| {
+                let Some(handler) = weak_handler.upgrade() else {
+                    return false;
+                };
+
+                if handler.subscription_input(true).is_subset_of(&removed) {
+                    handler.invalidate();
+                    return false;
+                }
+
+                true
+            });
+            *self.unsubscribed.write() += removed.clone();
+        }
+
+        self.change_subscription(Some(&removed));
+    }
+
     pub fn disconnect(&self) {
         self.event_engine.process(&SubscribeEvent::Disconnect);
     }
@@ -250,7 +356,8 @@ where
 
     /// Returns the current subscription input.
     ///
-    /// Gather subscriptions from all registered (active) event handlers.
+    /// Gather subscriptions from all registered (active) event handlers except
+    /// channels and groups removed with partial unsubscribe.
     ///
     /// # Returns
     ///
@@ -262,7 +369,17 @@ where
             .values()
             .filter_map(|weak_handler| weak_handler.upgrade().clone())
             .map(|handler| handler.subscription_input(false).clone())
-            .sum()
+            .sum::<SubscriptionInput>()
+            - self.unsubscribed.read().clone()
+    }
+
+    /// Returns the current subscription time cursor.
+    ///
+    /// # Returns
+    ///
+    /// - [`SubscriptionCursor`]: Time cursor used by subscription loop, if any.
+    pub fn current_cursor(&self) -> Option<SubscriptionCursor> {
+        self.event_engine.current_cursor()
     }
 
     /// Checks if there are any event handlers registered.
@@ -285,6 +402,7 @@ where
     }
 
     fn change_subscription(&self, removed: Option<&SubscriptionInput>) {
+        self.update_retry_configuration(None);
         let mut inputs = self.current_input();
 
         if let Some(removed) = removed {
@@ -311,6 +429,19 @@ where
             }
         }
 
+        // Last event handler has been unregistered and subscription loop should
+        // be stopped.
+        if inputs.is_empty
+            && !self.has_handlers()
+            && !matches!(
+                self.event_engine.current_state(),
+                SubscribeState::Unsubscribed
+            )
+        {
+            self.event_engine.process(&SubscribeEvent::UnsubscribeAll);
+            return;
+        }
+
         self.event_engine
             .process(&SubscribeEvent::SubscriptionChanged {
                 channels,
@@ -334,6 +465,39 @@ where
             });
     }
 
+    /// Update subscription loop retry configuration override.
+    ///
+    /// Override from the `registered` event handler takes precedence.
+    /// Otherwise, the current one is kept while its event handler is
+    /// registered or replaced with override from any other registered event
+    /// handler.
+    fn update_retry_configuration(
+        &self,
+        registered: Option<&Arc<dyn EventHandler<T, D> + Send + Sync>>,
+    ) {
+        let event_handlers = self.event_handlers.read();
+        let mut owner = self.retry_configuration_owner.write();
+        let provider = registered
+            .filter(|handler| handler.retry_configuration().is_some())
+            .cloned()
+            .or_else(|| {
+                owner
+                    .as_ref()
+                    .and_then(|id| event_handlers.get(id))
+                    .and_then(|handler| handler.upgrade())
+            })
+            .or_else(|| {
+                event_handlers
+                    .values()
+                    .filter_map(|handler| handler.upgrade())
+                    .find(|handler| handler.retry_configuration().is_some())
+            });
+
+        *owner = provider.as_ref().map(|handler| handler.id().clone());
+        *self.retry_configuration.write() =
+            provider.and_then(|handler| handler.retry_configuration());
+    }
+
     /// [`PubNubClientInstance`] associated with any of the event handlers.
     ///
     /// # Returns
@@ -355,6 +519,58 @@ where
     }
 }
 
+/// Bounded LRU set of recently delivered updates.
+///
+/// Updates identified by `(channel, timetoken)` pair. When cache is full, least
+/// recently seen entry is evicted.
+#[derive(Debug)]
+struct DeliveredUpdates {
+    /// Maximum number of tracked updates.
+    capacity: usize,
+
+    /// Tracked updates ordered from least to most recently seen.
+    entries: VecDeque<(String, usize)>,
+}
+
+impl DeliveredUpdates {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Track delivered update.
+    ///
+    /// # Returns
+    ///
+    /// Returns `false` if update with same channel and timetoken has been seen
+    /// recently.
+    fn insert(&mut self, update: &Update) -> bool {
+        let timetoken = update.event_timestamp();
+
+        // Updates without timetoken can't be identified.
+        if self.capacity == 0 || timetoken == 0 {
+            return true;
+        }
+
+        let key = (update.channel(), timetoken);
+        if let Some(position) = self.entries.iter().position(|entry| entry.eq(&key)) {
+            if let Some(entry) = self.entries.remove(position) {
+                self.entries.push_back(entry);
+            }
+            return false;
+        }
+
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(key);
+
+        true
+    }
+}
+
 impl<T, D> Debug for SubscriptionManagerRef<T, D> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -371,12 +587,13 @@ mod should {
 
     use super::*;
     use crate::{
-        core::RequestRetryConfiguration,
+        core::{PubNubError, TransportRequest, TransportResponse},
         dx::subscribe::{
             event_engine::{SubscribeEffectHandler, SubscribeState},
             result::SubscribeResult,
             types::Message,
-            EventEmitter, Subscriber, Update,
+            EventEmitter, EventSubscriber, InMemoryCursorStore, Subscriber, SubscriptionOptions,
+            SubscriptionParams, Update,
         },
         lib::alloc::sync::Arc,
         providers::futures_tokio::RuntimeTokio,
@@ -386,7 +603,7 @@ mod should {
     fn client() -> PubNubClient {
         PubNubClientBuilder::with_reqwest_transport()
             .with_keyset(Keyset {
-                subscribe_key: "",
+                subscribe_key: "demo",
                 publish_key: Some(""),
                 secret_key: None,
             })
@@ -395,6 +612,31 @@ mod should {
             .unwrap()
     }
 
+    /// Transport which completes handshake and keeps long-poll requests
+    /// pending.
+    struct MockTransport;
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            let handshake = request
+                .query_parameters
+                .get("tt")
+                .map(|timetoken| timetoken.eq("0"))
+                .unwrap_or(true);
+
+            if !handshake {
+                tokio::time::sleep(tokio::time::Duration::from_secs(10)).await;
+            }
+
+            Ok(TransportResponse {
+                status: 200,
+                body: Some(br#"{"t":{"t":"17000000000000001","r":1},"m":[]}"#.to_vec()),
+                ..Default::default()
+            })
+        }
+    }
+
     fn event_engine() -> Arc<SubscribeEventEngine> {
         let (cancel_tx, _) = async_channel::bounded(1);
 
@@ -416,6 +658,8 @@ mod should {
                     // Do nothing yet
                 })),
                 RequestRetryConfiguration::None,
+                Default::default(),
+                None,
                 cancel_tx,
             ),
             SubscribeState::Unsubscribed,
@@ -428,6 +672,9 @@ mod should {
         let client = client();
         let mut manager = SubscriptionManager::new(
             event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            Default::default(),
             #[cfg(feature = "presence")]
             Arc::new(|channels, _, _| {
                 assert!(channels.is_some());
@@ -446,11 +693,56 @@ mod should {
         assert_eq!(manager.event_handlers.read().len(), 1);
     }
 
+    #[tokio::test]
+    async fn use_retry_configuration_of_registered_subscriptions() {
+        let client = client();
+        let retry_configuration = Arc::new(RwLock::new(None));
+        let mut manager = SubscriptionManager::new(
+            event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            retry_configuration.clone(),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+        );
+        let fixed = RequestRetryConfiguration::Fixed { max_retry: 1 };
+        let linear = RequestRetryConfiguration::default_linear();
+        let subscription_a = client.channel("test-a").subscription(Some(vec![
+            SubscriptionOptions::RetryConfiguration(fixed.clone()),
+        ]));
+        let subscription_b = client.channel("test-b").subscription(Some(vec![
+            SubscriptionOptions::RetryConfiguration(linear.clone()),
+        ]));
+        let weak_subscription_a = &Arc::downgrade(&subscription_a.inner);
+        let weak_handler_a: Weak<dyn EventHandler<_, _> + Send + Sync> =
+            weak_subscription_a.clone();
+        let weak_subscription_b = &Arc::downgrade(&subscription_b.inner);
+        let weak_handler_b: Weak<dyn EventHandler<_, _> + Send + Sync> =
+            weak_subscription_b.clone();
+
+        manager.register(&weak_handler_a, None);
+        assert_eq!(*retry_configuration.read(), Some(fixed.clone()));
+
+        manager.register(&weak_handler_b, None);
+        assert_eq!(*retry_configuration.read(), Some(linear));
+
+        manager.unregister(&weak_handler_b);
+        assert_eq!(*retry_configuration.read(), Some(fixed));
+
+        manager.unregister(&weak_handler_a);
+        assert_eq!(*retry_configuration.read(), None);
+    }
+
     #[tokio::test]
     async fn unregister_subscription() {
         let client = client();
         let mut manager = SubscriptionManager::new(
             event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            Default::default(),
             #[cfg(feature = "presence")]
             Arc::new(|_, _, _| {}),
             #[cfg(feature = "presence")]
@@ -470,11 +762,105 @@ mod should {
         assert_eq!(manager.event_handlers.read().len(), 0);
     }
 
+    #[tokio::test]
+    async fn unsubscribe_from_subset_of_channels() {
+        let client = client();
+        let mut manager = SubscriptionManager::new(
+            event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            Default::default(),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+        );
+        let subscription1 = client.channel("test-1").subscription(None);
+        let subscription2 = client.channel("test-2").subscription(None);
+        let weak_subscription1 = Arc::downgrade(&subscription1.inner);
+        let weak_handler1: Weak<dyn EventHandler<_, _> + Send + Sync> = weak_subscription1.clone();
+        let weak_subscription2 = Arc::downgrade(&subscription2.inner);
+        let weak_handler2: Weak<dyn EventHandler<_, _> + Send + Sync> = weak_subscription2.clone();
+
+        // Simulate `.subscribe()` call.
+        for subscription in [&subscription1, &subscription2] {
+            let mut is_subscribed = subscription.is_subscribed.write();
+            *is_subscribed = true;
+        }
+        manager.register(&weak_handler1, None);
+        manager.register(&weak_handler2, None);
+        assert_eq!(manager.event_handlers.read().len(), 2);
+
+        manager.unsubscribe(SubscriptionInput::new(&Some(vec!["test-2".into()]), &None));
+
+        assert_eq!(manager.event_handlers.read().len(), 1);
+        assert!(subscription1.is_subscribed());
+        assert!(!subscription2.is_subscribed());
+    }
+
+    #[tokio::test]
+    async fn keep_partially_unsubscribed_channels_of_overlapping_handler() {
+        let client = client();
+        let mut manager = SubscriptionManager::new(
+            event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            Default::default(),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+        );
+        let subscription_set = client.subscription(SubscriptionParams {
+            channels: Some(&["test-1", "test-2"]),
+            channel_groups: None,
+            options: None,
+        });
+        let subscription = client.channel("test-3").subscription(None);
+        let weak_set = Arc::downgrade(&subscription_set.inner);
+        let weak_set_handler: Weak<dyn EventHandler<_, _> + Send + Sync> = weak_set.clone();
+        let weak_subscription = Arc::downgrade(&subscription.inner);
+        let weak_handler: Weak<dyn EventHandler<_, _> + Send + Sync> = weak_subscription.clone();
+        let channels = |manager: &SubscriptionManager<_, _>| {
+            let mut channels = manager.current_input().channels().unwrap_or_default();
+            channels.sort();
+            channels
+        };
+
+        // Simulate `.subscribe()` call.
+        *subscription_set.is_subscribed.write() = true;
+        *subscription.is_subscribed.write() = true;
+        subscription_set
+            .subscriptions
+            .read()
+            .iter()
+            .for_each(|subscription| subscription.entity.increase_subscriptions_count());
+        subscription.entity.increase_subscriptions_count();
+        manager.register(&weak_set_handler, None);
+
+        // Channels which are not subscribed shouldn't be tracked as removed.
+        manager.unsubscribe(SubscriptionInput::new(&Some(vec!["test-9".into()]), &None));
+        assert!(manager.unsubscribed.read().is_empty);
+
+        manager.unsubscribe(SubscriptionInput::new(&Some(vec!["test-2".into()]), &None));
+        assert!(subscription_set.is_subscribed());
+        assert_eq!(channels(&manager), vec!["test-1"]);
+
+        // Changes in other handlers shouldn't bring removed channel back.
+        manager.register(&weak_handler, None);
+        assert_eq!(channels(&manager), vec!["test-1", "test-3"]);
+        manager.unregister(&weak_handler);
+        assert_eq!(channels(&manager), vec!["test-1"]);
+    }
+
     #[tokio::test]
     async fn notify_subscription_about_updates() {
         let client = client();
         let mut manager = SubscriptionManager::new(
             event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            Default::default(),
             #[cfg(feature = "presence")]
             Arc::new(|_, _, _| {}),
             #[cfg(feature = "presence")]
@@ -505,4 +891,254 @@ mod should {
 
         assert!(subscription.messages_stream().next().await.is_some());
     }
+
+    #[tokio::test]
+    async fn notify_channel_group_subscription_about_updates() {
+        let client = client();
+        let mut manager = SubscriptionManager::new(
+            event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            Default::default(),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+        );
+        let cursor: SubscriptionCursor = "15800701771129796".to_string().into();
+        let channel_group = client.channel_group("group");
+        let subscription = channel_group.subscription(None);
+        let weak_subscription = Arc::downgrade(&subscription.inner);
+        let weak_handler: Weak<dyn EventHandler<_, _> + Send + Sync> = weak_subscription.clone();
+
+        // Simulate `.subscribe()` call.
+        {
+            let mut is_subscribed = subscription.is_subscribed.write();
+            *is_subscribed = true;
+        }
+        manager.register(&weak_handler, Some(cursor.clone()));
+
+        manager.notify_new_messages(
+            cursor.clone(),
+            vec![
+                Update::Message(Message {
+                    channel: "group-member".into(),
+                    subscription: "group".into(),
+                    timestamp: 15800701771129796,
+                    ..Default::default()
+                }),
+                Update::Message(Message {
+                    channel: "other-channel".into(),
+                    subscription: "other-channel".into(),
+                    timestamp: 15800701771129797,
+                    ..Default::default()
+                }),
+            ],
+        );
+
+        let mut stream = subscription.messages_stream();
+        let message = stream.next().await.unwrap();
+        assert_eq!(message.channel, "group-member");
+        assert_eq!(message.subscription, "group");
+        assert!(stream.next().now_or_never().is_none());
+    }
+
+    #[tokio::test]
+    async fn notify_subscriptions_about_duplicated_updates_once() {
+        let client = client();
+        let mut manager = SubscriptionManager::new(
+            event_engine(),
+            DEFAULT_DEDUPLICATION_CACHE_SIZE,
+            None,
+            Default::default(),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+            #[cfg(feature = "presence")]
+            Arc::new(|_, _, _| {}),
+        );
+        let cursor: SubscriptionCursor = "15800701771129796".to_string().into();
+        let channel = client.channel("test");
+        let subscriptions = vec![channel.subscription(None), channel.subscription(None)];
+        for subscription in &subscriptions {
+            let weak_subscription = Arc::downgrade(&subscription.inner);
+            let weak_handler: Weak<dyn EventHandler<_, _> + Send + Sync> = weak_subscription;
+
+            // Simulate `.subscribe()` call.
+            {
+                let mut is_subscribed = subscription.is_subscribed.write();
+                *is_subscribed = true;
+            }
+            manager.register(&weak_handler, Some(cursor.clone()));
+        }
+        let message = |timestamp: usize| {
+            Update::Message(Message {
+                channel: "test".into(),
+                subscription: "test".into(),
+                timestamp,
+                ..Default::default()
+            })
+        };
+
+        manager.notify_new_messages(cursor.clone(), vec![message(15800701771129796)]);
+        // Catch-up after reconnection delivers already received message again.
+        manager.notify_new_messages(
+            cursor.clone(),
+            vec![message(15800701771129796), message(15800701771129797)],
+        );
+
+        for subscription in &subscriptions {
+            let mut stream = subscription.messages_stream();
+            assert_eq!(
+                stream.next().await.map(|message| message.timestamp),
+                Some(15800701771129796)
+            );
+            assert_eq!(
+                stream.next().await.map(|message| message.timestamp),
+                Some(15800701771129797)
+            );
+            assert!(stream.next().now_or_never().is_none());
+        }
+    }
+
+    #[test]
+    fn evict_least_recently_seen_update() {
+        let message = |timestamp: usize| {
+            Update::Message(Message {
+                channel: "test".into(),
+                timestamp,
+                ..Default::default()
+            })
+        };
+        let mut delivered_updates = DeliveredUpdates::new(2);
+
+        assert!(delivered_updates.insert(&message(1)));
+        assert!(delivered_updates.insert(&message(2)));
+        assert!(!delivered_updates.insert(&message(1)));
+        assert!(delivered_updates.insert(&message(3)));
+
+        // `2` has been evicted, because `1` has been seen more recently.
+        assert!(delivered_updates.insert(&message(2)));
+        assert!(!delivered_updates.insert(&message(3)));
+    }
+
+    #[tokio::test]
+    async fn unregister_dropped_subscriptions() {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .build()
+            .unwrap();
+        let subscription1 = client.channel("test-1").subscription(None);
+        let subscription2 = client.channel("test-2").subscription(None);
+        subscription1.subscribe();
+        subscription2.subscribe();
+
+        let manager = client.subscription_manager(false);
+        {
+            let manager = manager.read();
+            let manager = manager.as_ref().unwrap();
+            assert_eq!(manager.event_handlers.read().len(), 2);
+            assert!(!matches!(
+                manager.event_engine.current_state(),
+                SubscribeState::Unsubscribed
+            ));
+        }
+
+        drop(subscription1);
+        drop(subscription2);
+
+        let manager = manager.read();
+        let manager = manager.as_ref().unwrap();
+        assert!(manager.event_handlers.read().is_empty());
+        assert!(matches!(
+            manager.event_engine.current_state(),
+            SubscribeState::Unsubscribed
+        ));
+    }
+
+    #[tokio::test]
+    async fn disconnect_and_reconnect_subscription_loop() {
+        let client = PubNubClientBuilder::with_transport(MockTransport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user_id")
+            .build()
+            .unwrap();
+        let subscription = client.channel("test").subscription(None);
+        let mut status_stream = client.status_stream();
+        subscription.subscribe();
+
+        assert!(matches!(
+            status_stream.next().await,
+            Some(ConnectionStatus::Connected)
+        ));
+        let current_state = || {
+            client
+                .subscription_manager(false)
+                .read()
+                .as_ref()
+                .unwrap()
+                .event_engine
+                .current_state()
+        };
+        assert!(matches!(current_state(), SubscribeState::Receiving { .. }));
+
+        client.disconnect();
+        assert!(matches!(
+            current_state(),
+            SubscribeState::ReceiveStopped { .. }
+        ));
+
+        client.reconnect(None);
+        assert!(matches!(
+            current_state(),
+            SubscribeState::Handshaking { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn restore_subscription_from_stored_cursor() {
+        let client = client();
+        let cursor = SubscriptionCursor {
+            timetoken: "17000000000000001".into(),
+            region: 4,
+        };
+        let store = Arc::new(InMemoryCursorStore::new());
+        let create_manager = |store: Arc<InMemoryCursorStore>| {
+            SubscriptionManager::new(
+                event_engine(),
+                DEFAULT_DEDUPLICATION_CACHE_SIZE,
+                Some(store),
+                Default::default(),
+                #[cfg(feature = "presence")]
+                Arc::new(|_, _, _| {}),
+                #[cfg(feature = "presence")]
+                Arc::new(|_, _, _| {}),
+            )
+        };
+        let subscription = client.channel("test").subscription(None);
+        let weak_subscription = Arc::downgrade(&subscription.inner);
+        let weak_handler: Weak<dyn EventHandler<_, _> + Send + Sync> = weak_subscription.clone();
+
+        let mut previous_manager = create_manager(store.clone());
+        previous_manager.register(&weak_handler, None);
+        previous_manager.notify_new_messages(cursor.clone(), vec![]);
+        assert_eq!(store.load(), Some(cursor.clone()));
+
+        // Fresh subscription manager with the same store.
+        let mut manager = create_manager(store);
+        manager.register(&weak_handler, None);
+
+        assert!(matches!(
+            manager.event_engine.current_state(),
+            SubscribeState::Handshaking { cursor: Some(restored), .. } if restored == cursor
+        ));
+    }
 }
@@ -10,7 +10,7 @@ use uuid::Uuid;
 use crate::core::{Deserializer, Transport};
 use crate::subscribe::traits::EventHandler;
 use crate::{
-    core::{DataStream, PubNubEntity},
+    core::{DataStream, PubNubEntity, RequestRetryConfiguration},
     dx::pubnub_client::PubNubClientInstance,
     lib::{
         alloc::{
@@ -316,9 +316,11 @@ where
         let unique_subscriptions =
             { Self::unique_subscriptions_from_list(Some(self), subscriptions) };
 
+        let added_input = Self::subscription_input_from_list(&unique_subscriptions, true);
+
         {
             let mut subscription_input = self.subscription_input.write();
-            *subscription_input += Self::subscription_input_from_list(&unique_subscriptions, true);
+            *subscription_input += added_input.clone();
             self.subscriptions
                 .write()
                 .extend(unique_subscriptions.clone());
@@ -342,7 +344,7 @@ where
             // Notify manager to update its state with new subscriptions.
             if let Some((_, handler)) = self.clones.read().iter().next() {
                 let handler: Weak<dyn EventHandler<T, D> + Send + Sync> = handler.clone();
-                manager.update(&handler, None);
+                manager.update(&handler, Some(&added_input), None);
             }
         };
     }
@@ -428,7 +430,7 @@ where
             // Notify manager to update its state with removed subscriptions.
             if let Some((_, handler)) = self.clones.read().iter().next() {
                 let handler: Weak<dyn EventHandler<T, D> + Send + Sync> = handler.clone();
-                manager.update(&handler, Some(&removed));
+                manager.update(&handler, None, Some(&removed));
             }
         };
     }
@@ -698,12 +700,17 @@ where
         let subscription = subscriptions
             .first()
             .expect("At least one subscription expected.");
+        let event_dispatcher = subscription
+            .client()
+            .upgrade()
+            .map(|client| EventDispatcher::with_configuration(&client.config.subscribe))
+            .unwrap_or_default();
         let subscription_state =
             SubscriptionSetState::new(subscription.client(), subscriptions, options);
         let subscription_set = Arc::new(Self {
             instance_id: Uuid::new_v4().to_string(),
             state: Arc::new(subscription_state),
-            event_dispatcher: Default::default(),
+            event_dispatcher,
         });
         subscription_set.store_clone(
             subscription_set.instance_id.clone(),
@@ -759,7 +766,7 @@ where
         let instance = Arc::new(Self {
             instance_id: instance_id.clone(),
             state: Arc::clone(&self.state),
-            event_dispatcher: Default::default(),
+            event_dispatcher: self.event_dispatcher.clone_empty(),
         });
         self.store_clone(instance_id, Arc::downgrade(&instance));
         instance
@@ -835,7 +842,7 @@ where
         events
             .iter()
             .filter(|event| {
-                subscription_input.contains(&event.subscription())
+                subscription_input.contains(event.subscription())
                     && event.event_timestamp().ge(&current_timetoken)
             })
             .cloned()
@@ -1004,6 +1011,10 @@ where
     fn client(&self) -> Weak<PubNubClientInstance<T, D>> {
         self.client.clone()
     }
+
+    fn retry_configuration(&self) -> Option<RequestRetryConfiguration> {
+        SubscriptionOptions::retry_configuration(&self.options)
+    }
 }
 
 impl<T, D> EventEmitter for SubscriptionSetRef<T, D>
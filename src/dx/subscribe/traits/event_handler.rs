@@ -1,4 +1,5 @@
 use crate::{
+    core::RequestRetryConfiguration,
     dx::pubnub_client::PubNubClientInstance,
     lib::alloc::sync::Weak,
     subscribe::{event_engine::SubscriptionInput, SubscriptionCursor, Update},
@@ -49,4 +50,14 @@ pub trait EventHandler<T, D> {
     /// Reference on the underlying [`PubNubClientInstance`] instance of
     /// [`Subscription`].
     fn client(&self) -> Weak<PubNubClientInstance<T, D>>;
+
+    /// Subscribe requests retry configuration override.
+    ///
+    /// # Returns
+    ///
+    /// Retry configuration which should be used by the subscription loop
+    /// instead of the client-wide one or `None`.
+    fn retry_configuration(&self) -> Option<RequestRetryConfiguration> {
+        None
+    }
 }
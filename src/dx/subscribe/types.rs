@@ -3,12 +3,13 @@
 use base64::{engine::general_purpose, Engine};
 
 use crate::{
-    core::{CryptoProvider, PubNubError, ScalarValue},
+    core::{utils::encoding::url_encode, CryptoProvider, PubNubError, ScalarValue},
     dx::subscribe::result::{Envelope, EnvelopePayload, ObjectDataBody, Update},
     lib::{
         alloc::{
             borrow::ToOwned,
             boxed::Box,
+            format,
             string::{String, ToString},
             sync::Arc,
             vec::Vec,
@@ -22,6 +23,8 @@ use crate::{
     },
 };
 
+#[cfg(feature = "std")]
+use crate::core::RequestRetryConfiguration;
 #[cfg(not(feature = "serde"))]
 use crate::lib::alloc::vec;
 
@@ -47,7 +50,6 @@ pub enum SubscribeStreamEvent {
 ///
 /// [`PubNub`]:https://www.pubnub.com/
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize), serde(untagged))]
 pub enum SubscribeMessageType {
     /// Regular messages.
     ///
@@ -80,18 +82,94 @@ pub enum SubscribeMessageType {
     File = 4,
 }
 
+impl SubscribeMessageType {
+    /// Create message type from its numeric representation.
+    ///
+    /// Numeric representation used by [`PubNub`] service in real-time updates
+    /// and history responses.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` if `discriminant` doesn't match any known message type.
+    ///
+    /// [`PubNub`]:https://www.pubnub.com/
+    pub fn from_discriminant(discriminant: u8) -> Option<Self> {
+        match discriminant {
+            0 => Some(Self::Message),
+            1 => Some(Self::Signal),
+            2 => Some(Self::Object),
+            3 => Some(Self::MessageAction),
+            4 => Some(Self::File),
+            _ => None,
+        }
+    }
+
+    /// Numeric representation of message type.
+    pub fn discriminant(&self) -> u8 {
+        match self {
+            Self::Message => 0,
+            Self::Signal => 1,
+            Self::Object => 2,
+            Self::MessageAction => 3,
+            Self::File => 4,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SubscribeMessageType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let discriminant = <u8 as serde::Deserialize>::deserialize(deserializer)?;
+        Self::from_discriminant(discriminant).ok_or_else(|| {
+            serde::de::Error::custom(format!("unknown message type: {discriminant}"))
+        })
+    }
+}
+
 /// Subscription behaviour options.
 ///
 /// Subscription behaviour with real-time events can be adjusted using provided
 /// options. Currently, subscription can be instructed to:
 /// * listen presence events for channels and groups
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// * use own retry policy for the subscription loop
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SubscriptionOptions {
     /// Whether presence events should be received.
     ///
     /// Whether presence updates for `userId` should be delivered through
     /// [`Subscription`] and [`SubscriptionSet`] listener streams or not.
     ReceivePresenceEvents,
+
+    /// Subscribe requests automatic retry configuration.
+    ///
+    /// The retry configuration overrides the client-wide one for the
+    /// subscription loop while [`Subscription`] or [`SubscriptionSet`] is
+    /// subscribed (for example, to reconnect aggressively while other requests
+    /// aren't retried). If several active subscriptions provide it, the one
+    /// from the most recently subscribed is used.
+    #[cfg(feature = "std")]
+    RetryConfiguration(RequestRetryConfiguration),
+}
+
+#[cfg(feature = "std")]
+impl SubscriptionOptions {
+    /// Retry configuration from the list of subscription options.
+    ///
+    /// # Returns
+    ///
+    /// Subscribe requests retry configuration or `None` if it isn't part of
+    /// `options`.
+    pub(crate) fn retry_configuration(
+        options: &Option<Vec<Self>>,
+    ) -> Option<RequestRetryConfiguration> {
+        options.as_ref()?.iter().find_map(|option| match option {
+            Self::RetryConfiguration(configuration) => Some(configuration.clone()),
+            _ => None,
+        })
+    }
 }
 
 /// [`PubNubClientInstance`] multiplex subscription parameters.
@@ -129,7 +207,12 @@ pub struct SubscriptionCursor {
 }
 
 /// Subscription statuses.
+///
+/// With `serde` feature statuses can be serialized (for example for logging
+/// or telemetry). Errors carried by [`ConnectionStatus::ConnectionError`] and
+/// [`ConnectionStatus::DisconnectedUnexpectedly`] serialized as message string.
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectionStatus {
     /// Successfully connected and receiving real-time updates.
     Connected,
@@ -142,10 +225,12 @@ pub enum ConnectionStatus {
     Disconnected,
 
     /// Connection attempt failed.
-    ConnectionError(PubNubError),
+    ConnectionError(#[cfg_attr(feature = "serde", serde(with = "error_message"))] PubNubError),
 
     /// Unexpected disconnection.
-    DisconnectedUnexpectedly(PubNubError),
+    DisconnectedUnexpectedly(
+        #[cfg_attr(feature = "serde", serde(with = "error_message"))] PubNubError,
+    ),
 
     /// List of channels and groups changed in subscription.
     SubscriptionChanged {
@@ -318,6 +403,11 @@ pub enum Presence {
         #[cfg(not(feature = "serde"))]
         data: Vec<u8>,
 
+        /// The user's state parsed into flatten `HashMap`.
+        ///
+        /// `None` if state can't be represented as object with scalar values.
+        state: Option<HashMap<String, ScalarValue>>,
+
         /// PubNub high-precision timestamp.
         ///
         /// Time when event has been emitted.
@@ -561,7 +651,7 @@ pub enum ObjectEvent {
 }
 
 /// Message's actions update event types.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MessageActionEvent {
     /// Message's action has been modified.
     Update,
@@ -583,6 +673,48 @@ impl SubscriptionCursor {
     pub(crate) fn is_valid(&self) -> bool {
         self.timetoken.len() == 17 && self.timetoken.chars().all(char::is_numeric)
     }
+
+    /// Create cursor for current point in time.
+    ///
+    /// Cursor `timetoken` is 17-digits PubNub high-precision timestamp (number
+    /// of 100-nanosecond intervals since Unix epoch).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use pubnub::subscribe::SubscriptionCursor;
+    ///
+    /// let cursor = SubscriptionCursor::now();
+    /// assert_eq!(cursor.timetoken.len(), 17);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        let since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self {
+            timetoken: (since_epoch.as_nanos() / 100).to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Check whether cursor points to later time than `other`.
+    ///
+    /// Timetokens compared numerically, so tokens with different length are
+    /// handled properly. Cursor with invalid `timetoken` is never newer.
+    pub fn is_newer_than(&self, other: &SubscriptionCursor) -> bool {
+        match (self.numeric_timetoken(), other.numeric_timetoken()) {
+            (Some(lhs), Some(rhs)) => lhs > rhs,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+
+    /// Numeric representation of `timetoken`.
+    fn numeric_timetoken(&self) -> Option<u64> {
+        self.timetoken.parse::<u64>().ok()
+    }
 }
 
 impl Default for SubscriptionCursor {
@@ -626,7 +758,7 @@ impl PartialOrd for SubscriptionCursor {
 
 impl Ord for SubscriptionCursor {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        self.numeric_timetoken().cmp(&other.numeric_timetoken())
     }
 }
 
@@ -737,6 +869,27 @@ impl From<SubscriptionCursor> for HashMap<String, String> {
     }
 }
 
+/// [`PubNubError`] serialization as error message string.
+#[cfg(feature = "serde")]
+mod error_message {
+    use super::*;
+
+    pub(super) fn serialize<S>(error: &PubNubError, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&error.to_string())
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<PubNubError, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let message = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(PubNubError::general_api_error(message, None, None))
+    }
+}
+
 impl Debug for ConnectionStatus {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
@@ -761,22 +914,81 @@ impl Debug for ConnectionStatus {
     }
 }
 
-#[cfg(feature = "std")]
 impl Presence {
     /// Name of subscription.
     ///
     /// Name of channel or channel group on which client subscribed and through
     /// which presence update has been delivered.
-    pub(crate) fn subscription(&self) -> String {
+    pub fn subscription(&self) -> &str {
         match self {
             Self::Join { subscription, .. }
             | Self::Leave { subscription, .. }
             | Self::Timeout { subscription, .. }
             | Self::Interval { subscription, .. }
-            | Self::StateChange { subscription, .. } => subscription.clone(),
+            | Self::StateChange { subscription, .. } => subscription,
         }
     }
 
+    /// Name of channel.
+    ///
+    /// Name of channel on which presence event has been triggered.
+    pub fn channel(&self) -> &str {
+        match self {
+            Self::Join { channel, .. }
+            | Self::Leave { channel, .. }
+            | Self::Timeout { channel, .. }
+            | Self::Interval { channel, .. }
+            | Self::StateChange { channel, .. } => channel,
+        }
+    }
+
+    /// Unix timestamp when presence event has been triggered.
+    pub fn timestamp(&self) -> usize {
+        match self {
+            Self::Join { timestamp, .. }
+            | Self::Leave { timestamp, .. }
+            | Self::Timeout { timestamp, .. }
+            | Self::Interval { timestamp, .. }
+            | Self::StateChange { timestamp, .. } => *timestamp,
+        }
+    }
+
+    /// Current channel occupancy.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` for [`Presence::StateChange`] because it doesn't carry
+    /// occupancy information.
+    pub fn occupancy(&self) -> Option<usize> {
+        match self {
+            Self::Join { occupancy, .. }
+            | Self::Leave { occupancy, .. }
+            | Self::Timeout { occupancy, .. }
+            | Self::Interval { occupancy, .. } => Some(*occupancy),
+            Self::StateChange { .. } => None,
+        }
+    }
+
+    /// Unique identification of the user for whom presence event has been
+    /// triggered.
+    ///
+    /// # Returns
+    ///
+    /// Returns `None` for [`Presence::Interval`] because it describes changes
+    /// for multiple users.
+    pub fn uuid(&self) -> Option<&str> {
+        match self {
+            Self::Join { uuid, .. }
+            | Self::Leave { uuid, .. }
+            | Self::Timeout { uuid, .. }
+            | Self::StateChange { uuid, .. } => Some(uuid),
+            Self::Interval { .. } => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Presence {
     /// PubNub high-precision presence event timestamp.
     ///
     /// # Returns
@@ -803,20 +1015,22 @@ impl Presence {
     }
 }
 
-#[cfg(feature = "std")]
 impl AppContext {
     /// Name of subscription.
     ///
     /// Name of channel or channel group on which client subscribed and through
     /// which object update has been triggered.
-    pub(crate) fn subscription(&self) -> String {
+    pub fn subscription(&self) -> &str {
         match self {
             Self::Channel { subscription, .. }
             | Self::Uuid { subscription, .. }
-            | Self::Membership { subscription, .. } => subscription.clone(),
+            | Self::Membership { subscription, .. } => subscription,
         }
     }
+}
 
+#[cfg(feature = "std")]
+impl AppContext {
     /// PubNub high-precision AppContext event timestamp.
     ///
     /// # Returns
@@ -872,6 +1086,42 @@ impl Message {
     }
 }
 
+impl File {
+    /// Shared file download URL.
+    ///
+    /// URL points to the default [`PubNub`] origin and doesn't include access
+    /// credentials.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use pubnub::subscribe::File;
+    /// let file = File {
+    ///     sender: "sender".into(),
+    ///     timestamp: 0,
+    ///     channel: "family".into(),
+    ///     subscription: "family".into(),
+    ///     message: "Look at my cat!".into(),
+    ///     id: "file-id".into(),
+    ///     name: "cat.jpg".into(),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     file.url("demo"),
+    ///     "https://ps.pndsn.com/v1/files/demo/channels/family/files/file-id/cat.jpg"
+    /// );
+    /// ```
+    ///
+    /// [`PubNub`]: https://www.pubnub.com
+    pub fn url(&self, sub_key: &str) -> String {
+        format!(
+            "https://ps.pndsn.com/v1/files/{sub_key}/channels/{}/files/{}/{}",
+            url_encode(self.channel.as_bytes()),
+            url_encode(self.id.as_bytes()),
+            url_encode(self.name.as_bytes())
+        )
+    }
+}
+
 impl TryFrom<Envelope> for Presence {
     type Error = PubNubError;
 
@@ -943,6 +1193,12 @@ impl TryFrom<Envelope> for Presence {
                     channel,
                     subscription,
                     #[cfg(feature = "serde")]
+                    state: data
+                        .as_ref()
+                        .and_then(|data| serde_json::from_value(data.clone()).ok()),
+                    #[cfg(not(feature = "serde"))]
+                    state: None,
+                    #[cfg(feature = "serde")]
                     data: data.unwrap_or(serde_json::Value::Null),
                     #[cfg(not(feature = "serde"))]
                     data: data.unwrap_or(vec![]),
@@ -1198,6 +1454,305 @@ mod should {
         resolve_subscription_value(subscription, channel)
     }
 
+    #[test_case(0 => Some(SubscribeMessageType::Message); "message")]
+    #[test_case(1 => Some(SubscribeMessageType::Signal); "signal")]
+    #[test_case(2 => Some(SubscribeMessageType::Object); "object")]
+    #[test_case(3 => Some(SubscribeMessageType::MessageAction); "message action")]
+    #[test_case(4 => Some(SubscribeMessageType::File); "file")]
+    #[test_case(5 => None; "out of range")]
+    fn create_message_type_from_discriminant(discriminant: u8) -> Option<SubscribeMessageType> {
+        let message_type = SubscribeMessageType::from_discriminant(discriminant);
+        if let Some(message_type) = &message_type {
+            assert_eq!(message_type.discriminant(), discriminant);
+        }
+
+        message_type
+    }
+
+    #[cfg(feature = "serde")]
+    #[test_case(0, "family", r#""hello""#; "message")]
+    #[test_case(1, "family", r#""ping""#; "signal")]
+    #[test_case(
+        0,
+        "family-pnpres",
+        r#"{"action": "join", "timestamp": 1700000000, "uuid": "bob", "occupancy": 1}"#;
+        "presence"
+    )]
+    #[test_case(
+        3,
+        "family",
+        r#"{
+            "event": "update",
+            "data": {
+                "messageTimetoken": "16000000000000000",
+                "actionTimetoken": "17000000000000000",
+                "type": "reaction",
+                "value": "smiley"
+            },
+            "source": "actions",
+            "version": "1.0"
+        }"#;
+        "message action"
+    )]
+    #[test_case(
+        4,
+        "family",
+        r#"{"message": "Look at my cat!", "file": {"id": "file-id", "name": "cat.jpg"}}"#;
+        "file"
+    )]
+    fn provide_update_subscription(message_type: u8, channel: &str, payload: &str) {
+        let envelope: Envelope = serde_json::from_str(&format!(
+            r#"{{
+                "a": "1",
+                "f": 0,
+                "e": {message_type},
+                "i": "sender",
+                "p": {{"t": "17000000000000000", "r": 1}},
+                "k": "demo",
+                "c": "{channel}",
+                "b": "family.*",
+                "d": {payload}
+            }}"#
+        ))
+        .unwrap();
+        let update = Update::try_from(envelope).unwrap();
+
+        assert_eq!(update.subscription(), "family.*");
+    }
+
+    #[test_case(
+        Presence::Join {
+            timestamp: 100,
+            uuid: "bob".into(),
+            channel: "family".into(),
+            subscription: "family.*".into(),
+            occupancy: 2,
+            data: None,
+            event_timestamp: 0,
+        } => ("family".to_string(), 100, Some(2), Some("bob".to_string()));
+        "join"
+    )]
+    #[test_case(
+        Presence::Leave {
+            timestamp: 101,
+            channel: "family".into(),
+            subscription: "family".into(),
+            occupancy: 1,
+            uuid: "bob".into(),
+            event_timestamp: 0,
+        } => ("family".to_string(), 101, Some(1), Some("bob".to_string()));
+        "leave"
+    )]
+    #[test_case(
+        Presence::Timeout {
+            timestamp: 102,
+            channel: "family".into(),
+            subscription: "family".into(),
+            occupancy: 0,
+            uuid: "bob".into(),
+            event_timestamp: 0,
+        } => ("family".to_string(), 102, Some(0), Some("bob".to_string()));
+        "timeout"
+    )]
+    #[test_case(
+        Presence::Interval {
+            timestamp: 103,
+            channel: "family".into(),
+            subscription: "family".into(),
+            occupancy: 3,
+            join: Some(vec!["alice".into()]),
+            leave: None,
+            timeout: None,
+            event_timestamp: 0,
+        } => ("family".to_string(), 103, Some(3), None);
+        "interval"
+    )]
+    #[test_case(
+        Presence::StateChange {
+            timestamp: 104,
+            channel: "family".into(),
+            subscription: "family".into(),
+            uuid: "bob".into(),
+            #[cfg(feature = "serde")]
+            data: serde_json::Value::Null,
+            #[cfg(not(feature = "serde"))]
+            data: vec![],
+            state: None,
+            event_timestamp: 0,
+        } => ("family".to_string(), 104, None, Some("bob".to_string()));
+        "state change"
+    )]
+    fn provide_presence_common_fields(
+        presence: Presence,
+    ) -> (String, usize, Option<usize>, Option<String>) {
+        (
+            presence.channel().to_string(),
+            presence.timestamp(),
+            presence.occupancy(),
+            presence.uuid().map(ToString::to_string),
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    #[test_case(
+        r#"{"is_admin": true, "score": 10}"#
+        => Some(HashMap::from([
+            ("is_admin".into(), ScalarValue::Boolean(true)),
+            ("score".into(), ScalarValue::Signed8(10)),
+        ]));
+        "object state"
+    )]
+    #[test_case(r#""online""# => None; "non-object state")]
+    #[test_case(r#"{"profile": {"age": 42}}"# => None; "nested state")]
+    fn parse_presence_state(payload: &str) -> Option<HashMap<String, ScalarValue>> {
+        let envelope: Envelope = serde_json::from_str(&format!(
+            r#"{{
+                "a": "1",
+                "f": 0,
+                "p": {{"t": "17000000000000000", "r": 1}},
+                "k": "demo",
+                "c": "family-pnpres",
+                "d": {{
+                    "action": "state-change",
+                    "timestamp": 1700000000,
+                    "uuid": "bob",
+                    "occupancy": 1,
+                    "data": {payload}
+                }}
+            }}"#
+        ))
+        .unwrap();
+
+        let Ok(Presence::StateChange { data, state, .. }) = Presence::try_from(envelope) else {
+            panic!("Expected to receive state change presence update.")
+        };
+        assert_eq!(
+            data,
+            serde_json::from_str::<serde_json::Value>(payload).unwrap()
+        );
+
+        state
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn classify_update_by_message_type() {
+        let envelope: Envelope = serde_json::from_str(
+            r#"{
+                "a": "1",
+                "f": 0,
+                "e": 1,
+                "i": "sender",
+                "p": {"t": "17000000000000000", "r": 1},
+                "k": "demo",
+                "c": "family",
+                "d": "ping"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(envelope.message_type, SubscribeMessageType::Signal);
+        assert!(matches!(Update::try_from(envelope), Ok(Update::Signal(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn create_file_url_from_envelope() {
+        let envelope: Envelope = serde_json::from_str(
+            r#"{
+                "a": "1",
+                "f": 0,
+                "e": 4,
+                "i": "sender",
+                "p": {"t": "17000000000000000", "r": 1},
+                "k": "demo",
+                "c": "my family",
+                "d": {
+                    "message": "Look at my cat!",
+                    "file": {"id": "file-id", "name": "cat picture.jpg"}
+                }
+            }"#,
+        )
+        .unwrap();
+        let file = File::try_from(envelope).unwrap();
+
+        assert_eq!(file.message, "Look at my cat!");
+        assert_eq!(
+            file.url("demo"),
+            "https://ps.pndsn.com/v1/files/demo/channels/my%20family/files/file-id/\
+            cat%20picture.jpg"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test_case(ConnectionStatus::Connected; "connected")]
+    #[test_case(ConnectionStatus::Reconnected; "reconnected")]
+    #[test_case(ConnectionStatus::Disconnected; "disconnected")]
+    #[test_case(
+        ConnectionStatus::ConnectionError(PubNubError::general_api_error("error", None, None));
+        "connection error"
+    )]
+    #[test_case(
+        ConnectionStatus::DisconnectedUnexpectedly(PubNubError::Transport {
+            details: "error".into(),
+            status: None,
+            response: None,
+        });
+        "disconnected unexpectedly"
+    )]
+    #[test_case(
+        ConnectionStatus::SubscriptionChanged {
+            channels: Some(vec!["channel".into()]),
+            channel_groups: None,
+        };
+        "subscription changed"
+    )]
+    fn serialize_and_deserialize_connection_status(status: ConnectionStatus) {
+        let serialized = serde_json::to_string(&status).unwrap();
+        let deserialized: ConnectionStatus = serde_json::from_str(&serialized).unwrap();
+
+        match (&status, &deserialized) {
+            (
+                ConnectionStatus::ConnectionError(error),
+                ConnectionStatus::ConnectionError(PubNubError::API { message, .. }),
+            )
+            | (
+                ConnectionStatus::DisconnectedUnexpectedly(error),
+                ConnectionStatus::DisconnectedUnexpectedly(PubNubError::API { message, .. }),
+            ) => assert_eq!(message, &error.to_string()),
+            _ => assert_eq!(deserialized, status),
+        }
+    }
+
+    #[test_case("17000000000000000", "16000000000000000" => true; "newer with equal length")]
+    #[test_case("16000000000000000", "17000000000000000" => false; "older with equal length")]
+    #[test_case("17000000000000000", "17000000000000000" => false; "same timetoken")]
+    #[test_case("17000000000000000", "9000000000000000" => true; "newer with longer timetoken")]
+    #[test_case("9000000000000000", "17000000000000000" => false; "older with shorter timetoken")]
+    #[test_case("17000000000000000", "0" => true; "newer than default cursor")]
+    #[test_case("-1", "17000000000000000" => false; "invalid timetoken is never newer")]
+    fn compare_cursors(lhs: &str, rhs: &str) -> bool {
+        let lhs = SubscriptionCursor {
+            timetoken: lhs.into(),
+            region: 1,
+        };
+        let rhs = SubscriptionCursor {
+            timetoken: rhs.into(),
+            region: 1,
+        };
+
+        lhs.is_newer_than(&rhs)
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn create_cursor_for_current_time() {
+        let cursor = SubscriptionCursor::now();
+
+        assert!(cursor.is_valid());
+        assert!(cursor.is_newer_than(&SubscriptionCursor::default()));
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn create_valid_subscription_cursor_as_struct() {
@@ -1281,4 +1836,43 @@ mod should {
         let cursor: SubscriptionCursor = timetoken.into();
         assert!(!cursor.is_valid())
     }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn decrypt_message_payload() {
+        let cryptor: Arc<dyn CryptoProvider + Send + Sync> = Arc::new(
+            crate::providers::crypto::CryptoModule::new_legacy_module("enigma", false).unwrap(),
+        );
+        let update = Update::Message(Message {
+            data: "\"4K7StI9dRz7utFsDHvuy082CQupbJvdwzrRja47qAV4=\"".into(),
+            ..Default::default()
+        });
+
+        let Update::Message(message) = update.decrypt(&cryptor) else {
+            panic!("Expected message update");
+        };
+        assert!(message.decryption_error.is_none());
+        assert_eq!(message.data, "\"Hello there 🙃\"".as_bytes());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn keep_payload_and_report_error_when_decryption_failed() {
+        let cryptor: Arc<dyn CryptoProvider + Send + Sync> = Arc::new(
+            crate::providers::crypto::CryptoModule::new_legacy_module("enigma", false).unwrap(),
+        );
+        let update = Update::Signal(Message {
+            data: "\"Hello there\"".into(),
+            ..Default::default()
+        });
+
+        let Update::Signal(message) = update.decrypt(&cryptor) else {
+            panic!("Expected signal update");
+        };
+        assert!(matches!(
+            message.decryption_error,
+            Some(PubNubError::Decryption { .. })
+        ));
+        assert_eq!(message.data, "\"Hello there\"".as_bytes());
+    }
 }
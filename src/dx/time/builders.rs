@@ -0,0 +1,136 @@
+//! Time module.
+//!
+//! This module contains `Time` request builder.
+
+use crate::{
+    core::{error::PubNubError, Deserializer, Transport, TransportMethod, TransportRequest},
+    dx::{
+        pubnub_client::PubNubClientInstance,
+        time::{TimeResponseBody, TimeResult},
+    },
+    lib::alloc::string::{String, ToString},
+};
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(
+    pattern = "owned",
+    build_fn(vis = "pub(in crate::dx::time)", validate = "Self::validate"),
+    no_std
+)]
+/// The [`TimeRequestBuilder`] is used to build request for current [`PubNub`]
+/// server time.
+///
+/// This struct used by the [`time`] method of the [`PubNubClient`].
+///
+/// [`PubNub`]:https://www.pubnub.com/
+/// [`time`]: crate::dx::PubNubClient::time
+/// [`PubNubClient`]: crate::PubNubClient
+pub struct TimeRequest<T, D> {
+    /// Current client which can provide transportation to perform the request.
+    ///
+    /// This field is used to get [`Transport`] to perform the request.
+    #[builder(field(vis = "pub(in crate::dx::time)"), setter(custom))]
+    pub(in crate::dx::time) pubnub_client: PubNubClientInstance<T, D>,
+}
+
+impl<T, D> TimeRequest<T, D> {
+    /// Create transport request from the request builder.
+    pub(in crate::dx::time) fn transport_request(&self) -> TransportRequest {
+        TransportRequest {
+            path: "/time/0".into(),
+            method: TransportMethod::Get,
+            #[cfg(feature = "std")]
+            timeout: self.pubnub_client.config.transport.request_timeout,
+            ..Default::default()
+        }
+    }
+}
+
+impl<T, D> TimeRequestBuilder<T, D> {
+    /// Validate user-provided data for request builder.
+    ///
+    /// Time request doesn't require any keys, so there is nothing to check
+    /// except presence of the client.
+    fn validate(&self) -> Result<(), String> {
+        self.pubnub_client
+            .is_some()
+            .then_some(())
+            .ok_or("PubNub client instance not set.".into())
+    }
+}
+
+impl<T, D> TimeRequestBuilder<T, D>
+where
+    T: Transport + 'static,
+    D: Deserializer + 'static,
+{
+    /// Build and call asynchronous request.
+    pub async fn execute(self) -> Result<TimeResult, PubNubError> {
+        // Build request instance and report errors if any.
+        let request = self
+            .build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+
+        transport_request
+            .send::<TimeResponseBody, _, _, _>(
+                &client.transport,
+                deserializer,
+                #[cfg(feature = "std")]
+                &client.config.transport.retry_configuration,
+                #[cfg(feature = "std")]
+                client.config.transport.retry_budget.as_ref(),
+                #[cfg(feature = "std")]
+                &client.runtime,
+            )
+            .await
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T, D> TimeRequestBuilder<T, D>
+where
+    T: crate::core::blocking::Transport,
+    D: Deserializer + 'static,
+{
+    /// Execute synchronous request and return the result.
+    ///
+    /// This method is synchronous and will return result which will resolve to
+    /// a [`TimeResult`] or [`PubNubError`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_blocking_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: None,
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// let result = pubnub.time().execute_blocking()?;
+    /// println!("Server time: {}", result.timetoken);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn execute_blocking(self) -> Result<TimeResult, PubNubError> {
+        // Build request instance and report errors if any.
+        let request = self
+            .build()
+            .map_err(|err| PubNubError::general_api_error(err.to_string(), None, None))?;
+
+        let transport_request = request.transport_request();
+        let client = request.pubnub_client.clone();
+        let deserializer = client.deserializer.clone();
+        transport_request
+            .send_blocking::<TimeResponseBody, _, _, _>(&client.transport, deserializer)
+    }
+}
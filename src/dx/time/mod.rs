@@ -0,0 +1,193 @@
+//! Time module.
+//!
+//! Retrieve current [`PubNub`] server time.
+//! The time module contains the [`TimeRequestBuilder`] which is used to
+//! request current server timetoken. Server time can be used to initialize
+//! subscription cursor or to check local clock skew.
+//!
+//! [`PubNub`]:https://www.pubnub.com/
+
+#[doc(inline)]
+pub use result::{TimeResponseBody, TimeResult};
+pub mod result;
+
+#[doc(inline)]
+pub use builders::{TimeRequest, TimeRequestBuilder};
+pub mod builders;
+
+use crate::dx::pubnub_client::PubNubClientInstance;
+
+impl<T, D> PubNubClientInstance<T, D> {
+    /// Create current server time request builder.
+    ///
+    /// This method is used to retrieve current [`PubNub`] high-precision
+    /// timestamp.
+    ///
+    /// Instance of [`TimeRequestBuilder`] returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pubnub = // PubNubClient
+    /// #     PubNubClientBuilder::with_reqwest_transport()
+    /// #         .with_keyset(Keyset {
+    /// #              subscribe_key: "demo",
+    /// #              publish_key: Some("demo"),
+    /// #              secret_key: None,
+    /// #          })
+    /// #         .with_user_id("uuid")
+    /// #         .build()?;
+    /// let result = pubnub.time().execute().await?;
+    /// println!("Server time: {}", result.timetoken);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`PubNub`]:https://www.pubnub.com/
+    pub fn time(&self) -> TimeRequestBuilder<T, D> {
+        TimeRequestBuilder {
+            pubnub_client: Some(self.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    use crate::{
+        core::{PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse},
+        lib::alloc::{boxed::Box, vec::Vec},
+        providers::deserialization_serde::DeserializerSerde,
+        transport::middleware::PubNubMiddleware,
+        Keyset, PubNubClientBuilder,
+    };
+
+    #[cfg(feature = "std")]
+    use crate::{
+        core::{retry_policy::RetryBudget, RequestRetryConfiguration},
+        lib::alloc::sync::Arc,
+    };
+    #[cfg(feature = "std")]
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Requests handler function type.
+    type RequestHandler = Box<dyn Fn(&TransportRequest) + Send + Sync>;
+
+    #[derive(Default)]
+    struct MockTransport {
+        ///  Response which mocked transport should return.
+        response: Option<TransportResponse>,
+
+        /// Request handler function which will be called before returning
+        /// response.
+        ///
+        /// Use function to verify request parameters.
+        request_handler: Option<RequestHandler>,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for MockTransport {
+        async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
+            // Calling request handler (if provided).
+            if let Some(handler) = &self.request_handler {
+                handler(&req);
+            }
+
+            Ok(self.response.clone().unwrap_or(TransportResponse {
+                status: 200,
+                body: Some(Vec::from("[16834217349830546]")),
+                ..Default::default()
+            }))
+        }
+    }
+
+    /// Construct test client with mocked transport.
+    fn client(
+        transport: MockTransport,
+    ) -> PubNubClientInstance<PubNubMiddleware<MockTransport>, DeserializerSerde> {
+        PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn request_server_time() {
+        let transport = MockTransport {
+            request_handler: Some(Box::new(|req| {
+                assert_eq!(req.path, "/time/0");
+                assert!(matches!(req.method, TransportMethod::Get));
+            })),
+            ..Default::default()
+        };
+        let result = client(transport).time().execute().await;
+
+        match result {
+            Ok(result) => assert_eq!(result.timetoken, 16834217349830546),
+            Err(err) => panic!("Request should not fail: {}", err),
+        }
+    }
+
+    #[tokio::test]
+    async fn return_error_on_malformed_response() {
+        let transport = MockTransport {
+            response: Some(TransportResponse {
+                status: 200,
+                body: Some(Vec::from("[]")),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let result = client(transport).time().execute().await;
+
+        assert!(matches!(result, Err(PubNubError::Deserialization { .. })));
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn fail_fast_when_retry_budget_exhausted() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_counter = attempts.clone();
+        let transport = MockTransport {
+            response: Some(TransportResponse {
+                status: 500,
+                ..Default::default()
+            }),
+            request_handler: Some(Box::new(move |_| {
+                attempts_counter.fetch_add(1, Ordering::SeqCst);
+            })),
+        };
+        let client = PubNubClientBuilder::with_transport(transport)
+            .with_keyset(Keyset {
+                subscribe_key: "demo",
+                publish_key: None,
+                secret_key: None,
+            })
+            .with_user_id("user")
+            .with_retry_configuration(RequestRetryConfiguration::Fixed { max_retry: 5 })
+            .with_retry_budget(RetryBudget::new(2, 0))
+            .build()
+            .unwrap();
+
+        let result = client.time().execute().await;
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(matches!(
+            result,
+            Err(PubNubError::Transport {
+                status: Some(500),
+                ..
+            })
+        ));
+
+        let _ = client.time().execute().await;
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+}
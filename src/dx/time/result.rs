@@ -0,0 +1,78 @@
+//! Time result module.
+//!
+//! This module contains [`TimeResult`] type which is used to represent
+//! current [`PubNub`] server time.
+//!
+//! [`PubNub`]:https://www.pubnub.com/
+
+use crate::{
+    core::PubNubError,
+    lib::alloc::{string::ToString, vec::Vec},
+};
+
+/// The result of a time operation.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeResult {
+    /// Current PubNub high-precision timestamp.
+    ///
+    /// 17-digits timetoken (number of 100-nanosecond intervals since Unix
+    /// epoch).
+    pub timetoken: u64,
+}
+
+/// [`PubNub API`] response for time request.
+///
+/// Service responds with single-element array which contains current server
+/// timetoken.
+///
+/// # Example
+/// ```json
+/// [16834217349830546]
+/// ```
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeResponseBody(pub Vec<u64>);
+
+impl TryFrom<TimeResponseBody> for TimeResult {
+    type Error = PubNubError;
+
+    fn try_from(value: TimeResponseBody) -> Result<Self, Self::Error> {
+        value
+            .0
+            .first()
+            .map(|timetoken| TimeResult {
+                timetoken: *timetoken,
+            })
+            .ok_or(PubNubError::Deserialization {
+                details: "Time response doesn't contain timetoken".to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+    use crate::lib::alloc::vec;
+
+    #[test]
+    fn parse_time_response() {
+        let result: Result<TimeResult, PubNubError> =
+            TimeResponseBody(vec![16834217349830546]).try_into();
+
+        assert_eq!(
+            result,
+            Ok(TimeResult {
+                timetoken: 16834217349830546
+            })
+        );
+    }
+
+    #[test]
+    fn return_error_on_empty_response() {
+        let result: Result<TimeResult, PubNubError> = TimeResponseBody(vec![]).try_into();
+
+        assert!(matches!(result, Err(PubNubError::Deserialization { .. })));
+    }
+}
@@ -188,8 +188,10 @@
 //! | `tokio`       | Enables the [tokio](https://tokio.rs/) asynchronous runtime for Subscribe and Presence APIs | n/a  |
 //! | `serde`       | Uses [serde](https://github.com/serde-rs/serde) for serialization | n/a |
 //! | `reqwest`     | Uses [reqwest](https://github.com/seanmonstar/reqwest) as a transport layer | n/a |
+//! | `hyper`       | Uses [hyper](https://github.com/hyperium/hyper) as a transport layer (not part of `full`) | n/a |
 //! | `blocking`    | Enables blocking executions of APIs | n/a |
 //! | `crypto`      | Enables crypto module for data encryption and decryption | n/a |
+//! | `compression` | Enables gzip compression of published messages and decoding of compressed responses (not part of `full`) | n/a |
 //! | `std`         | Enables `std` library | n/a |
 //!
 //! ## Documentation
@@ -233,6 +235,7 @@
 //! * partially `presence` module (because of the spawning tasks and time
 //!   dependence)
 //! * `std` feature (because of the `std` library)
+//! * `compression` feature (because of the `std` library)
 //!
 //! We depend on a random number generator to generate data for debugging
 //! purposes. If you want to use the SDK in a `no_std` environment, you'll have
@@ -271,6 +274,30 @@ pub use dx::{parse_token, Token};
 #[doc(inline)]
 pub use dx::publish;
 
+#[cfg(feature = "publish")]
+#[doc(inline)]
+pub use dx::signal;
+
+#[cfg(all(feature = "publish", feature = "subscribe", feature = "serde"))]
+#[doc(inline)]
+pub use dx::message_actions;
+
+#[cfg(all(feature = "publish", feature = "subscribe"))]
+#[doc(inline)]
+pub use dx::history;
+
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use dx::app_context;
+
+#[cfg(feature = "subscribe")]
+#[doc(inline)]
+pub use dx::channel_groups;
+
+#[cfg(all(feature = "publish", feature = "serde"))]
+#[doc(inline)]
+pub use dx::files;
+
 #[cfg(feature = "subscribe")]
 #[doc(inline)]
 pub use dx::subscribe;
@@ -279,6 +306,9 @@ pub use dx::subscribe;
 #[doc(inline)]
 pub use dx::presence;
 
+#[doc(inline)]
+pub use dx::time;
+
 #[doc(inline)]
 pub use dx::{Keyset, PubNubClientBuilder, PubNubGenericClient};
 
@@ -0,0 +1,215 @@
+//! # AES-GCM cryptor module.
+//!
+//! Module contains [`AesGcmCryptor`] type which can be used for authenticated
+//! data encryption and decryption.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    core::{Cryptor, EncryptedData, PubNubError},
+    lib::alloc::{format, string::ToString, vec::Vec},
+};
+
+/// Unique cryptor identifier
+const IDENTIFIER: [u8; 4] = *b"AGCM";
+
+/// AES-GCM nonce size.
+const NONCE_SIZE: usize = 12;
+
+/// AES-GCM cryptor.
+///
+/// Random nonce generated for each encrypted payload and passed along with
+/// encrypted data as cryptor-defined metadata.
+#[derive(Debug)]
+pub struct AesGcmCryptor {
+    /// Key for data _encryption_ / _decryption_.
+    cipher_key: Vec<u8>,
+}
+
+impl AesGcmCryptor {
+    /// Create AES-GCM cryptor.
+    pub fn new<K>(cipher_key: K) -> Result<Self, PubNubError>
+    where
+        K: Into<Vec<u8>>,
+    {
+        let cipher_key = cipher_key.into();
+
+        if cipher_key.is_empty() {
+            return Err(PubNubError::CryptoInitialization {
+                details: "Cipher key is empty".into(),
+            });
+        }
+
+        Ok(Self {
+            cipher_key: Self::sha256(cipher_key),
+        })
+    }
+
+    /// Generate fresh random nonce.
+    ///
+    /// Nonce reuse with the same key breaks AES-GCM confidentiality and
+    /// authenticity, so encryption can't proceed without random source.
+    fn nonce(&self) -> Result<[u8; NONCE_SIZE], PubNubError> {
+        let mut random = [0u8; NONCE_SIZE];
+        getrandom::getrandom(&mut random).map_err(|err| PubNubError::Encryption {
+            details: format!("Unable to generate nonce: {err}"),
+        })?;
+
+        Ok(random)
+    }
+
+    fn cipher(&self) -> Result<Aes256Gcm, PubNubError> {
+        Aes256Gcm::new_from_slice(&self.cipher_key).map_err(|err| {
+            PubNubError::CryptoInitialization {
+                details: err.to_string(),
+            }
+        })
+    }
+
+    fn sha256(data: Vec<u8>) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(data.as_slice());
+        hasher.finalize().to_vec()
+    }
+}
+
+impl Cryptor for AesGcmCryptor {
+    fn identifier(&self) -> [u8; 4] {
+        IDENTIFIER
+    }
+
+    fn encrypt(&self, data: Vec<u8>) -> Result<EncryptedData, PubNubError> {
+        if data.is_empty() {
+            return Err(PubNubError::Encryption {
+                details: "Encrypted data is empty".into(),
+            });
+        }
+
+        let nonce = self.nonce()?;
+        let encrypted = self
+            .cipher()?
+            .encrypt(Nonce::from_slice(&nonce), data.as_slice())
+            .map_err(|err| PubNubError::Encryption {
+                details: err.to_string(),
+            })?;
+
+        Ok(EncryptedData {
+            metadata: Some(nonce.to_vec()),
+            data: encrypted,
+        })
+    }
+
+    fn decrypt(&self, data: EncryptedData) -> Result<Vec<u8>, PubNubError> {
+        let Some(nonce) = data.metadata else {
+            return Err(PubNubError::Decryption {
+                details: "Nonce is missing from payload".into(),
+            });
+        };
+
+        if nonce.len().ne(&NONCE_SIZE) {
+            return Err(PubNubError::Decryption {
+                details: format!(
+                    "Unexpected nonce size: {} bytes ({} bytes is expected)",
+                    nonce.len(),
+                    NONCE_SIZE
+                ),
+            });
+        }
+
+        // Error returned on authentication tag mismatch doesn't contain any
+        // details.
+        self.cipher()?
+            .decrypt(Nonce::from_slice(&nonce), data.data.as_slice())
+            .map_err(|_| PubNubError::Decryption {
+                details: "Data authentication failed".into(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod it_should {
+    use super::*;
+
+    #[test]
+    fn create_cryptor() {
+        let cryptor = AesGcmCryptor::new("enigma").expect("Cryptor should be created");
+        assert_ne!(cryptor.nonce().unwrap(), cryptor.nonce().unwrap());
+    }
+
+    #[test]
+    fn not_create_cryptor_with_empty_cipher_key() {
+        let cryptor = AesGcmCryptor::new("");
+        assert!(matches!(
+            cryptor,
+            Err(PubNubError::CryptoInitialization { .. })
+        ));
+    }
+
+    #[test]
+    fn encrypt_data() {
+        let cryptor = AesGcmCryptor::new("enigma").expect("Cryptor should be created");
+        let encrypted1 = cryptor
+            .encrypt(Vec::from("\"Hello there 🙃\""))
+            .expect("Data should be encrypted");
+        let encrypted2 = cryptor
+            .encrypt(Vec::from("\"Hello there 🙃\""))
+            .expect("Data should be encrypted");
+        assert_ne!(encrypted1.metadata, encrypted2.metadata);
+        assert_ne!(encrypted1.data, encrypted2.data);
+    }
+
+    #[test]
+    fn decrypt_encrypted_data() {
+        let cryptor = AesGcmCryptor::new("enigma").expect("Cryptor should be created");
+        let encrypted = cryptor
+            .encrypt(Vec::from("\"Hello there 🙃\""))
+            .expect("Data should be encrypted");
+        let decrypted = cryptor
+            .decrypt(encrypted)
+            .expect("Data should be decrypted");
+        assert_eq!(decrypted, "\"Hello there 🙃\"".as_bytes());
+    }
+
+    #[test]
+    fn not_decrypt_tampered_data() {
+        let cryptor = AesGcmCryptor::new("enigma").expect("Cryptor should be created");
+        let mut encrypted = cryptor
+            .encrypt(Vec::from("\"Hello there 🙃\""))
+            .expect("Data should be encrypted");
+        encrypted.data[0] ^= 0x01;
+
+        assert!(matches!(
+            cryptor.decrypt(encrypted),
+            Err(PubNubError::Decryption { .. })
+        ));
+    }
+
+    #[test]
+    fn not_decrypt_with_different_cipher_key() {
+        let encrypted = AesGcmCryptor::new("enigma")
+            .expect("Cryptor should be created")
+            .encrypt(Vec::from("\"Hello there 🙃\""))
+            .expect("Data should be encrypted");
+        let cryptor = AesGcmCryptor::new("secret").expect("Cryptor should be created");
+
+        assert!(matches!(
+            cryptor.decrypt(encrypted),
+            Err(PubNubError::Decryption { .. })
+        ));
+    }
+
+    #[test]
+    fn not_decrypt_without_nonce() {
+        let cryptor = AesGcmCryptor::new("enigma").expect("Cryptor should be created");
+        let result = cryptor.decrypt(EncryptedData {
+            metadata: None,
+            data: Vec::from("data"),
+        });
+
+        assert!(matches!(result, Err(PubNubError::Decryption { .. })));
+    }
+}
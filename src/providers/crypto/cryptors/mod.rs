@@ -3,6 +3,7 @@
 //! The module provides [`Cryptor`] trait implementations:
 //! * [`LegacyCryptor`]
 //! * [`AesCbcCryptor`]
+//! * [`AesGcmCryptor`]
 //!
 //! Actual implementations can be used to configure [`CryptoProvider`]
 //! implementations for standalone usage or as part of [`PubNubClientInstance`]
@@ -15,3 +16,7 @@ pub mod legacy;
 #[doc(inline)]
 pub use aes_cbc::AesCbcCryptor;
 pub mod aes_cbc;
+
+#[doc(inline)]
+pub use aes_gcm::AesGcmCryptor;
+pub mod aes_gcm;
@@ -1,12 +1,12 @@
 //! Crypto module
 //!
 //! This module contains a [`CryptoModule`] which allows to handle encrypted
-//! data in backward compatible way. [`AesCbcCryptor`] and [`LegacyCryptor`]
-//! cryptors available for [`CryptoModule`] configuration for data _encryption_
-//! and _decryption_.
+//! data in backward compatible way. [`AesGcmCryptor`], [`AesCbcCryptor`] and
+//! [`LegacyCryptor`] cryptors available for [`CryptoModule`] configuration for
+//! data _encryption_ and _decryption_.
 
 #[doc(inline)]
-pub use cryptors::{AesCbcCryptor, LegacyCryptor};
+pub use cryptors::{AesCbcCryptor, AesGcmCryptor, LegacyCryptor};
 pub mod cryptors;
 
 #[doc(inline)]
@@ -53,6 +53,35 @@ impl CryptoModule {
         ))
     }
 
+    /// AES-GCM cryptor based module.
+    ///
+    /// Data _encryption_ and _decryption_ will be done by default using the
+    /// [`AesGcmCryptor`]. In addition to the [`AesGcmCryptor`] for data
+    /// _decryption_, the [`AesCbcCryptor`] and [`LegacyCryptor`] will be
+    /// registered for migration from previously encrypted data.
+    ///
+    /// Returns error if `cipher_key` is empty.
+    pub fn new_aes_gcm_module<K>(cipher_key: K, use_random_iv: bool) -> Result<Self, PubNubError>
+    where
+        K: Into<Vec<u8>>,
+    {
+        let cipher_key = cipher_key.into();
+
+        if cipher_key.is_empty() {
+            return Err(PubNubError::CryptoInitialization {
+                details: "Cipher key is empty".into(),
+            });
+        }
+
+        Ok(Self::new(
+            Box::new(AesGcmCryptor::new(cipher_key.clone())?),
+            Some(vec![
+                Box::new(AesCbcCryptor::new(cipher_key.clone())?),
+                Box::new(LegacyCryptor::new(cipher_key, use_random_iv)?),
+            ]),
+        ))
+    }
+
     /// Legacy AES-CBC cryptor based module.
     ///
     /// Data _encryption_ and _decryption_ will be done by default using the
@@ -83,6 +112,7 @@ impl CryptoModule {
 #[cfg(test)]
 mod it_should {
     use super::*;
+    use crate::core::CryptoProvider;
 
     #[test]
     fn not_create_legacy_module_with_empty_cipher_key() {
@@ -95,4 +125,33 @@ mod it_should {
         let crypto_module = CryptoModule::new_aes_cbc_module("", false);
         assert!(crypto_module.is_err());
     }
+
+    #[test]
+    fn not_create_aes_gcm_module_with_empty_cipher_key() {
+        let crypto_module = CryptoModule::new_aes_gcm_module("", false);
+        assert!(matches!(
+            crypto_module,
+            Err(PubNubError::CryptoInitialization { .. })
+        ));
+    }
+
+    #[test]
+    fn decrypt_data_encrypted_by_previous_cryptors_with_aes_gcm_module() {
+        let aes_gcm_module =
+            CryptoModule::new_aes_gcm_module("enigma", true).expect("Module should be created");
+        let aes_cbc_module =
+            CryptoModule::new_aes_cbc_module("enigma", true).expect("Module should be created");
+        let legacy_module =
+            CryptoModule::new_legacy_module("enigma", true).expect("Module should be created");
+
+        for module in [&aes_gcm_module, &aes_cbc_module, &legacy_module] {
+            let encrypted = module
+                .encrypt(Vec::from("\"Hello there 🙃\""))
+                .expect("Data should be encrypted");
+            let decrypted = aes_gcm_module
+                .decrypt(encrypted)
+                .expect("Data should be decrypted");
+            assert_eq!(decrypted, "\"Hello there 🙃\"".as_bytes());
+        }
+    }
 }
@@ -0,0 +1,581 @@
+//! # Hyper Transport Implementation
+//!
+//! This module contains the [`TransportHyper`] struct.
+//! It is used to send requests to the [`PubNub API`] using the [`hyper`]
+//! crate directly (without [`reqwest`] on top of it). It is intended to be
+//! used by the [`pubnub`] crate.
+//!
+//! It requires the [`hyper` feature] to be enabled.
+//!
+//! [`TransportHyper`]: ./struct.TransportHyper.html
+//! [`PubNub API`]: https://www.pubnub.com/docs
+//! [`hyper`]: https://docs.rs/hyper
+//! [`reqwest`]: https://docs.rs/reqwest
+//! [`pubnub`]: ../index.html
+//! [`hyper` feature]: ../index.html#features
+
+#[cfg(not(any(feature = "subscribe", feature = "presence")))]
+use crate::dx::pubnub_client::PubNubClientDeserializerBuilder;
+
+#[cfg(any(feature = "subscribe", feature = "presence"))]
+use crate::dx::pubnub_client::PubNubClientRuntimeBuilder;
+
+use crate::{
+    core::{
+        error::PubNubError, transport::PUBNUB_DEFAULT_BASE_URL, Transport, TransportMethod,
+        TransportRequest, TransportResponse,
+    },
+    lib::{
+        alloc::{
+            boxed::Box,
+            format,
+            string::{String, ToString},
+        },
+        collections::HashMap,
+    },
+    PubNubClientBuilder,
+};
+use core::time::Duration;
+use hyper::{
+    client::HttpConnector,
+    header::{HeaderName, HeaderValue},
+    Body, Client, HeaderMap, Method, Request,
+};
+use hyper_tls::HttpsConnector;
+use log::info;
+
+/// This struct is used to send requests to the [`PubNub API`] using the
+/// [`hyper`] crate. It is used as the transport type for the
+/// [`PubNubClient`] when [`reqwest`] dependency should be avoided.
+///
+/// [`PubNubClient`]: ../../dx/pubnub_client/struct.PubNubClientInstance.html
+/// [`PubNub API`]: https://www.pubnub.com/docs
+/// [`hyper`]: https://docs.rs/hyper
+/// [`reqwest`]: https://docs.rs/reqwest
+#[derive(Clone, Debug)]
+pub struct TransportHyper {
+    hyper_client: Client<HttpsConnector<HttpConnector>>,
+
+    /// Timeout which is used for requests which doesn't specify own timeout.
+    request_timeout: Duration,
+
+    /// Timeout for connection establishment.
+    connect_timeout: Duration,
+
+    /// The hostname to use for requests.
+    /// It is used as the base URL for all requests.
+    ///
+    /// It defaults to `https://ps.pndsn.com/`.
+    /// # Examples
+    /// ```
+    /// use pubnub::transport::TransportHyper;
+    ///
+    /// let transport = {
+    ///    let mut transport = TransportHyper::default();
+    ///    transport.hostname = "https://wherever.you.want.com".into();
+    ///    transport
+    /// };
+    /// ```
+    pub hostname: String,
+}
+
+#[async_trait::async_trait]
+impl Transport for TransportHyper {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        send_with_client(
+            &self.hyper_client,
+            &self.hostname,
+            self.request_timeout,
+            request,
+        )
+        .await
+    }
+}
+
+impl Default for TransportHyper {
+    fn default() -> Self {
+        Self::with_timeouts(DEFAULT_REQUEST_TIMEOUT, DEFAULT_CONNECT_TIMEOUT)
+    }
+}
+
+impl TransportHyper {
+    /// Create a new [`TransportHyper`] instance.
+    ///
+    /// It provides a [`hyper`] client with HTTPS support and a default
+    /// hostname of `https://ps.pndsn.com`.
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::transport::TransportHyper;
+    ///
+    /// let transport = TransportHyper::new();
+    /// ```
+    ///
+    /// [`hyper`]: https://docs.rs/hyper
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new [`TransportHyper`] instance with custom timeouts.
+    ///
+    /// `request_timeout` is used only for requests which doesn't specify own
+    /// timeout. `connect_timeout` limits time which can be spent to establish
+    /// connection with [`PubNub API`].
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::transport::TransportHyper;
+    /// use std::time::Duration;
+    ///
+    /// let transport = TransportHyper::with_timeouts(
+    ///     Duration::from_secs(15),
+    ///     Duration::from_secs(5),
+    /// );
+    /// ```
+    ///
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    pub fn with_timeouts(request_timeout: Duration, connect_timeout: Duration) -> Self {
+        Self {
+            hyper_client: create_client(connect_timeout, true),
+            request_timeout,
+            connect_timeout,
+            hostname: PUBNUB_DEFAULT_BASE_URL.into(),
+        }
+    }
+}
+
+/// Default timeout for requests which doesn't specify own timeout.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default timeout for connection establishment.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Create [`hyper`] client which is able to send requests over HTTP and
+/// HTTPS.
+///
+/// Connections pool can be disabled for clients which is used with
+/// short-living runtime.
+///
+/// [`hyper`]: https://docs.rs/hyper
+fn create_client(connect_timeout: Duration, pooled: bool) -> Client<HttpsConnector<HttpConnector>> {
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+    http.set_connect_timeout(Some(connect_timeout));
+
+    let mut builder = Client::builder();
+    if !pooled {
+        builder.pool_max_idle_per_host(0);
+    }
+
+    builder.build(HttpsConnector::new_with_connector(http))
+}
+
+async fn send_with_client(
+    client: &Client<HttpsConnector<HttpConnector>>,
+    hostname: &str,
+    request_timeout: Duration,
+    request: TransportRequest,
+) -> Result<TransportResponse, PubNubError> {
+    let request_url = request.url(hostname);
+    info!(
+        "Sending data to pubnub: {} {:?} {}",
+        request.method, request.headers, request_url
+    );
+
+    let timeout = if request.timeout.gt(&0) {
+        Duration::from_secs(request.timeout)
+    } else {
+        request_timeout
+    };
+    let path = request.path.clone();
+    let hyper_request = prepare_request(request, request_url)?;
+
+    let response = tokio::time::timeout(timeout, async {
+        let response = client.request(hyper_request).await?;
+        let (parts, body) = response.into_parts();
+        hyper::body::to_bytes(body)
+            .await
+            .map(|bytes| (parts, bytes))
+    })
+    .await
+    .map_err(|_| PubNubError::Timeout { endpoint: path })?
+    .map_err(PubNubError::from)?;
+
+    let (parts, body) = response;
+    Ok(TransportResponse {
+        status: parts.status.as_u16(),
+        body: (!body.is_empty()).then(|| body.to_vec()),
+        headers: extract_headers(&parts.headers),
+    })
+}
+
+pub(crate) fn prepare_request(
+    request: TransportRequest,
+    url: String,
+) -> Result<Request<Body>, PubNubError> {
+    let (method, body) = match request.method {
+        TransportMethod::Get => (Method::GET, Body::empty()),
+        TransportMethod::Post => (
+            Method::POST,
+            request.body.map(Body::from).ok_or(PubNubError::Transport {
+                details: "Body should not be empty for POST".into(),
+                status: None,
+                response: None,
+            })?,
+        ),
+        TransportMethod::Delete => (Method::DELETE, Body::empty()),
+        TransportMethod::Patch => (
+            Method::PATCH,
+            request.body.map(Body::from).ok_or(PubNubError::Transport {
+                details: "Body should not be empty for PATCH".into(),
+                status: None,
+                response: None,
+            })?,
+        ),
+        TransportMethod::Head => (Method::HEAD, Body::empty()),
+    };
+
+    let mut hyper_request = Request::builder()
+        .method(method)
+        .uri(url)
+        .body(body)
+        .map_err(|err| PubNubError::Transport {
+            details: err.to_string(),
+            status: None,
+            response: None,
+        })?;
+    *hyper_request.headers_mut() = prepare_headers(&request.headers)?;
+
+    Ok(hyper_request)
+}
+
+fn prepare_headers(request_headers: &HashMap<String, String>) -> Result<HeaderMap, PubNubError> {
+    request_headers
+        .iter()
+        .map(|(k, v)| -> Result<(HeaderName, HeaderValue), PubNubError> {
+            let name = HeaderName::try_from(k).map_err(|err| PubNubError::Transport {
+                details: err.to_string(),
+                status: None,
+                response: None,
+            })?;
+            let value = HeaderValue::try_from(v).map_err(|err| PubNubError::Transport {
+                details: err.to_string(),
+                status: None,
+                response: None,
+            })?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
+pub(crate) fn extract_headers(headers: &HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .fold(HashMap::new(), |mut acc, (name, value)| {
+            if let Ok(value) = value.to_str() {
+                acc.insert(name.to_string(), value.to_string());
+            }
+            acc
+        })
+}
+
+impl PubNubClientBuilder {
+    /// Creates a new [`PubNubClientBuilder`] with the default
+    /// [`TransportHyper`] transport. The default transport uses the
+    /// [`hyper`] crate to send requests to the [`PubNub API`]. The default
+    /// hostname is `https://ps.pndsn.com`.
+    ///
+    /// # Examples
+    /// ```
+    /// use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// let client = PubNubClientBuilder::with_hyper_transport()
+    ///     .with_keyset(Keyset {
+    ///         subscribe_key: "sub-c-abc123",
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("user-123")
+    ///     .build();
+    /// ```
+    ///
+    /// [`TransportHyper`]: ./struct.TransportHyper.html
+    /// [`hyper`]: https://docs.rs/hyper
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    #[cfg(any(feature = "subscribe", feature = "presence"))]
+    pub fn with_hyper_transport() -> PubNubClientRuntimeBuilder<TransportHyper> {
+        PubNubClientRuntimeBuilder {
+            transport: TransportHyper::new(),
+        }
+    }
+
+    /// Creates a new [`PubNubClientBuilder`] with the default
+    /// [`TransportHyper`] transport. The default transport uses the
+    /// [`hyper`] crate to send requests to the [`PubNub API`]. The default
+    /// hostname is `https://ps.pndsn.com`.
+    ///
+    /// # Examples
+    /// ```
+    /// use pubnub::{PubNubClientBuilder, Keyset};
+    ///
+    /// let client = PubNubClientBuilder::with_hyper_transport()
+    ///     .with_keyset(Keyset {
+    ///         subscribe_key: "sub-c-abc123",
+    ///         publish_key: Some("pub-c-abc123"),
+    ///         secret_key: None,
+    ///     })
+    ///     .with_user_id("user-123")
+    ///     .build();
+    /// ```
+    ///
+    /// [`TransportHyper`]: ./struct.TransportHyper.html
+    /// [`hyper`]: https://docs.rs/hyper
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    #[cfg(not(any(feature = "subscribe", feature = "presence")))]
+    pub fn with_hyper_transport() -> PubNubClientDeserializerBuilder<TransportHyper> {
+        PubNubClientDeserializerBuilder {
+            transport: TransportHyper::new(),
+        }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl crate::core::blocking::Transport for TransportHyper {
+    /// Send request in blocking manner.
+    ///
+    /// Request is sent using dedicated single-threaded runtime, so it can't be
+    /// called from within asynchronous context.
+    fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|err| PubNubError::Transport {
+                details: format!("Unable to create runtime: {err}"),
+                status: None,
+                response: None,
+            })?;
+
+        // Pooled connections are bound to the runtime which created them and
+        // become unusable after runtime shutdown.
+        let client = create_client(self.connect_timeout, false);
+        runtime.block_on(send_with_client(
+            &client,
+            &self.hostname,
+            self.request_timeout,
+            request,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Response, Server, StatusCode,
+    };
+    use std::{convert::Infallible, net::SocketAddr};
+
+    /// Start local [`hyper`] server which echoes request method, path, query
+    /// and body.
+    ///
+    /// Server is started on separate thread to be usable from both
+    /// asynchronous and blocking tests.
+    fn start_echo_server() -> SocketAddr {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+
+            runtime.block_on(async move {
+                let service = make_service_fn(|_| async {
+                    Ok::<_, Infallible>(service_fn(|request: Request<Body>| async move {
+                        if request.uri().path() == "/delayed" {
+                            tokio::time::sleep(Duration::from_secs(2)).await;
+                        }
+
+                        let (parts, body) = request.into_parts();
+                        let body = hyper::body::to_bytes(body).await.unwrap();
+                        let response = Response::builder()
+                            .status(StatusCode::CREATED)
+                            .header("x-echo-method", parts.method.as_str())
+                            .header("x-echo-path", parts.uri.path())
+                            .header("x-echo-query", parts.uri.query().unwrap_or_default())
+                            .header(
+                                "x-echo-test",
+                                parts
+                                    .headers
+                                    .get("x-test")
+                                    .cloned()
+                                    .unwrap_or(HeaderValue::from_static("")),
+                            )
+                            .body(Body::from(body))
+                            .unwrap();
+
+                        Ok::<_, Infallible>(response)
+                    }))
+                });
+
+                let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(service);
+                sender.send(server.local_addr()).unwrap();
+                server.await.unwrap();
+            });
+        });
+
+        receiver.recv().unwrap()
+    }
+
+    fn transport(address: SocketAddr) -> TransportHyper {
+        TransportHyper {
+            hostname: format!("http://{address}"),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn send_via_get_method() {
+        let transport = transport(start_echo_server());
+
+        let request = TransportRequest {
+            path: "/path/%22Hello%22".into(),
+            query_parameters: [("uuid".into(), "Phoenix".into())].into(),
+            headers: [("x-test".into(), "value".into())].into(),
+            method: TransportMethod::Get,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.status, 201);
+        assert_eq!(response.headers["x-echo-method"], "GET");
+        assert_eq!(response.headers["x-echo-path"], "/path/%22Hello%22");
+        assert_eq!(response.headers["x-echo-query"], "uuid=Phoenix");
+        assert_eq!(response.headers["x-echo-test"], "value");
+        assert_eq!(response.body, None);
+    }
+
+    #[tokio::test]
+    async fn send_via_post_method() {
+        let transport = transport(start_echo_server());
+
+        let request = TransportRequest {
+            path: "/publish".into(),
+            method: TransportMethod::Post,
+            body: Some(b"{\"hello\":\"world\"}".to_vec()),
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.status, 201);
+        assert_eq!(response.headers["x-echo-method"], "POST");
+        assert_eq!(response.body, Some(b"{\"hello\":\"world\"}".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn send_via_delete_method() {
+        let transport = transport(start_echo_server());
+
+        let request = TransportRequest {
+            path: "/resource".into(),
+            method: TransportMethod::Delete,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.headers["x-echo-method"], "DELETE");
+    }
+
+    #[tokio::test]
+    async fn send_via_patch_method() {
+        let transport = transport(start_echo_server());
+
+        let request = TransportRequest {
+            path: "/v2/objects/sub_key/uuids/user".into(),
+            method: TransportMethod::Patch,
+            body: Some(b"{\"name\":\"user\"}".to_vec()),
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.headers["x-echo-method"], "PATCH");
+        assert_eq!(response.body, Some(b"{\"name\":\"user\"}".to_vec()));
+    }
+
+    #[tokio::test]
+    async fn send_via_head_method() {
+        let transport = transport(start_echo_server());
+
+        let request = TransportRequest {
+            path: "/time/0".into(),
+            method: TransportMethod::Head,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.headers["x-echo-method"], "HEAD");
+        assert_eq!(response.body, None);
+    }
+
+    #[tokio::test]
+    async fn return_err_on_post_empty_body() {
+        let transport = TransportHyper::default();
+
+        let request = TransportRequest {
+            path: "/publish".into(),
+            method: TransportMethod::Post,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            transport.send(request).await,
+            Err(PubNubError::Transport { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn return_timeout_err_on_delayed_response() {
+        let transport = transport(start_echo_server());
+
+        let request = TransportRequest {
+            path: "/delayed".into(),
+            method: TransportMethod::Get,
+            timeout: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transport.send(request).await,
+            Err(PubNubError::Timeout {
+                endpoint: "/delayed".into()
+            })
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn send_in_blocking_manner() {
+        let transport = transport(start_echo_server());
+
+        for _ in 0..2 {
+            let request = TransportRequest {
+                path: "/publish".into(),
+                method: TransportMethod::Post,
+                body: Some(b"[1,2,3]".to_vec()),
+                ..Default::default()
+            };
+
+            let response = crate::core::blocking::Transport::send(&transport, request).unwrap();
+
+            assert_eq!(response.status, 201);
+            assert_eq!(response.headers["x-echo-method"], "POST");
+            assert_eq!(response.body, Some(b"[1,2,3]".to_vec()));
+        }
+    }
+}
@@ -4,16 +4,14 @@
 //! parameters to the requests.
 //! The middleware is used to add the `pnsdk`, `uuid`, `instanceid` and
 //! `requestid` query parameters to the requests.
+//! Optionally, it logs requests and responses with redacted credentials.
 
 #[cfg(feature = "std")]
-use crate::{
-    core::{utils::encoding::url_encode, TransportMethod},
-    lib::{alloc::vec::Vec, collections::HashMap},
-};
+use crate::core::utils::encoding::url_encode;
 use crate::{
     core::{
         utils::metadata::{PKG_VERSION, RUSTC_VERSION, SDK_ID, TARGET},
-        PubNubError, Transport, TransportRequest, TransportResponse,
+        PubNubError, Transport, TransportMethod, TransportRequest, TransportResponse,
     },
     lib::{
         alloc::{
@@ -21,7 +19,9 @@ use crate::{
             format,
             string::{String, ToString},
             sync::Arc,
+            vec::Vec,
         },
+        collections::HashMap,
         core::ops::Deref,
     },
 };
@@ -31,10 +31,15 @@ use base64::{engine::general_purpose, Engine as _};
 use hmac::{Hmac, Mac};
 #[cfg(feature = "std")]
 use sha2::Sha256;
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+use std::time::Instant;
 #[cfg(feature = "std")]
 use time::OffsetDateTime;
 use uuid::Uuid;
 
+/// Query parameters which values shouldn't appear in logs.
+const REDACTED_QUERY_PARAMETERS: [&str; 2] = ["auth", "signature"];
+
 /// PubNub middleware.
 ///
 /// This middleware is used to add the required query parameters to the requests.
@@ -59,6 +64,81 @@ pub struct PubNubMiddleware<T> {
     pub(crate) auth_token: Arc<spin::RwLock<String>>,
     #[cfg_attr(not(feature = "std"), allow(dead_code))]
     pub(crate) signature_keys: Option<SignatureKeySet>,
+
+    /// Whether requests and responses should be logged or not.
+    pub(crate) log_requests: bool,
+}
+
+/// Logged request information.
+///
+/// Used to match response with request for which it has been received.
+struct RequestLogEntry {
+    method: TransportMethod,
+    path: String,
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    started: Instant,
+}
+
+impl RequestLogEntry {
+    /// Log outgoing request.
+    fn new(request: &TransportRequest) -> Self {
+        log::debug!(
+            "Sending request: {} {}{}",
+            request.method,
+            request.path,
+            Self::redacted_query(&request.query_parameters)
+        );
+
+        Self {
+            method: request.method.clone(),
+            path: request.path.clone(),
+            #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+            started: Instant::now(),
+        }
+    }
+
+    /// Log response (or error) received for request.
+    fn finish(self, result: &Result<TransportResponse, PubNubError>) {
+        #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+        let latency = format!(" in {}ms", self.started.elapsed().as_millis());
+        #[cfg(not(all(feature = "std", not(target_arch = "wasm32"))))]
+        let latency = String::new();
+
+        match result {
+            Ok(response) => log::debug!(
+                "Received response: {} {} {}{latency}",
+                self.method,
+                self.path,
+                response.status
+            ),
+            Err(error) => log::debug!(
+                "Request failed: {} {}{latency}: {error}",
+                self.method,
+                self.path
+            ),
+        }
+    }
+
+    /// Serialize query parameters with redacted credentials.
+    fn redacted_query(query_parameters: &HashMap<String, String>) -> String {
+        if query_parameters.is_empty() {
+            return String::new();
+        }
+
+        let mut query = query_parameters
+            .iter()
+            .map(|(key, value)| {
+                if REDACTED_QUERY_PARAMETERS.contains(&key.as_str()) {
+                    format!("{key}=[REDACTED]")
+                } else {
+                    format!("{key}={value}")
+                }
+            })
+            .collect::<Vec<String>>();
+        query.sort_unstable();
+
+        format!("?{}", query.join("&"))
+    }
 }
 
 #[derive(Debug)]
@@ -72,12 +152,15 @@ pub(crate) struct SignatureKeySet {
 #[cfg(feature = "std")]
 impl SignatureKeySet {
     fn handle_query_params(query_parameters: &HashMap<String, String>) -> String {
-        let mut query_params_str = query_parameters
-            .iter()
+        // Parameters should be sorted by name (not by `name=value` pair),
+        // otherwise names which are prefix of other names may be misplaced.
+        let mut query_params = query_parameters.iter().collect::<Vec<_>>();
+        query_params.sort_unstable_by_key(|(key, _)| *key);
+        query_params
+            .into_iter()
             .map(|(key, value)| format!("{}={}", key, url_encode(value.as_bytes())))
-            .collect::<Vec<String>>();
-        query_params_str.sort_unstable();
-        query_params_str.join("&")
+            .collect::<Vec<String>>()
+            .join("&")
     }
 
     fn prepare_signature_v1_input(&self, req: &TransportRequest) -> String {
@@ -123,6 +206,12 @@ impl SignatureKeySet {
 
 impl<T> PubNubMiddleware<T> {
     fn prepare_request(&self, mut req: TransportRequest) -> Result<TransportRequest, PubNubError> {
+        // External services (like file storage) shouldn't receive PubNub
+        // metadata and credentials.
+        if req.is_absolute() {
+            return Ok(req);
+        }
+
         req.query_parameters
             .insert("requestid".into(), Uuid::new_v4().to_string());
 
@@ -134,7 +223,8 @@ impl<T> PubNubMiddleware<T> {
 
         if let Some(instance_id) = self.instance_id.as_deref() {
             req.query_parameters
-                .insert("instanceid".into(), instance_id.into());
+                .entry("instanceid".into())
+                .or_insert(instance_id.into());
         }
 
         // Adding access token or authorization key.
@@ -173,9 +263,15 @@ where
     T: Transport,
 {
     async fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
-        self.prepare_request(req)
-            .map(|req| self.transport.send(req))?
-            .await
+        let req = self.prepare_request(req)?;
+        if !self.log_requests {
+            return self.transport.send(req).await;
+        }
+
+        let log_entry = RequestLogEntry::new(&req);
+        let result = self.transport.send(req).await;
+        log_entry.finish(&result);
+        result
     }
 }
 
@@ -185,8 +281,15 @@ where
     T: crate::core::blocking::Transport,
 {
     fn send(&self, req: TransportRequest) -> Result<TransportResponse, PubNubError> {
-        self.prepare_request(req)
-            .and_then(|req| self.transport.send(req))
+        let req = self.prepare_request(req)?;
+        if !self.log_requests {
+            return self.transport.send(req);
+        }
+
+        let log_entry = RequestLogEntry::new(&req);
+        let result = self.transport.send(req);
+        log_entry.finish(&result);
+        result
     }
 }
 
@@ -197,6 +300,7 @@ mod should {
     #[cfg(feature = "std")]
     use crate::{core::TransportMethod::Get, lib::collections::HashMap};
     use spin::rwlock::RwLock;
+    use test_case::test_case;
 
     #[tokio::test]
     async fn include_pubnub_metadata() {
@@ -239,6 +343,7 @@ mod should {
             signature_keys: None,
             auth_token: Arc::new(RwLock::new(String::new())),
             auth_key: None,
+            log_requests: false,
         };
 
         let result = middleware.send(TransportRequest::default()).await;
@@ -246,6 +351,176 @@ mod should {
         assert!(result.is_ok());
     }
 
+    #[test_case("/v2/subscribe/sub_key/my_channel/0" ; "subscribe request")]
+    #[test_case("/publish/pub_key/sub_key/0/my_channel/0/%22hello%22" ; "publish request")]
+    #[tokio::test]
+    async fn include_user_and_instance_id_for_any_request(path: &str) {
+        struct MockTransport;
+
+        #[async_trait::async_trait]
+        impl Transport for MockTransport {
+            async fn send(
+                &self,
+                request: TransportRequest,
+            ) -> Result<TransportResponse, PubNubError> {
+                assert_eq!(request.query_parameters["uuid"], "user_id");
+                assert_eq!(request.query_parameters["instanceid"], "instance_id");
+
+                Ok(TransportResponse::default())
+            }
+        }
+
+        let middleware = PubNubMiddleware {
+            transport: MockTransport,
+            instance_id: Arc::new(Some(String::from("instance_id"))),
+            user_id: String::from("user_id").into(),
+            signature_keys: None,
+            auth_token: Arc::new(RwLock::new(String::new())),
+            auth_key: None,
+            log_requests: false,
+        };
+
+        let result = middleware
+            .send(TransportRequest {
+                path: path.into(),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn not_override_present_user_and_instance_id() {
+        let middleware = PubNubMiddleware {
+            transport: (),
+            instance_id: Arc::new(Some(String::from("instance_id"))),
+            user_id: String::from("user_id").into(),
+            signature_keys: None,
+            auth_token: Arc::new(RwLock::new(String::new())),
+            auth_key: None,
+            log_requests: false,
+        };
+
+        let request = middleware
+            .prepare_request(TransportRequest {
+                path: "/v2/subscribe/sub_key/my_channel/0".into(),
+                query_parameters: [
+                    ("uuid".into(), "other_user_id".into()),
+                    ("instanceid".into(), "other_instance_id".into()),
+                ]
+                .into(),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(request.query_parameters["uuid"], "other_user_id");
+        assert_eq!(request.query_parameters["instanceid"], "other_instance_id");
+    }
+
+    #[test]
+    fn not_add_metadata_to_external_requests() {
+        let middleware = PubNubMiddleware {
+            transport: (),
+            instance_id: Arc::new(Some(String::from("instance_id"))),
+            user_id: String::from("user_id").into(),
+            signature_keys: None,
+            auth_token: Arc::new(RwLock::new(String::from("token"))),
+            auth_key: None,
+            log_requests: false,
+        };
+
+        let request = middleware
+            .prepare_request(TransportRequest {
+                path: "https://files.example.com/bucket".into(),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert!(request.query_parameters.is_empty());
+        assert!(request.headers.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn log_requests_with_redacted_credentials() {
+        static RECORDS: spin::Mutex<Vec<String>> = spin::Mutex::new(Vec::new());
+        static LOGGER: CapturingLogger = CapturingLogger;
+
+        struct CapturingLogger;
+
+        impl log::Log for CapturingLogger {
+            fn enabled(&self, metadata: &log::Metadata) -> bool {
+                metadata.level() <= log::Level::Debug
+            }
+
+            fn log(&self, record: &log::Record) {
+                RECORDS.lock().push(record.args().to_string());
+            }
+
+            fn flush(&self) {}
+        }
+
+        struct MockTransport;
+
+        #[async_trait::async_trait]
+        impl Transport for MockTransport {
+            async fn send(&self, _: TransportRequest) -> Result<TransportResponse, PubNubError> {
+                Ok(TransportResponse {
+                    status: 200,
+                    ..Default::default()
+                })
+            }
+        }
+
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let middleware = |log_requests| PubNubMiddleware {
+            transport: MockTransport,
+            instance_id: Arc::new(None),
+            user_id: String::from("user_id").into(),
+            signature_keys: Some(SignatureKeySet {
+                secret_key: "secKey".into(),
+                publish_key: "pubKey".into(),
+                subscribe_key: "subKey".into(),
+            }),
+            auth_token: Arc::new(RwLock::new(String::new())),
+            auth_key: Some(Arc::new("secret-auth-key".into())),
+            log_requests,
+        };
+        let request = |path: &str| TransportRequest {
+            path: path.into(),
+            method: Get,
+            ..Default::default()
+        };
+
+        middleware(false)
+            .send(request("/logging/disabled"))
+            .await
+            .unwrap();
+        middleware(true)
+            .send(request("/logging/enabled"))
+            .await
+            .unwrap();
+
+        let records = RECORDS.lock().clone();
+        assert!(!records.iter().any(|r| r.contains("/logging/disabled")));
+
+        let records = records
+            .into_iter()
+            .filter(|r| r.contains("/logging/enabled"))
+            .collect::<Vec<_>>();
+        assert_eq!(records.len(), 2);
+        assert!(records[0].starts_with("Sending request: GET /logging/enabled?"));
+        assert!(records[0].contains("auth=[REDACTED]"));
+        assert!(records[0].contains("signature=[REDACTED]"));
+        assert!(records[0].contains("uuid=user_id"));
+        assert!(!records[0].contains("secret-auth-key"));
+        assert!(!records[0].contains("signature=v2."));
+        assert!(records[1].starts_with("Received response: GET /logging/enabled 200 in "));
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_signature() {
@@ -272,6 +547,47 @@ mod should {
         assert_eq!("v2.AHl5lMpzyT4qcvvlqaszCjTUqU6dPb10a4_XSaYCNIQ", signature);
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn sort_query_parameters_by_name_for_signature() {
+        let query_parameters = HashMap::from([
+            ("a-b".to_string(), "2".to_string()),
+            ("uuid".to_string(), "user id".to_string()),
+            ("a".to_string(), "1".to_string()),
+        ]);
+
+        assert_eq!(
+            SignatureKeySet::handle_query_params(&query_parameters),
+            "a=1&a-b=2&uuid=user%20id"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sign_request_with_body() {
+        let signature_key_set = SignatureKeySet {
+            secret_key: "secKey".into(),
+            publish_key: "pubKey".into(),
+            subscribe_key: "subKey".into(),
+        };
+
+        let request = TransportRequest {
+            path: "/v3/pam/subKey/grant".to_string(),
+            method: TransportMethod::Post,
+            body: Some("{\"ttl\":10}".as_bytes().to_vec()),
+            query_parameters: HashMap::from([
+                ("a-b".to_string(), "2".to_string()),
+                ("a".to_string(), "1".to_string()),
+                ("auth".to_string(), "token".to_string()),
+                ("uuid".to_string(), "userId".to_string()),
+                ("timestamp".to_string(), "1679642098".to_string()),
+            ]),
+            ..TransportRequest::default()
+        };
+        let signature = signature_key_set.calculate_signature(&request);
+        assert_eq!("v2.FDLfKt0_y9aQc9vbVnHLdeeGwer_DOr3PWOO5yQvtoU", signature);
+    }
+
     #[cfg(feature = "blocking")]
     #[test]
     fn blocking_transport() {
@@ -306,6 +622,7 @@ mod should {
             signature_keys: None,
             auth_token: Arc::new(RwLock::new(String::new())),
             auth_key: None,
+            log_requests: false,
         };
 
         let result = middleware.send(TransportRequest::default());
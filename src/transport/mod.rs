@@ -10,6 +10,18 @@ pub mod middleware;
 
 #[cfg(feature = "reqwest")]
 #[doc(inline)]
-pub use self::reqwest::TransportReqwest;
+pub use self::reqwest::{TransportReqwest, TransportReqwestBuilder};
 #[cfg(feature = "reqwest")]
 pub mod reqwest;
+
+#[cfg(feature = "hyper")]
+#[doc(inline)]
+pub use self::hyper::TransportHyper;
+#[cfg(feature = "hyper")]
+pub mod hyper;
+
+#[cfg(feature = "hyper")]
+#[doc(inline)]
+pub use self::pool::{PoolStats, PooledTransportHyper};
+#[cfg(feature = "hyper")]
+pub mod pool;
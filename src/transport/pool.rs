@@ -0,0 +1,571 @@
+//! # Pooled Hyper Transport Implementation
+//!
+//! This module contains the [`PooledTransportHyper`] struct.
+//! It is used to send requests to the [`PubNub API`] over a bounded pool of
+//! connections which are kept per origin and reused between requests.
+//! HTTP/2 is used when negotiated with origin, HTTP/1.1 otherwise.
+//!
+//! It requires the [`hyper` feature] to be enabled.
+//!
+//! [`PooledTransportHyper`]: ./struct.PooledTransportHyper.html
+//! [`PubNub API`]: https://www.pubnub.com/docs
+//! [`hyper` feature]: ../index.html#features
+
+use crate::{
+    core::{
+        error::PubNubError, transport::PUBNUB_DEFAULT_BASE_URL, Transport, TransportRequest,
+        TransportResponse,
+    },
+    lib::{
+        alloc::{
+            boxed::Box,
+            format,
+            string::{String, ToString},
+            sync::Arc,
+            vec::Vec,
+        },
+        collections::HashMap,
+        core::{
+            future,
+            sync::atomic::{AtomicUsize, Ordering},
+        },
+    },
+    transport::hyper::{extract_headers, prepare_request},
+};
+use core::time::Duration;
+use hyper::{
+    client::{
+        conn::{http1, http2},
+        HttpConnector,
+    },
+    rt::Executor,
+    service::Service,
+    Body, Request, Response, Uri,
+};
+use hyper_tls::{HttpsConnector, MaybeHttpsStream};
+use log::info;
+use spin::Mutex;
+use tokio::sync::Semaphore;
+
+/// Connections pool usage statistics.
+///
+/// Statistics aggregated across all origins served by
+/// [`PooledTransportHyper`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Number of requests which currently use connections.
+    pub active: usize,
+
+    /// Number of HTTP/1.1 connections which wait in pool for next request.
+    pub idle: usize,
+
+    /// Number of HTTP/2 connections which are shared by concurrent requests.
+    pub multiplexed: usize,
+
+    /// Total number of connections which has been created by pool.
+    pub created: usize,
+}
+
+/// This struct is used to send requests to the [`PubNub API`] over pooled
+/// connections.
+///
+/// Each origin has own pool which serves up to `max_connections` requests at
+/// once. HTTP/2 connection negotiated with origin is shared by concurrent
+/// requests. Otherwise idle HTTP/1.1 connections are reused by next requests
+/// and new connections are created only when all existing are busy. Requests
+/// which exceed limit wait in queue for connection to be released.
+///
+/// # Example
+/// ```
+/// use pubnub::transport::PooledTransportHyper;
+///
+/// let transport = PooledTransportHyper::with_max_connections(5);
+/// let stats = transport.pool_stats();
+/// assert_eq!(stats.created, 0);
+/// ```
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[derive(Clone, Debug)]
+pub struct PooledTransportHyper {
+    connector: HttpsConnector<HttpConnector>,
+
+    /// Pools of connections per origin.
+    pool: Arc<Pool>,
+
+    /// Timeout which is used for requests which doesn't specify own timeout.
+    request_timeout: Duration,
+
+    /// The hostname to use for requests.
+    /// It is used as the base URL for all requests.
+    ///
+    /// It defaults to `https://ps.pndsn.com/`.
+    pub hostname: String,
+}
+
+#[derive(Debug)]
+struct Pool {
+    /// Maximum number of concurrent requests (and HTTP/1.1 connections) per
+    /// origin.
+    max_connections: usize,
+
+    /// Pools of connections per origin.
+    origins: Mutex<HashMap<String, Arc<OriginPool>>>,
+
+    /// Total number of created connections.
+    created: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct OriginPool {
+    /// Permits to use connection to origin.
+    permits: Arc<Semaphore>,
+
+    /// HTTP/1.1 connections which wait for next request.
+    idle: Mutex<Vec<http1::SendRequest<Body>>>,
+
+    /// HTTP/2 connection which is shared by concurrent requests.
+    multiplexed: Mutex<Option<http2::SendRequest<Body>>>,
+
+    /// Number of requests which currently use connections.
+    active: AtomicUsize,
+}
+
+/// Connection to the origin.
+enum Connection {
+    /// Connection which serves one request at a time.
+    Http1(http1::SendRequest<Body>),
+
+    /// Connection which is shared by concurrent requests.
+    Http2(http2::SendRequest<Body>),
+}
+
+impl Connection {
+    /// Send request over connection.
+    async fn send_request(&mut self, request: Request<Body>) -> hyper::Result<Response<Body>> {
+        match self {
+            Self::Http1(sender) => sender.send_request(request).await,
+            Self::Http2(sender) => sender.send_request(request).await,
+        }
+    }
+}
+
+/// Executor which runs HTTP/2 connection background tasks with `tokio`.
+#[derive(Clone, Copy)]
+struct TokioExecutor;
+
+impl<F> Executor<F> for TokioExecutor
+where
+    F: future::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    fn execute(&self, future: F) {
+        tokio::spawn(future);
+    }
+}
+
+/// Connection checked out from the origin pool.
+///
+/// HTTP/1.1 connection returned to the pool when checkout dropped, unless it
+/// has been discarded because of error. HTTP/2 connection stays in the pool
+/// while it is in use.
+struct Checkout {
+    origin_pool: Arc<OriginPool>,
+    connection: Option<Connection>,
+}
+
+impl Drop for Checkout {
+    fn drop(&mut self) {
+        self.origin_pool.active.fetch_sub(1, Ordering::SeqCst);
+
+        if let Some(Connection::Http1(connection)) = self.connection.take() {
+            self.origin_pool.idle.lock().push(connection);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Transport for PooledTransportHyper {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
+        let request_url = request.url(&self.hostname);
+        info!(
+            "Sending data to pubnub: {} {:?} {}",
+            request.method, request.headers, request_url
+        );
+
+        let timeout = if request.timeout.gt(&0) {
+            Duration::from_secs(request.timeout)
+        } else {
+            self.request_timeout
+        };
+        let path = request.path.clone();
+        let hyper_request = prepare_request(request, request_url)?;
+        let origin_pool = self.pool.origin(hyper_request.uri());
+
+        tokio::time::timeout(timeout, async {
+            // Wait for connection to be released when pool is exhausted.
+            let _permit = origin_pool
+                .permits
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|err| PubNubError::Transport {
+                    details: err.to_string(),
+                    status: None,
+                    response: None,
+                })?;
+
+            let mut checkout = self.checkout(&origin_pool, hyper_request.uri()).await?;
+            let Some(connection) = checkout.connection.as_mut() else {
+                unreachable!("Checkout always contains connection");
+            };
+
+            let result = async {
+                let response = connection.send_request(hyper_request).await?;
+                let (parts, body) = response.into_parts();
+                hyper::body::to_bytes(body)
+                    .await
+                    .map(|bytes| (parts, bytes))
+            }
+            .await;
+
+            if result.is_err() {
+                checkout.connection = None;
+            }
+
+            let (parts, body) = result.map_err(PubNubError::from)?;
+            Ok(TransportResponse {
+                status: parts.status.as_u16(),
+                body: (!body.is_empty()).then(|| body.to_vec()),
+                headers: extract_headers(&parts.headers),
+            })
+        })
+        .await
+        .map_err(|_| PubNubError::Timeout { endpoint: path })?
+    }
+}
+
+impl Default for PooledTransportHyper {
+    fn default() -> Self {
+        Self::with_max_connections(DEFAULT_MAX_CONNECTIONS)
+    }
+}
+
+impl PooledTransportHyper {
+    /// Create a new [`PooledTransportHyper`] instance with default limit of
+    /// connections per origin.
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::transport::PooledTransportHyper;
+    ///
+    /// let transport = PooledTransportHyper::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new [`PooledTransportHyper`] instance which will keep up to
+    /// `max_connections` connections per origin.
+    ///
+    /// Zero `max_connections` treated as `1`.
+    pub fn with_max_connections(max_connections: usize) -> Self {
+        let mut http = HttpConnector::new();
+        http.enforce_http(false);
+        http.set_connect_timeout(Some(DEFAULT_CONNECT_TIMEOUT));
+
+        // HTTP/2 should be negotiated for secured connections.
+        let tls = native_tls::TlsConnector::builder()
+            .request_alpns(&["h2", "http/1.1"])
+            .build()
+            .map(tokio_native_tls::TlsConnector::from);
+        let connector = match tls {
+            Ok(tls) => HttpsConnector::from((http, tls)),
+            Err(_) => HttpsConnector::new_with_connector(http),
+        };
+
+        Self {
+            connector,
+            pool: Arc::new(Pool {
+                max_connections: max_connections.max(1),
+                origins: Mutex::new(HashMap::new()),
+                created: AtomicUsize::new(0),
+            }),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            hostname: PUBNUB_DEFAULT_BASE_URL.into(),
+        }
+    }
+
+    /// Current connections pool usage statistics.
+    pub fn pool_stats(&self) -> PoolStats {
+        let origins = self.pool.origins.lock();
+
+        origins.values().fold(
+            PoolStats {
+                created: self.pool.created.load(Ordering::SeqCst),
+                ..Default::default()
+            },
+            |mut stats, origin_pool| {
+                stats.active += origin_pool.active.load(Ordering::SeqCst);
+                stats.idle += origin_pool.idle.lock().len();
+                stats.multiplexed += usize::from(origin_pool.multiplexed.lock().is_some());
+                stats
+            },
+        )
+    }
+
+    /// Take shared or idle connection from the origin pool or create new one.
+    async fn checkout(
+        &self,
+        origin_pool: &Arc<OriginPool>,
+        uri: &Uri,
+    ) -> Result<Checkout, PubNubError> {
+        origin_pool.active.fetch_add(1, Ordering::SeqCst);
+        let mut checkout = Checkout {
+            origin_pool: origin_pool.clone(),
+            connection: None,
+        };
+
+        let multiplexed = origin_pool.multiplexed.lock().clone();
+        if let Some(mut connection) = multiplexed {
+            if future::poll_fn(|cx| connection.poll_ready(cx))
+                .await
+                .is_ok()
+            {
+                checkout.connection = Some(Connection::Http2(connection));
+                return Ok(checkout);
+            }
+
+            origin_pool.multiplexed.lock().take();
+        }
+
+        loop {
+            let Some(mut connection) = origin_pool.idle.lock().pop() else {
+                break;
+            };
+
+            // Connections closed by remote side are dropped from pool.
+            if future::poll_fn(|cx| connection.poll_ready(cx))
+                .await
+                .is_ok()
+            {
+                checkout.connection = Some(Connection::Http1(connection));
+                return Ok(checkout);
+            }
+        }
+
+        let connection = self.connect(uri).await?;
+        if let Connection::Http2(connection) = &connection {
+            // Keep connection which has been shared first.
+            origin_pool
+                .multiplexed
+                .lock()
+                .get_or_insert_with(|| connection.clone());
+        }
+
+        checkout.connection = Some(connection);
+        Ok(checkout)
+    }
+
+    /// Establish new connection with origin.
+    ///
+    /// HTTP/2 is used only when negotiated with origin over TLS, otherwise
+    /// HTTP/1.1 connection is established.
+    async fn connect(&self, uri: &Uri) -> Result<Connection, PubNubError> {
+        let io = self
+            .connector
+            .clone()
+            .call(uri.clone())
+            .await
+            .map_err(|err| PubNubError::Transport {
+                details: format!("Unable to connect: {err}"),
+                status: None,
+                response: None,
+            })?;
+
+        let http2 = match &io {
+            MaybeHttpsStream::Https(stream) => matches!(
+                stream.get_ref().negotiated_alpn(),
+                Ok(Some(protocol)) if protocol == b"h2"
+            ),
+            MaybeHttpsStream::Http(_) => false,
+        };
+
+        let connection = if http2 {
+            let (sender, connection) = http2::handshake(TokioExecutor, io).await?;
+            tokio::spawn(connection);
+            Connection::Http2(sender)
+        } else {
+            let (sender, connection) = http1::handshake(io).await?;
+            tokio::spawn(connection);
+            Connection::Http1(sender)
+        };
+        self.pool.created.fetch_add(1, Ordering::SeqCst);
+
+        Ok(connection)
+    }
+}
+
+impl Pool {
+    /// Pool of connections for request origin.
+    fn origin(&self, uri: &Uri) -> Arc<OriginPool> {
+        let origin = format!(
+            "{}://{}",
+            uri.scheme_str().unwrap_or_default(),
+            uri.authority().map(|a| a.as_str()).unwrap_or_default()
+        );
+
+        self.origins
+            .lock()
+            .entry(origin)
+            .or_insert_with(|| {
+                Arc::new(OriginPool {
+                    permits: Arc::new(Semaphore::new(self.max_connections)),
+                    idle: Mutex::new(Vec::new()),
+                    multiplexed: Mutex::new(None),
+                    active: AtomicUsize::new(0),
+                })
+            })
+            .clone()
+    }
+}
+
+/// Default maximum number of connections per origin.
+const DEFAULT_MAX_CONNECTIONS: usize = 10;
+
+/// Default timeout for requests which doesn't specify own timeout.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default timeout for connection establishment.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[cfg(test)]
+mod should {
+    use super::*;
+    use crate::core::TransportMethod;
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Request, Response, Server,
+    };
+    use std::{convert::Infallible, net::SocketAddr};
+
+    /// Start local HTTP/1.1 server which respond after `delay`.
+    async fn start_server(delay: Duration) -> SocketAddr {
+        let service = make_service_fn(move |_| async move {
+            Ok::<_, Infallible>(service_fn(move |_: Request<Body>| async move {
+                tokio::time::sleep(delay).await;
+                Ok::<_, Infallible>(Response::new(Body::from("[16787176144828000]")))
+            }))
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into())
+            .http1_only(true)
+            .serve(service);
+        let address = server.local_addr();
+        tokio::spawn(server);
+
+        address
+    }
+
+    fn transport(address: SocketAddr, max_connections: usize) -> PooledTransportHyper {
+        PooledTransportHyper {
+            hostname: format!("http://{address}"),
+            ..PooledTransportHyper::with_max_connections(max_connections)
+        }
+    }
+
+    fn request() -> TransportRequest {
+        TransportRequest {
+            path: "/time/0".into(),
+            method: TransportMethod::Get,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn reuse_idle_connection() {
+        let transport = transport(start_server(Duration::ZERO).await, 5);
+
+        for _ in 0..5 {
+            let response = transport.send(request()).await.unwrap();
+            assert_eq!(response.status, 200);
+        }
+
+        assert_eq!(
+            transport.pool_stats(),
+            PoolStats {
+                active: 0,
+                idle: 1,
+                multiplexed: 0,
+                created: 1
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn queue_requests_over_connections_limit() {
+        let transport = transport(start_server(Duration::from_millis(200)).await, 2);
+
+        let requests = (0..6)
+            .map(|_| {
+                let transport = transport.clone();
+                tokio::spawn(async move { transport.send(request()).await })
+            })
+            .collect::<Vec<_>>();
+
+        for request in requests {
+            assert!(request.await.unwrap().is_ok());
+        }
+        assert_eq!(
+            transport.pool_stats(),
+            PoolStats {
+                active: 0,
+                idle: 2,
+                multiplexed: 0,
+                created: 2
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn time_out_while_waiting_for_connection() {
+        let transport = transport(start_server(Duration::from_secs(2)).await, 1);
+
+        let in_flight = {
+            let transport = transport.clone();
+            tokio::spawn(async move {
+                transport
+                    .send(TransportRequest {
+                        timeout: 5,
+                        ..request()
+                    })
+                    .await
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let queued = transport
+            .send(TransportRequest {
+                timeout: 1,
+                ..request()
+            })
+            .await;
+
+        assert!(matches!(queued, Err(PubNubError::Timeout { .. })));
+        assert!(in_flight.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn report_active_connections() {
+        let transport = transport(start_server(Duration::from_millis(300)).await, 2);
+
+        let in_flight = {
+            let transport = transport.clone();
+            tokio::spawn(async move { transport.send(request()).await })
+        };
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(transport.pool_stats().active, 1);
+        assert!(in_flight.await.unwrap().is_ok());
+        assert_eq!(transport.pool_stats().active, 0);
+    }
+}
@@ -23,8 +23,8 @@ use crate::dx::pubnub_client::PubNubClientRuntimeBuilder;
 
 use crate::{
     core::{
-        error::PubNubError, transport::PUBNUB_DEFAULT_BASE_URL, utils::encoding::url_encode,
-        Transport, TransportMethod, TransportRequest, TransportResponse,
+        error::PubNubError, transport::PUBNUB_DEFAULT_BASE_URL, Transport, TransportMethod,
+        TransportRequest, TransportResponse,
     },
     lib::{
         alloc::{
@@ -37,12 +37,18 @@ use crate::{
     PubNubClientBuilder,
 };
 use bytes::Bytes;
+use core::time::Duration;
 use log::info;
 use reqwest::{
     header::{HeaderMap, HeaderName, HeaderValue, InvalidHeaderName, InvalidHeaderValue},
     StatusCode,
 };
 
+#[cfg(feature = "compression")]
+use crate::lib::alloc::vec::Vec;
+#[cfg(feature = "compression")]
+use reqwest::header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH};
+
 /// This struct is used to send requests to the [`PubNub API`] using the
 /// [`reqwest`] crate. It is used as the transport type for the
 /// [`PubNubClient`]. It is intended to be used by the [`pubnub`] crate.
@@ -55,6 +61,10 @@ use reqwest::{
 pub struct TransportReqwest {
     reqwest_client: reqwest::Client,
 
+    /// Timeout which is used for requests which doesn't specify own timeout.
+    #[cfg(feature = "std")]
+    request_timeout: Duration,
+
     /// The hostname to use for requests.
     /// It is used as the base URL for all requests.
     ///
@@ -76,7 +86,7 @@ pub struct TransportReqwest {
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 impl Transport for TransportReqwest {
     async fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
-        let request_url = prepare_url(&self.hostname, &request.path, &request.query_parameters);
+        let request_url = request.url(&self.hostname);
         info!(
             "Sending data to pubnub: {} {:?} {}",
             request.method, request.headers, request_url
@@ -91,11 +101,17 @@ impl Transport for TransportReqwest {
             TransportMethod::Get => self.prepare_get_method(request, request_url),
             TransportMethod::Post => self.prepare_post_method(request, request_url),
             TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+            TransportMethod::Patch => self.prepare_patch_method(request, request_url),
+            TransportMethod::Head => self.prepare_head_method(request, request_url),
         }?;
 
         #[cfg(feature = "std")]
-        if timeout.gt(&0) {
-            builder = builder.timeout(core::time::Duration::from_secs(timeout));
+        {
+            builder = builder.timeout(if timeout.gt(&0) {
+                Duration::from_secs(timeout)
+            } else {
+                self.request_timeout
+            });
         }
 
         #[cfg(not(feature = "std"))]
@@ -103,16 +119,15 @@ impl Transport for TransportReqwest {
             TransportMethod::Get => self.prepare_get_method(request, request_url),
             TransportMethod::Post => self.prepare_post_method(request, request_url),
             TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+            TransportMethod::Patch => self.prepare_patch_method(request, request_url),
+            TransportMethod::Head => self.prepare_head_method(request, request_url),
         }?;
 
         let result = builder
             .headers(headers)
             .send()
             .await
-            .map_err(|e| PubNubError::Transport {
-                details: e.to_string(),
-                response: None,
-            })?;
+            .map_err(PubNubError::from)?;
 
         let headers = result.headers().clone();
         let status = result.status();
@@ -121,6 +136,7 @@ impl Transport for TransportReqwest {
             .await
             .map_err(|e| PubNubError::Transport {
                 details: e.to_string(),
+                status: Some(status.into()),
                 response: Some(Box::new(TransportResponse {
                     status: status.into(),
                     headers: extract_headers(&headers),
@@ -133,10 +149,7 @@ impl Transport for TransportReqwest {
 
 impl Default for TransportReqwest {
     fn default() -> Self {
-        Self {
-            reqwest_client: reqwest::Client::default(),
-            hostname: PUBNUB_DEFAULT_BASE_URL.into(),
-        }
+        Self::with_timeouts(DEFAULT_REQUEST_TIMEOUT, DEFAULT_CONNECT_TIMEOUT)
     }
 }
 
@@ -164,6 +177,64 @@ impl TransportReqwest {
         Self::default()
     }
 
+    /// Create a new [`TransportReqwest`] instance with custom timeouts.
+    ///
+    /// `request_timeout` is used only for requests which doesn't specify own
+    /// timeout (client's requests use timeouts from [`TransportConfiguration`]
+    /// and subscribe requests use longer timeout than others).
+    /// `connect_timeout` limits time which can be spent to establish
+    /// connection with [`PubNub API`].
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::transport::TransportReqwest;
+    /// use std::time::Duration;
+    ///
+    /// let transport = TransportReqwest::with_timeouts(
+    ///     Duration::from_secs(15),
+    ///     Duration::from_secs(5),
+    /// );
+    /// ```
+    ///
+    /// [`TransportConfiguration`]: crate::dx::pubnub_client::TransportConfiguration
+    /// [`PubNub API`]: https://www.pubnub.com/docs
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    pub fn with_timeouts(request_timeout: Duration, connect_timeout: Duration) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let reqwest_client = reqwest::Client::builder()
+            .connect_timeout(connect_timeout)
+            .build()
+            .unwrap_or_default();
+        #[cfg(target_arch = "wasm32")]
+        let reqwest_client = reqwest::Client::default();
+
+        Self {
+            reqwest_client,
+            #[cfg(feature = "std")]
+            request_timeout,
+            hostname: PUBNUB_DEFAULT_BASE_URL.into(),
+        }
+    }
+
+    /// Create a new [`TransportReqwestBuilder`] to configure transport.
+    ///
+    /// # Example
+    /// ```
+    /// use pubnub::transport::TransportReqwest;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+    /// let transport = TransportReqwest::builder()
+    ///     .connect_timeout(Duration::from_secs(5))
+    ///     .proxy("http://proxy.example.com:8080")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> TransportReqwestBuilder {
+        TransportReqwestBuilder::default()
+    }
+
     /// set the custom hostname for request
     pub fn set_hostname<S>(&mut self, hostname: S)
     where
@@ -189,6 +260,7 @@ impl TransportReqwest {
             .body
             .ok_or(PubNubError::Transport {
                 details: "Body should not be empty for POST".into(),
+                status: None,
                 response: None,
             })
             .map(|vec_bytes| self.reqwest_client.post(url).body(vec_bytes))
@@ -201,39 +273,235 @@ impl TransportReqwest {
     ) -> Result<reqwest::RequestBuilder, PubNubError> {
         Ok(self.reqwest_client.delete(url))
     }
+
+    fn prepare_patch_method(
+        &self,
+        request: TransportRequest,
+        url: String,
+    ) -> Result<reqwest::RequestBuilder, PubNubError> {
+        request
+            .body
+            .ok_or(PubNubError::Transport {
+                details: "Body should not be empty for PATCH".into(),
+                status: None,
+                response: None,
+            })
+            .map(|vec_bytes| self.reqwest_client.patch(url).body(vec_bytes))
+    }
+
+    fn prepare_head_method(
+        &self,
+        _request: TransportRequest,
+        url: String,
+    ) -> Result<reqwest::RequestBuilder, PubNubError> {
+        Ok(self.reqwest_client.head(url))
+    }
+}
+
+/// The [`TransportReqwestBuilder`] is used to configure and build
+/// [`TransportReqwest`] instance.
+///
+/// Builder let configure transport timeouts and proxy which should be used to
+/// route requests to the [`PubNub API`].
+///
+/// # Example
+/// ```
+/// use pubnub::transport::reqwest::TransportReqwestBuilder;
+///
+/// # fn main() -> Result<(), pubnub::core::PubNubError> {
+/// let transport = TransportReqwestBuilder::default()
+///     .proxy("http://proxy.example.com:8080")
+///     .proxy_credentials("username", "password")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`PubNub API`]: https://www.pubnub.com/docs
+#[derive(Debug, Clone)]
+pub struct TransportReqwestBuilder {
+    /// The hostname to use for requests.
+    hostname: String,
+
+    /// Timeout which is used for requests which doesn't specify own timeout.
+    request_timeout: Duration,
+
+    /// Timeout for connection establishment.
+    connect_timeout: Duration,
+
+    /// URL of HTTP / HTTPS proxy through which all requests should be sent.
+    proxy: Option<String>,
+
+    /// Username and password for proxy authorization.
+    proxy_credentials: Option<(String, String)>,
+}
+
+impl Default for TransportReqwestBuilder {
+    fn default() -> Self {
+        Self {
+            hostname: PUBNUB_DEFAULT_BASE_URL.into(),
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            proxy: None,
+            proxy_credentials: None,
+        }
+    }
+}
+
+impl TransportReqwestBuilder {
+    /// Set the custom hostname for requests.
+    pub fn hostname<S>(mut self, hostname: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.hostname = hostname.into();
+        self
+    }
+
+    /// Set timeout for requests which doesn't specify own timeout.
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Set timeout for connection establishment.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Set URL of HTTP / HTTPS proxy through which all requests should be
+    /// sent.
+    ///
+    /// Proxy URL validated when [`TransportReqwest`] is built.
+    pub fn proxy<S>(mut self, url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Set credentials for proxy authorization.
+    ///
+    /// Credentials are used only when [`proxy`] has been provided.
+    ///
+    /// [`proxy`]: TransportReqwestBuilder::proxy
+    pub fn proxy_credentials<U, P>(mut self, username: U, password: P) -> Self
+    where
+        U: Into<String>,
+        P: Into<String>,
+    {
+        self.proxy_credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Build [`TransportReqwest`] from builder.
+    ///
+    /// Returns [`PubNubError::ClientInitialization`] if provided proxy URL is
+    /// malformed or [`reqwest`] client can't be created.
+    ///
+    /// [`reqwest`]: https://docs.rs/reqwest
+    #[cfg_attr(not(feature = "std"), allow(unused_mut))]
+    pub fn build(self) -> Result<TransportReqwest, PubNubError> {
+        let mut builder = reqwest::Client::builder();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            builder = builder.connect_timeout(self.connect_timeout);
+            if let Some(proxy) = self.reqwest_proxy()? {
+                builder = builder.proxy(proxy);
+            }
+        }
+
+        Ok(TransportReqwest {
+            reqwest_client: builder.build().map_err(client_initialization_error)?,
+            #[cfg(feature = "std")]
+            request_timeout: self.request_timeout,
+            hostname: self.hostname,
+        })
+    }
+
+    /// Create [`reqwest::Proxy`] from provided proxy configuration.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn reqwest_proxy(&self) -> Result<Option<reqwest::Proxy>, PubNubError> {
+        let Some(url) = self.proxy.as_ref() else {
+            return Ok(None);
+        };
+
+        // Check that URL has scheme and host, because `reqwest` accepts values
+        // which can't be used to reach proxy (like `proxy:8080`).
+        let parsed_url = reqwest::Url::parse(url).map_err(client_initialization_error)?;
+        if !matches!(parsed_url.scheme(), "http" | "https" | "socks5" | "socks5h")
+            || parsed_url.host_str().is_none()
+        {
+            return Err(PubNubError::ClientInitialization {
+                details: format!("Malformed proxy URL: {url}"),
+            });
+        }
+
+        let proxy = reqwest::Proxy::all(parsed_url).map_err(client_initialization_error)?;
+        Ok(Some(match self.proxy_credentials.as_ref() {
+            Some((username, password)) => proxy.basic_auth(username, password),
+            None => proxy,
+        }))
+    }
 }
 
+/// Map error which happened during [`reqwest`] client configuration.
+///
+/// [`reqwest`]: https://docs.rs/reqwest
+fn client_initialization_error<E>(error: E) -> PubNubError
+where
+    E: ToString,
+{
+    PubNubError::ClientInitialization {
+        details: error.to_string(),
+    }
+}
+
+/// Default timeout for requests which doesn't specify own timeout.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default timeout for connection establishment.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Response body encodings which can be decoded by transport.
+#[cfg(feature = "compression")]
+const SUPPORTED_CONTENT_ENCODINGS: &str = "gzip, deflate";
+
 fn prepare_headers(request_headers: &HashMap<String, String>) -> Result<HeaderMap, PubNubError> {
-    request_headers
+    let headers = request_headers
         .iter()
         .map(|(k, v)| -> Result<(HeaderName, HeaderValue), PubNubError> {
             let name =
                 TryFrom::try_from(k).map_err(|err: InvalidHeaderName| PubNubError::Transport {
                     details: err.to_string(),
+                    status: None,
                     response: None,
                 })?;
             let value: HeaderValue =
                 TryFrom::try_from(v).map_err(|err: InvalidHeaderValue| PubNubError::Transport {
                     details: err.to_string(),
+                    status: None,
                     response: None,
                 })?;
             Ok((name, value))
         })
-        .collect()
-}
-
-fn prepare_url(hostname: &str, path: &str, query_params: &HashMap<String, String>) -> String {
-    if query_params.is_empty() {
-        return format!("{}{}", hostname, path);
-    }
-    let mut qp = query_params
-        .iter()
-        .fold(format!("{}{}?", hostname, path), |acc_query, (k, v)| {
-            format!("{}{}={}&", acc_query, k, url_encode(v.as_bytes()))
-        });
+        .collect::<Result<HeaderMap, _>>()?;
+
+    // Ask service to compress response (unless request already specifies
+    // acceptable encodings).
+    #[cfg(feature = "compression")]
+    let headers = {
+        let mut headers = headers;
+        headers
+            .entry(ACCEPT_ENCODING)
+            .or_insert(HeaderValue::from_static(SUPPORTED_CONTENT_ENCODINGS));
+        headers
+    };
 
-    qp.remove(qp.len() - 1);
-    qp
+    Ok(headers)
 }
 
 fn extract_headers(headers: &HeaderMap) -> HashMap<String, String> {
@@ -252,13 +520,62 @@ fn create_result(
     body: Bytes,
     headers: &HeaderMap,
 ) -> Result<TransportResponse, PubNubError> {
+    #[cfg(feature = "compression")]
+    let (body, headers) = decode_body(status, body, headers)?;
+    #[cfg(not(feature = "compression"))]
+    let headers = headers.clone();
+
     Ok(TransportResponse {
         status: status.as_u16(),
         body: (!body.is_empty()).then(|| body.to_vec()),
-        headers: extract_headers(headers),
+        headers: extract_headers(&headers),
     })
 }
 
+/// Decode compressed response body.
+///
+/// Body decoded according to the `Content-Encoding` header. Encoding related
+/// headers removed from the returned headers, because they don't describe
+/// decoded body anymore.
+#[cfg(feature = "compression")]
+fn decode_body(
+    status: StatusCode,
+    body: Bytes,
+    headers: &HeaderMap,
+) -> Result<(Bytes, HeaderMap), PubNubError> {
+    use std::io::Read;
+
+    let encoding = headers
+        .get(CONTENT_ENCODING)
+        .and_then(|encoding| encoding.to_str().ok())
+        .map(|encoding| encoding.trim().to_ascii_lowercase());
+
+    let mut decoded = Vec::new();
+    let result = match encoding.as_deref() {
+        Some("gzip") | Some("x-gzip") => {
+            flate2::read::GzDecoder::new(body.as_ref()).read_to_end(&mut decoded)
+        }
+        Some("deflate") => flate2::read::ZlibDecoder::new(body.as_ref()).read_to_end(&mut decoded),
+        _ => return Ok((body, headers.clone())),
+    };
+
+    result.map_err(|error| PubNubError::Transport {
+        details: format!("Unable to decode response body: {error}"),
+        status: Some(status.into()),
+        response: Some(Box::new(TransportResponse {
+            status: status.into(),
+            headers: extract_headers(headers),
+            body: None,
+        })),
+    })?;
+
+    let mut headers = headers.clone();
+    headers.remove(CONTENT_ENCODING);
+    headers.remove(CONTENT_LENGTH);
+
+    Ok((decoded.into(), headers))
+}
+
 impl PubNubClientBuilder {
     /// Creates a new [`PubNubClientBuilder`] with the default
     /// [`TransportReqwest`] transport. The default transport uses the
@@ -359,9 +676,13 @@ pub mod blocking {
             boxed::Box,
             string::{String, ToString},
         },
-        transport::reqwest::{create_result, extract_headers, prepare_headers, prepare_url},
+        transport::reqwest::{
+            client_initialization_error, create_result, extract_headers, prepare_headers,
+            TransportReqwestBuilder, DEFAULT_CONNECT_TIMEOUT, DEFAULT_REQUEST_TIMEOUT,
+        },
         PubNubClientBuilder,
     };
+    use core::time::Duration;
     use log::info;
 
     /// This struct is used to send requests to the [`PubNub API`] using the
@@ -376,6 +697,12 @@ pub mod blocking {
     /// [`pubnub`]: ../index.html
     pub struct TransportReqwest {
         reqwest_client: reqwest::blocking::Client,
+
+        /// Timeout which is used for requests which doesn't specify own
+        /// timeout.
+        #[cfg(feature = "std")]
+        request_timeout: Duration,
+
         /// The hostname to use for requests.
         /// It is used as the base URL for all requests.
         ///
@@ -395,7 +722,7 @@ pub mod blocking {
 
     impl crate::core::blocking::Transport for TransportReqwest {
         fn send(&self, request: TransportRequest) -> Result<TransportResponse, PubNubError> {
-            let request_url = prepare_url(&self.hostname, &request.path, &request.query_parameters);
+            let request_url = request.url(&self.hostname);
             info!(
                 "Sending data to pubnub: {} {:?} {}",
                 request.method, request.headers, request_url
@@ -409,11 +736,17 @@ pub mod blocking {
                 TransportMethod::Get => self.prepare_get_method(request, request_url),
                 TransportMethod::Post => self.prepare_post_method(request, request_url),
                 TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+                TransportMethod::Patch => self.prepare_patch_method(request, request_url),
+                TransportMethod::Head => self.prepare_head_method(request, request_url),
             }?;
 
             #[cfg(feature = "std")]
-            if timeout.gt(&0) {
-                builder = builder.timeout(core::time::Duration::from_micros(timeout))
+            {
+                builder = builder.timeout(if timeout.gt(&0) {
+                    Duration::from_secs(timeout)
+                } else {
+                    self.request_timeout
+                });
             }
 
             #[cfg(not(feature = "std"))]
@@ -421,15 +754,11 @@ pub mod blocking {
                 TransportMethod::Get => self.prepare_get_method(request, request_url),
                 TransportMethod::Post => self.prepare_post_method(request, request_url),
                 TransportMethod::Delete => self.prepare_delete_method(request, request_url),
+                TransportMethod::Patch => self.prepare_patch_method(request, request_url),
+                TransportMethod::Head => self.prepare_head_method(request, request_url),
             }?;
 
-            let result = builder
-                .headers(headers)
-                .send()
-                .map_err(|e| PubNubError::Transport {
-                    details: e.to_string(),
-                    response: None,
-                })?;
+            let result = builder.headers(headers).send().map_err(PubNubError::from)?;
 
             let headers = result.headers().clone();
             let status = result.status();
@@ -437,6 +766,7 @@ pub mod blocking {
                 .bytes()
                 .map_err(|e| PubNubError::Transport {
                     details: e.to_string(),
+                    status: Some(status.into()),
                     response: Some(Box::new(TransportResponse {
                         status: status.into(),
                         headers: extract_headers(&headers),
@@ -449,10 +779,7 @@ pub mod blocking {
 
     impl Default for TransportReqwest {
         fn default() -> Self {
-            Self {
-                reqwest_client: reqwest::blocking::Client::default(),
-                hostname: PUBNUB_DEFAULT_BASE_URL.into(),
-            }
+            Self::with_timeouts(DEFAULT_REQUEST_TIMEOUT, DEFAULT_CONNECT_TIMEOUT)
         }
     }
 
@@ -481,6 +808,61 @@ pub mod blocking {
             Self::default()
         }
 
+        /// Create a new [`TransportReqwest`] instance with custom timeouts.
+        ///
+        /// `request_timeout` is used only for requests which doesn't specify
+        /// own timeout (client's requests use timeouts from
+        /// [`TransportConfiguration`] and subscribe requests use longer
+        /// timeout than others).
+        /// `connect_timeout` limits time which can be spent to establish
+        /// connection with [`PubNub API`].
+        ///
+        /// # Example
+        /// ```
+        /// use pubnub::transport::reqwest::blocking::TransportReqwest;
+        /// use std::time::Duration;
+        ///
+        /// let transport = TransportReqwest::with_timeouts(
+        ///     Duration::from_secs(15),
+        ///     Duration::from_secs(5),
+        /// );
+        /// ```
+        ///
+        /// [`TransportConfiguration`]: crate::dx::pubnub_client::TransportConfiguration
+        /// [`PubNub API`]: https://www.pubnub.com/docs
+        #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+        pub fn with_timeouts(request_timeout: Duration, connect_timeout: Duration) -> Self {
+            Self {
+                reqwest_client: reqwest::blocking::Client::builder()
+                    .connect_timeout(connect_timeout)
+                    .build()
+                    .unwrap_or_default(),
+                #[cfg(feature = "std")]
+                request_timeout,
+                hostname: PUBNUB_DEFAULT_BASE_URL.into(),
+            }
+        }
+
+        /// Create a new [`TransportReqwestBuilder`] to configure transport.
+        ///
+        /// Use [`TransportReqwestBuilder::build_blocking`] to build blocking
+        /// transport.
+        ///
+        /// # Example
+        /// ```
+        /// use pubnub::transport::reqwest::blocking::TransportReqwest;
+        ///
+        /// # fn main() -> Result<(), pubnub::core::PubNubError> {
+        /// let transport = TransportReqwest::builder()
+        ///     .proxy("http://proxy.example.com:8080")
+        ///     .build_blocking()?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub fn builder() -> TransportReqwestBuilder {
+            TransportReqwestBuilder::default()
+        }
+
         fn prepare_get_method(
             &self,
             _request: TransportRequest,
@@ -510,6 +892,50 @@ pub mod blocking {
         ) -> Result<reqwest::blocking::RequestBuilder, PubNubError> {
             Ok(self.reqwest_client.delete(request_url))
         }
+
+        fn prepare_patch_method(
+            &self,
+            request: TransportRequest,
+            request_url: String,
+        ) -> Result<reqwest::blocking::RequestBuilder, PubNubError> {
+            let builder = self.reqwest_client.patch(request_url);
+            let builder = match request.body {
+                Some(body) => builder.body(body),
+                None => builder,
+            };
+            Ok(builder)
+        }
+
+        fn prepare_head_method(
+            &self,
+            _request: TransportRequest,
+            request_url: String,
+        ) -> Result<reqwest::blocking::RequestBuilder, PubNubError> {
+            Ok(self.reqwest_client.head(request_url))
+        }
+    }
+
+    impl TransportReqwestBuilder {
+        /// Build blocking [`TransportReqwest`] from builder.
+        ///
+        /// Returns [`PubNubError::ClientInitialization`] if provided proxy URL
+        /// is malformed or [`reqwest`] client can't be created.
+        ///
+        /// [`reqwest`]: https://docs.rs/reqwest
+        pub fn build_blocking(self) -> Result<TransportReqwest, PubNubError> {
+            let mut builder =
+                reqwest::blocking::Client::builder().connect_timeout(self.connect_timeout);
+            if let Some(proxy) = self.reqwest_proxy()? {
+                builder = builder.proxy(proxy);
+            }
+
+            Ok(TransportReqwest {
+                reqwest_client: builder.build().map_err(client_initialization_error)?,
+                #[cfg(feature = "std")]
+                request_timeout: self.request_timeout,
+                hostname: self.hostname,
+            })
+        }
     }
 
     impl PubNubClientBuilder {
@@ -608,8 +1034,8 @@ pub mod blocking {
 
             tokio::task::spawn_blocking(move || {
                 let transport = TransportReqwest {
-                    reqwest_client: reqwest::blocking::Client::default(),
                     hostname: server.uri(),
+                    ..Default::default()
                 };
 
                 let request = TransportRequest {
@@ -646,8 +1072,8 @@ pub mod blocking {
 
             tokio::task::spawn_blocking(move || {
                 let transport = TransportReqwest {
-                    reqwest_client: reqwest::blocking::Client::default(),
                     hostname: server.uri(),
+                    ..Default::default()
                 };
 
                 let request = TransportRequest {
@@ -665,6 +1091,46 @@ pub mod blocking {
             .await
             .unwrap();
         }
+
+        #[cfg(feature = "std")]
+        #[tokio::test]
+        async fn return_timeout_err_on_delayed_response() {
+            let path = "/v2/subscribe/sub_key/chat/0";
+
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path_macher(path))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_string("{\"t\":{\"t\":\"16787176144828000\",\"r\":1},\"m\":[]}")
+                        .set_delay(std::time::Duration::from_secs(3)),
+                )
+                .mount(&server)
+                .await;
+
+            tokio::task::spawn_blocking(move || {
+                let transport = TransportReqwest {
+                    hostname: server.uri(),
+                    ..Default::default()
+                };
+
+                let request = TransportRequest {
+                    path: path.into(),
+                    method: TransportMethod::Get,
+                    timeout: 1,
+                    ..Default::default()
+                };
+
+                assert_eq!(
+                    transport.send(request),
+                    Err(PubNubError::Timeout {
+                        endpoint: path.into()
+                    })
+                );
+            })
+            .await
+            .unwrap();
+        }
     }
 }
 
@@ -674,6 +1140,8 @@ mod should {
     use crate::lib::alloc::string::ToString;
 
     use test_case::test_case;
+    #[cfg(feature = "compression")]
+    use wiremock::matchers::headers;
     use wiremock::matchers::{body_string, header, method, path as path_macher};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -695,8 +1163,8 @@ mod should {
             .await;
 
         let transport = TransportReqwest {
-            reqwest_client: reqwest::Client::default(),
             hostname: server.uri(),
+            ..Default::default()
         };
 
         let request = TransportRequest {
@@ -722,7 +1190,13 @@ mod should {
             ("pnsdk".to_string(), "rust/1.2".to_string()),
         ]);
 
-        let url_string = prepare_url("host:8080", "/key/channel", &query_params);
+        let request = TransportRequest {
+            path: "/key/channel".into(),
+            query_parameters: query_params.clone(),
+            ..Default::default()
+        };
+
+        let url_string = request.url("host:8080");
         let parsed_url = reqwest::Url::parse(&url_string).unwrap();
         let retrived_query_params: HashMap<String, String> =
             parsed_url.query_pairs().into_owned().collect();
@@ -744,8 +1218,8 @@ mod should {
             .await;
 
         let transport = TransportReqwest {
-            reqwest_client: reqwest::Client::default(),
             hostname: server.uri(),
+            ..Default::default()
         };
 
         let request = TransportRequest {
@@ -761,6 +1235,103 @@ mod should {
         assert_eq!(response.status, 200);
     }
 
+    #[tokio::test]
+    async fn send_via_patch_method() {
+        let body = "{\"name\":\"user\"}";
+        let path = "/v2/objects/sub_key/uuids/user";
+
+        let server = MockServer::start().await;
+        Mock::given(method("PATCH"))
+            .and(path_macher(path))
+            .and(body_string(body.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_string("{\"status\":200}"))
+            .mount(&server)
+            .await;
+
+        let transport = TransportReqwest {
+            hostname: server.uri(),
+            ..Default::default()
+        };
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Patch,
+            body: Some(body.as_bytes().to_vec()),
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.status, 200);
+    }
+
+    #[cfg(feature = "compression")]
+    #[tokio::test]
+    async fn decode_gzip_encoded_response() {
+        use std::io::Write;
+
+        let body = "[[\"message\"],\"17000000000000000\",\"17000000000000001\"]";
+        let path = "/v2/history/sub-key/sub_key/channel/chat";
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(body.as_bytes()).unwrap();
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .and(headers("accept-encoding", vec!["gzip", "deflate"]))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", "gzip")
+                    .set_body_bytes(encoder.finish().unwrap()),
+            )
+            .mount(&server)
+            .await;
+
+        let transport = TransportReqwest {
+            hostname: server.uri(),
+            ..Default::default()
+        };
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.body, Some(body.as_bytes().to_vec()));
+        assert!(!response.headers.contains_key("content-encoding"));
+    }
+
+    #[tokio::test]
+    async fn pass_through_not_encoded_response() {
+        let body = "[1,\"Sent\",\"16787176144828000\"]";
+        let path = "/publish/sub_key/pub_key/0/chat/0";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .mount(&server)
+            .await;
+
+        let transport = TransportReqwest {
+            hostname: server.uri(),
+            ..Default::default()
+        };
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            ..Default::default()
+        };
+
+        let response = transport.send(request).await.unwrap();
+
+        assert_eq!(response.body, Some(body.as_bytes().to_vec()));
+    }
+
     #[tokio::test]
     async fn send_headers() {
         let path = "/publish/sub_key/pub_key/0/chat/0";
@@ -778,8 +1349,8 @@ mod should {
             .await;
 
         let transport = TransportReqwest {
-            reqwest_client: reqwest::Client::default(),
             hostname: server.uri(),
+            ..Default::default()
         };
 
         let request = TransportRequest {
@@ -806,4 +1377,152 @@ mod should {
 
         assert!(transport.send(request).await.is_err());
     }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn return_timeout_err_on_delayed_response() {
+        let path = "/v2/subscribe/sub_key/chat/0";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("{\"t\":{\"t\":\"16787176144828000\",\"r\":1},\"m\":[]}")
+                    .set_delay(std::time::Duration::from_secs(3)),
+            )
+            .mount(&server)
+            .await;
+
+        let transport = TransportReqwest {
+            hostname: server.uri(),
+            ..Default::default()
+        };
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            timeout: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transport.send(request).await,
+            Err(PubNubError::Timeout {
+                endpoint: path.into()
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn return_timeout_err_when_transport_timeout_exceeded() {
+        let path = "/time/0";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("[16787176144828000]")
+                    .set_delay(std::time::Duration::from_secs(2)),
+            )
+            .mount(&server)
+            .await;
+
+        let transport = TransportReqwest {
+            hostname: server.uri(),
+            ..TransportReqwest::with_timeouts(Duration::from_millis(200), DEFAULT_CONNECT_TIMEOUT)
+        };
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            timeout: 0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            transport.send(request).await,
+            Err(PubNubError::Timeout {
+                endpoint: path.into()
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[tokio::test]
+    async fn use_request_timeout_instead_of_transport_timeout() {
+        let path = "/time/0";
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("[16787176144828000]")
+                    .set_delay(std::time::Duration::from_millis(500)),
+            )
+            .mount(&server)
+            .await;
+
+        let transport = TransportReqwest {
+            hostname: server.uri(),
+            ..TransportReqwest::with_timeouts(Duration::from_millis(100), DEFAULT_CONNECT_TIMEOUT)
+        };
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            timeout: 5,
+            ..Default::default()
+        };
+
+        assert_eq!(transport.send(request).await.unwrap().status, 200);
+    }
+
+    #[tokio::test]
+    async fn send_request_through_proxy() {
+        let path = "/time/0";
+
+        let proxy = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_macher(path))
+            .and(header("host", "pubnub.example"))
+            .and(header(
+                "proxy-authorization",
+                "Basic dXNlcm5hbWU6cGFzc3dvcmQ=",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_string("[16787176144828000]"))
+            .expect(1)
+            .mount(&proxy)
+            .await;
+
+        let transport = TransportReqwest::builder()
+            .hostname("http://pubnub.example")
+            .proxy(proxy.uri())
+            .proxy_credentials("username", "password")
+            .build()
+            .unwrap();
+
+        let request = TransportRequest {
+            path: path.into(),
+            method: TransportMethod::Get,
+            ..Default::default()
+        };
+
+        assert_eq!(transport.send(request).await.unwrap().status, 200);
+    }
+
+    #[test_case("proxy.example.com:8080" ; "without scheme")]
+    #[test_case("http://" ; "without host")]
+    #[test_case("not a url" ; "malformed")]
+    fn return_err_when_proxy_url_is_malformed(url: &str) {
+        let result = TransportReqwest::builder().proxy(url).build();
+
+        assert!(matches!(
+            result,
+            Err(PubNubError::ClientInitialization { .. })
+        ));
+    }
 }
@@ -94,10 +94,12 @@ impl Default for PAMState {
         Self {
             revoke_token_result: Err(PubNubError::Transport {
                 details: "This is default value".into(),
+                status: None,
                 response: None,
             }),
             grant_token_result: Err(PubNubError::Transport {
                 details: "This is default value".into(),
+                status: None,
                 response: None,
             }),
             resource_type: PAMCurrentResourceType::default(),
@@ -169,6 +171,7 @@ impl Default for PubNubWorld {
             },
             publish_result: Err(PubNubError::Transport {
                 details: "This is default value".into(),
+                status: None,
                 response: None,
             }),
             subscription: None,
@@ -245,6 +248,7 @@ fn set_with_retries(world: &mut PubNubWorld, retry_type: String, max_retry: u8)
             max_retry,
             delay: 0,
             excluded_endpoints: None,
+            max_elapsed: None,
         })
     }
 }
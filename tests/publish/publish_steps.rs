@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use cucumber::{given, then, when};
-use pubnub::core::PubNubError;
+use pubnub::{core::PubNubError, publish::COMPRESSION_THRESHOLD};
 
 use crate::common::PubNubWorld;
 
@@ -66,6 +66,33 @@ async fn i_publish_dictionary_as_message_to_channel_as_post_body(
     world.is_succeed = world.publish_result.is_ok();
 }
 
+#[when(regex = r"^I publish '(.*)' dictionary as message to '(.*)' channel with compression$")]
+async fn i_publish_dictionary_as_message_to_channel_with_compression(
+    world: &mut PubNubWorld,
+    dictionary_json: String,
+    channel: String,
+) {
+    let mut message_hash_map: HashMap<String, String> =
+        serde_json::from_str(dictionary_json.as_str()).unwrap();
+    // Message should exceed threshold to be actually compressed.
+    message_hash_map.insert("padding".into(), "a".repeat(COMPRESSION_THRESHOLD));
+    world.publish_result = world
+        .get_pubnub(world.keyset.to_owned())
+        .publish_message(message_hash_map)
+        .channel(channel)
+        .use_post(true)
+        .compress(true)
+        .execute()
+        .await
+        .map_err(|err| {
+            if let PubNubError::API { .. } = err {
+                world.api_error = Some(err.clone());
+            }
+            err
+        });
+    world.is_succeed = world.publish_result.is_ok();
+}
+
 #[when(regex = r"^I publish '(.*)' dictionary as message to '(.*)' channel$")]
 async fn i_publish_dictionary_as_message_to_channel(
     world: &mut PubNubWorld,